@@ -5,11 +5,17 @@
 //!
 //! We erase type annotations in the AVT. An AVT is assumed to be properly typed.
 
+// Our nodes have a `_private` field so that only this module may construct them. Unlike
+// `#[non_exhaustive]` that also stops the rest of our crate from constructing them.
+#![allow(clippy::manual_non_exhaustive)]
+
 use crate::diagnostics::{DiagnosticRef, TypeKindSnippet};
 use crate::parser::{Identifier, Range};
 use std::rc::Rc;
 
-pub use crate::language::{Constant, IntegerBase, LogicalOperator};
+pub use crate::language::{
+    AssignmentOperator, Constant, InfixOperator, IntegerBase, LogicalOperator,
+};
 
 /// A Brite module is a list of declarations. The order of the declarations does not matter.
 pub struct Module {
@@ -33,16 +39,32 @@ impl Module {
 pub enum Declaration {
     /// A function describes some reusable code which may be executed at any time.
     Function(FunctionDeclaration),
-
-    // TODO: While upgrading the checker to return an AVT we use this to represent an unimplemented
-    // AST to AVT conversion.
-    Unimplemented,
+    /// A class is some associated data and functions.
+    Class(ClassDeclaration),
+    /// A type alias gives a name to a type.
+    TypeAlias(TypeAliasDeclaration),
+    /// Imports declarations from another module.
+    Import(ImportDeclaration),
+    /// A declaration we could not parse. We skipped all of its code.
+    Error(DiagnosticRef),
 }
 
 /// A function describes some reusable code which may be executed at any time.
 pub struct FunctionDeclaration {
+    /// Is this function exported? Other modules may only import exported declarations.
+    pub export: bool,
+    /// Was this function declared with `@inline`? We substitute the body of an inline function at
+    /// its call sites.
+    pub inline: bool,
+    /// Was this function declared with `@outline`? We move the implementation of an outlined
+    /// function into a chunk which is loaded the first time our function is called.
+    pub outline: bool,
     /// The name of a function declaration.
     pub name: Identifier,
+    /// The range of our function’s name in source code.
+    pub range: Range,
+    /// The type of our function.
+    pub type_: Rc<FunctionType>,
     /// Shared function node.
     pub function: Function,
     /// Do not allow this struct to be constructed outside of this module.
@@ -51,15 +73,128 @@ pub struct FunctionDeclaration {
 
 impl FunctionDeclaration {
     /// Create a new function declaration.
-    pub fn new(name: Identifier, function: Function) -> Self {
+    pub fn new(
+        export: bool,
+        name: Identifier,
+        range: Range,
+        type_: Rc<FunctionType>,
+        function: Function,
+    ) -> Self {
         FunctionDeclaration {
+            export,
+            inline: false,
+            outline: false,
             name,
+            range,
+            type_,
             function,
             _private: (),
         }
     }
 }
 
+/// A class is some associated data and functions.
+pub struct ClassDeclaration {
+    /// Is this class exported? Other modules may only import exported declarations.
+    pub export: bool,
+    /// Is this a base class? Only the classes which extend a base class may be constructed.
+    pub base: bool,
+    /// Was this class declared with `@inline`? We substitute the constructor of an inline class at
+    /// the places it is constructed.
+    pub inline: bool,
+    /// The name of a class.
+    pub name: Identifier,
+    /// The range of our class’s name in source code.
+    pub range: Range,
+    /// The type parameters of a generic class.
+    pub type_parameters: Vec<TypeParameter>,
+    /// The name of the base class our class extends.
+    pub extends: Option<Identifier>,
+    /// The fields declared by our class. Does not include the fields of the class we extend.
+    pub fields: Vec<ClassField>,
+    /// The fields of all the classes we extend, even when they are declared in another module. The
+    /// fields of the class furthest from our class come first.
+    pub inherited_fields: Vec<ClassField>,
+    /// The methods and base methods declared by our class.
+    pub methods: Vec<ClassMethod>,
+    /// Do not allow this struct to be constructed outside of this module.
+    _private: (),
+}
+
+impl ClassDeclaration {
+    /// Create a new class declaration.
+    pub fn new(
+        export: bool,
+        name: Identifier,
+        range: Range,
+        type_parameters: Vec<TypeParameter>,
+        extends: Option<Identifier>,
+        fields: Vec<ClassField>,
+        methods: Vec<ClassMethod>,
+    ) -> Self {
+        ClassDeclaration {
+            export,
+            base: false,
+            inline: false,
+            name,
+            range,
+            type_parameters,
+            extends,
+            fields,
+            inherited_fields: Vec::new(),
+            methods,
+            _private: (),
+        }
+    }
+}
+
+/// A type alias gives a name to a type. A type alias is transparent so the types in our AVT never
+/// reference it. We only keep it so that we may declare it for other languages.
+pub struct TypeAliasDeclaration {
+    /// Is this type alias exported? Other modules may only import exported declarations.
+    pub export: bool,
+    /// The name of our type alias.
+    pub name: Identifier,
+    /// The type our type alias gives a name to.
+    pub type_: Type,
+}
+
+/// Imports declarations from another module.
+pub struct ImportDeclaration {
+    /// The path of the module we import from as it was written in our import.
+    pub path: String,
+    /// The classes we import. Types reference a class by its name so when we declare our module
+    /// for other languages we need to import the classes our types reference.
+    pub classes: Vec<Identifier>,
+    /// The values we import, including classes. Types don’t exist at runtime so when we compile
+    /// our module we only import values. We don’t keep the names we could not import.
+    pub values: Vec<Identifier>,
+}
+
+/// A field declares some data needed by a class.
+pub struct ClassField {
+    /// The name of our field.
+    pub name: Identifier,
+    /// The type of our field’s data.
+    pub type_: Type,
+}
+
+/// A method declares some function behavior that a class may perform.
+pub struct ClassMethod {
+    /// A static method does not have a `this` parameter so it is not called on an instance of
+    /// our class.
+    pub static_: bool,
+    /// Is this a base method? A base method is implemented by the classes which extend our class.
+    pub base: bool,
+    /// The name of our method.
+    pub name: Identifier,
+    /// The type of our method without its `this` parameter. We only know the type of a method if
+    /// its signature is fully annotated.
+    pub type_: Option<Type>,
+    /// The function which implements our method. Base methods don’t have one.
+    pub function: Option<Function>,
+}
+
 /// A function describes some reusable code which may be executed at any time. There are many places
 /// in our code where a function may be written.
 ///
@@ -68,8 +203,12 @@ impl FunctionDeclaration {
 /// - As a `FunctionExpression`.
 #[derive(Debug)]
 pub struct Function {
+    /// Is this an `async` function? An async function may await promises in its body.
+    pub async_: bool,
     /// The parameters of a function describes what the function accepts as input.
     pub parameters: Vec<Pattern>,
+    /// The default value of each of our parameters, if it has one.
+    pub defaults: Vec<Option<Expression>>,
     /// The code to be executed when the function is called.
     pub body: Block,
     /// Do not allow this struct to be constructed outside of this module.
@@ -78,9 +217,16 @@ pub struct Function {
 
 impl Function {
     /// Create a new function.
-    pub fn new(parameters: Vec<Pattern>, body: Block) -> Self {
+    pub fn new(
+        async_: bool,
+        parameters: Vec<Pattern>,
+        defaults: Vec<Option<Expression>>,
+        body: Block,
+    ) -> Self {
         Function {
+            async_,
             parameters,
+            defaults,
             body,
             _private: (),
         }
@@ -121,6 +267,18 @@ pub enum StatementKind {
     Expression(Expression),
     /// Binds a value to some names in the current scope.
     Binding(BindingStatement),
+    /// Assigns a new value to a variable.
+    Assignment(AssignmentStatement),
+    /// Exits a loop, optionally with a value.
+    Break(BreakStatement),
+    /// Skips to the next iteration of a loop.
+    Continue(ContinueStatement),
+    /// Returns from the function we are in, optionally with a value. The type checker makes sure
+    /// we are inside of a function.
+    Return(Option<Expression>),
+    /// When the type checker fails on a statement we insert an error statement which will panic
+    /// at runtime.
+    Error(ErrorStatement),
 }
 
 /// Binds a value to some names in the current scope.
@@ -130,6 +288,51 @@ pub struct BindingStatement {
     pub pattern: Pattern,
     /// The value being bound.
     pub value: Expression,
+    /// Do we assign a new value to one of the names our pattern binds? Only the names of a binding
+    /// declared with `let mut` may be assigned to.
+    pub reassigned: bool,
+}
+
+/// Assigns a new value to a variable. The type checker makes sure we only assign to variables
+/// declared with `let mut`.
+#[derive(Debug)]
+pub struct AssignmentStatement {
+    /// The variable we assign to.
+    pub target: Identifier,
+    /// The operator of a compound assignment like `+=`.
+    pub operator: Option<AssignmentOperator>,
+    /// Is our compound assignment arithmetic on two integers? Like an [`InfixExpression`] its
+    /// result wraps around to a 32-bit integer.
+    pub integer: bool,
+    /// The value being assigned.
+    pub value: Expression,
+}
+
+/// Exits a loop, optionally with a value. The type checker makes sure we are inside of the loop.
+#[derive(Debug)]
+pub struct BreakStatement {
+    /// The label of the loop we break out of. Without a label we break out of the innermost loop.
+    pub label: Option<Identifier>,
+    /// The value we break out of our loop with.
+    pub argument: Option<Expression>,
+}
+
+/// Skips to the next iteration of a loop. The type checker makes sure we are inside of the loop.
+#[derive(Debug)]
+pub struct ContinueStatement {
+    /// The label of the loop we continue. Without a label we continue the innermost loop.
+    pub label: Option<Identifier>,
+}
+
+/// When the type checker fails on a statement we insert an error statement which will panic
+/// at runtime.
+#[derive(Debug)]
+pub struct ErrorStatement {
+    /// The diagnostic our error statement failed with.
+    pub error: DiagnosticRef,
+    /// The expressions of our statement which we still evaluate before we panic. Like the value
+    /// of an assignment to a variable we may not assign to.
+    pub expressions: Vec<Expression>,
 }
 
 impl Statement {
@@ -146,11 +349,61 @@ impl Statement {
         Self::new(range, StatementKind::Expression(expression))
     }
 
-    /// Creates a binding statement.
+    /// Creates a binding statement. We don’t know if our binding is reassigned until we have
+    /// checked the rest of its block.
     pub fn binding(range: Range, pattern: Pattern, value: Expression) -> Self {
         Self::new(
             range,
-            StatementKind::Binding(BindingStatement { pattern, value }),
+            StatementKind::Binding(BindingStatement {
+                pattern,
+                value,
+                reassigned: false,
+            }),
+        )
+    }
+
+    /// Creates an assignment statement.
+    pub fn assignment(
+        range: Range,
+        target: Identifier,
+        operator: Option<AssignmentOperator>,
+        integer: bool,
+        value: Expression,
+    ) -> Self {
+        Self::new(
+            range,
+            StatementKind::Assignment(AssignmentStatement {
+                target,
+                operator,
+                integer,
+                value,
+            }),
+        )
+    }
+
+    /// Creates a break statement.
+    pub fn break_(range: Range, label: Option<Identifier>, argument: Option<Expression>) -> Self {
+        Self::new(
+            range,
+            StatementKind::Break(BreakStatement { label, argument }),
+        )
+    }
+
+    /// Creates a continue statement.
+    pub fn continue_(range: Range, label: Option<Identifier>) -> Self {
+        Self::new(range, StatementKind::Continue(ContinueStatement { label }))
+    }
+
+    /// Creates a return statement.
+    pub fn return_(range: Range, argument: Option<Expression>) -> Self {
+        Self::new(range, StatementKind::Return(argument))
+    }
+
+    /// Creates an error statement.
+    pub fn error(range: Range, error: DiagnosticRef, expressions: Vec<Expression>) -> Self {
+        Self::new(
+            range,
+            StatementKind::Error(ErrorStatement { error, expressions }),
         )
     }
 }
@@ -171,24 +424,46 @@ pub struct Expression {
 pub enum ExpressionKind {
     /// A constant value in the programmer’s code.
     Constant(Constant),
-    /// References a variable bound in this expression’s scope.
+    /// References a variable, function, or class bound in this expression’s scope.
     Reference(Identifier),
+    /// The instance of a class a method was called on.
+    This,
     /// A higher-order function.
     Function(Function),
     /// Calls a function with some arguments.
     Call(CallExpression),
+    /// Constructs an instance of a class with a value for each of its fields.
+    Construct(ConstructExpression),
+    /// Accesses a field or a method of an instance of a class.
+    Member(Box<MemberExpression>),
+    /// A list of values.
+    List(Vec<Expression>),
+    /// Gets a value from a list.
+    Index(Box<IndexExpression>),
     /// An operation using prefix syntax.
     Prefix(Box<PrefixExpression>),
+    /// Waits for a promise to resolve inside of an `async` function.
+    Await(Box<Expression>),
+    /// An operation using infix syntax.
+    Infix(Box<InfixExpression>),
+    /// A chain of relational comparisons like `a < b < c`.
+    Comparison(ComparisonExpression),
     /// A logical operation using infix syntax.
     Logical(Box<LogicalExpression>),
+    /// Runs one of two blocks depending on the value of a test.
+    Conditional(Box<ConditionalExpression>),
+    /// Matches a value against the pattern of each arm in order.
+    Case(Box<CaseExpression>),
+    /// Runs a block for as long as a test is true.
+    While(Box<WhileExpression>),
+    /// Runs a block until we break out of it.
+    Loop(LoopExpression),
     /// Embeds a block into an expression.
     Block(Block),
+    /// A string with expressions interpolated into it.
+    Template(TemplateExpression),
     /// When the type checker fails we insert an error expression which will panic at runtime.
     Error(ErrorExpression),
-
-    // TODO: While upgrading the checker to return an AVT we use this to represent an unimplemented
-    // AST to AVT conversion.
-    Unimplemented,
 }
 
 /// Calls a function with some arguments.
@@ -196,8 +471,58 @@ pub enum ExpressionKind {
 pub struct CallExpression {
     /// The function we want to call.
     pub callee: Box<Expression>,
-    /// The arguments we want to call the function with.
-    pub arguments: Vec<Expression>,
+    /// The arguments we want to call the function with in the order they were written. A value
+    /// piped into our call is our first argument.
+    pub arguments: Vec<CallArgument>,
+}
+
+/// An argument in a [`CallExpression`].
+#[derive(Debug)]
+pub struct CallArgument {
+    /// The index of the parameter our argument is passed to. Positional arguments are passed to
+    /// the parameter in the same position. If we could not find a parameter for our argument then
+    /// we have the error we reported instead.
+    pub parameter: Result<usize, DiagnosticRef>,
+    /// The value of our argument.
+    pub value: Expression,
+}
+
+/// Constructs an instance of a class with a value for each of its fields.
+#[derive(Debug)]
+pub struct ConstructExpression {
+    /// A reference to the class we construct. If we can’t construct our class then this is an
+    /// error expression, but we still evaluate our fields before we panic.
+    pub class: Box<Expression>,
+    /// The fields we construct our class with in the order they were written.
+    pub fields: Vec<ConstructField>,
+}
+
+/// A field in a [`ConstructExpression`].
+#[derive(Debug)]
+pub struct ConstructField {
+    /// The name of our field.
+    pub name: Identifier,
+    /// The value of our field.
+    pub value: Expression,
+}
+
+/// Accesses a field or a method of an instance of a class.
+#[derive(Debug)]
+pub struct MemberExpression {
+    /// The instance we access a member of. Or a reference to the class whose static method
+    /// we access.
+    pub object: Expression,
+    /// The name of the member we access.
+    pub property: Identifier,
+}
+
+/// Gets a value from a list.
+#[derive(Debug)]
+pub struct IndexExpression {
+    /// The list we get a value from.
+    pub object: Expression,
+    /// The index of the value we get.
+    pub index: Expression,
 }
 
 /// An operation using prefix syntax.
@@ -205,6 +530,9 @@ pub struct CallExpression {
 pub struct PrefixExpression {
     /// The operator which describes this operation.
     pub operator: PrefixOperator,
+    /// Is this the negation of an integer? Like integer arithmetic in an [`InfixExpression`] our
+    /// result wraps around to a 32-bit integer.
+    pub integer: bool,
     /// The operand we are performing the operation on.
     pub operand: Expression,
 }
@@ -214,6 +542,44 @@ pub struct PrefixExpression {
 pub enum PrefixOperator {
     /// `!`
     Not,
+    /// `-`
+    Negative,
+    /// `+`
+    Positive,
+    /// `~`
+    BitwiseNot,
+}
+
+/// An operation using infix syntax. Both of our operands are always evaluated.
+#[derive(Debug)]
+pub struct InfixExpression {
+    /// The operator which describes this operation.
+    pub operator: InfixOperator,
+    /// Is this arithmetic on two integers? The result of integer arithmetic wraps around to a
+    /// 32-bit integer.
+    pub integer: bool,
+    /// The left-hand-side operand.
+    pub left: Expression,
+    /// The right-hand-side operand.
+    pub right: Expression,
+}
+
+/// A chain of relational comparisons like `a < b < c`. Every operand is evaluated at most once.
+#[derive(Debug)]
+pub struct ComparisonExpression {
+    /// The first operand in our chain.
+    pub left: Box<Expression>,
+    /// Every comparison in our chain. There is always at least one.
+    pub comparisons: Vec<Comparison>,
+}
+
+/// A comparison in a [`ComparisonExpression`] with the operand before it.
+#[derive(Debug)]
+pub struct Comparison {
+    /// The relational operator of our comparison.
+    pub operator: InfixOperator,
+    /// The right-hand-side operand.
+    pub right: Expression,
 }
 
 /// A logical operation using infix syntax.
@@ -231,12 +597,74 @@ pub struct LogicalExpression {
     pub right: Expression,
 }
 
+/// Runs one of two blocks depending on the value of a test.
+#[derive(Debug)]
+pub struct ConditionalExpression {
+    /// The test which decides which block we run.
+    pub test: Expression,
+    /// The block we run when our test is true.
+    pub consequent: Block,
+    /// What we run when our test is false. Either a block expression or another
+    /// conditional expression.
+    pub alternate: Option<Expression>,
+}
+
+/// Matches a value against the pattern of each arm in order. The first arm whose pattern matches
+/// decides our value. The type checker makes sure that some arm always matches.
+#[derive(Debug)]
+pub struct CaseExpression {
+    /// The value we match against the pattern of each arm.
+    pub test: Expression,
+    /// The arms of our case expression.
+    pub arms: Vec<CaseArm>,
+}
+
+/// An arm in a [`CaseExpression`].
+#[derive(Debug)]
+pub struct CaseArm {
+    /// The pattern our test is matched against. Binds names for our body.
+    pub pattern: Pattern,
+    /// The value of our case expression when our pattern matches.
+    pub body: Expression,
+}
+
+/// Runs a block for as long as a test is true.
+#[derive(Debug)]
+pub struct WhileExpression {
+    /// The label break and continue statements may reference our loop with.
+    pub label: Option<Identifier>,
+    /// The test we evaluate before every iteration.
+    pub test: Expression,
+    /// The block we run for every iteration.
+    pub body: Block,
+}
+
+/// Runs a block until we break out of it. The value of our loop is the value we break out of it
+/// with.
+#[derive(Debug)]
+pub struct LoopExpression {
+    /// The label break and continue statements may reference our loop with.
+    pub label: Option<Identifier>,
+    /// The block we run for every iteration.
+    pub body: Block,
+}
+
+/// A string with expressions interpolated into it. Every interpolated expression has a type which
+/// may be printed.
+#[derive(Debug)]
+pub struct TemplateExpression {
+    /// The text before the first interpolated expression.
+    pub head: String,
+    /// Every interpolated expression along with the text which comes after it.
+    pub spans: Vec<(Expression, String)>,
+}
+
 /// When the type checker fails we insert an error expression which will panic at runtime.
 #[derive(Debug)]
 pub struct ErrorExpression {
     /// The diagnostic our error expression failed with.
     pub error: DiagnosticRef,
-    /// The underlying expression the type checker erred on.
+    /// The underlying expression the type checker erred on. We never evaluate it.
     pub expression: Option<Box<Expression>>,
 }
 
@@ -259,11 +687,135 @@ impl Expression {
         Self::new(range, ExpressionKind::Reference(identifier))
     }
 
+    /// Creates a `this` expression.
+    pub fn this(range: Range) -> Self {
+        Self::new(range, ExpressionKind::This)
+    }
+
     /// Creates a function expression.
     pub fn function(range: Range, function: Function) -> Self {
         Self::new(range, ExpressionKind::Function(function))
     }
 
+    /// Creates a call expression.
+    pub fn call(range: Range, callee: Expression, arguments: Vec<CallArgument>) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Call(CallExpression {
+                callee: Box::new(callee),
+                arguments,
+            }),
+        )
+    }
+
+    /// Creates a construct expression.
+    pub fn construct(range: Range, class: Expression, fields: Vec<ConstructField>) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Construct(ConstructExpression {
+                class: Box::new(class),
+                fields,
+            }),
+        )
+    }
+
+    /// Creates a member expression.
+    pub fn member(range: Range, object: Expression, property: Identifier) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Member(Box::new(MemberExpression { object, property })),
+        )
+    }
+
+    /// Creates a list expression.
+    pub fn list(range: Range, elements: Vec<Expression>) -> Self {
+        Self::new(range, ExpressionKind::List(elements))
+    }
+
+    /// Creates an index expression.
+    pub fn index(range: Range, object: Expression, index: Expression) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Index(Box::new(IndexExpression { object, index })),
+        )
+    }
+
+    /// Creates a prefix expression.
+    pub fn prefix(range: Range, operator: PrefixOperator, operand: Expression) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Prefix(Box::new(PrefixExpression {
+                operator,
+                integer: false,
+                operand,
+            })),
+        )
+    }
+
+    /// Creates a prefix expression which negates an integer.
+    pub fn integer_negative(range: Range, operand: Expression) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Prefix(Box::new(PrefixExpression {
+                operator: PrefixOperator::Negative,
+                integer: true,
+                operand,
+            })),
+        )
+    }
+
+    /// Creates an await expression.
+    pub fn await_(range: Range, operand: Expression) -> Self {
+        Self::new(range, ExpressionKind::Await(Box::new(operand)))
+    }
+
+    /// Creates an infix expression.
+    pub fn infix(
+        range: Range,
+        operator: InfixOperator,
+        left: Expression,
+        right: Expression,
+    ) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Infix(Box::new(InfixExpression {
+                operator,
+                integer: false,
+                left,
+                right,
+            })),
+        )
+    }
+
+    /// Creates an arithmetic infix expression on two integers.
+    pub fn integer_infix(
+        range: Range,
+        operator: InfixOperator,
+        left: Expression,
+        right: Expression,
+    ) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Infix(Box::new(InfixExpression {
+                operator,
+                integer: true,
+                left,
+                right,
+            })),
+        )
+    }
+
+    /// Creates a comparison expression.
+    pub fn comparison(range: Range, left: Expression, comparisons: Vec<Comparison>) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Comparison(ComparisonExpression {
+                left: Box::new(left),
+                comparisons,
+            }),
+        )
+    }
+
     /// Creates a logical expression.
     pub fn logical(
         range: Range,
@@ -281,11 +833,57 @@ impl Expression {
         )
     }
 
+    /// Creates a conditional expression.
+    pub fn conditional(
+        range: Range,
+        test: Expression,
+        consequent: Block,
+        alternate: Option<Expression>,
+    ) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Conditional(Box::new(ConditionalExpression {
+                test,
+                consequent,
+                alternate,
+            })),
+        )
+    }
+
+    /// Creates a case expression.
+    pub fn case(range: Range, test: Expression, arms: Vec<CaseArm>) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Case(Box::new(CaseExpression { test, arms })),
+        )
+    }
+
+    /// Creates a while expression.
+    pub fn while_(range: Range, label: Option<Identifier>, test: Expression, body: Block) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::While(Box::new(WhileExpression { label, test, body })),
+        )
+    }
+
+    /// Creates a loop expression.
+    pub fn loop_(range: Range, label: Option<Identifier>, body: Block) -> Self {
+        Self::new(range, ExpressionKind::Loop(LoopExpression { label, body }))
+    }
+
     /// Creates a block expression.
     pub fn block(range: Range, block: Block) -> Self {
         Self::new(range, ExpressionKind::Block(block))
     }
 
+    /// Creates a template expression.
+    pub fn template(range: Range, head: String, spans: Vec<(Expression, String)>) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Template(TemplateExpression { head, spans }),
+        )
+    }
+
     /// Creates an error expression.
     pub fn error(range: Range, error: DiagnosticRef, expression: Option<Expression>) -> Self {
         Self::new(
@@ -296,11 +894,6 @@ impl Expression {
             }),
         )
     }
-
-    // TODO: Remove this.
-    pub fn unimplemented(range: Range) -> Self {
-        Self::new(range, ExpressionKind::Unimplemented)
-    }
 }
 
 /// A pattern is used for binding a value to some names in the current block scope.
@@ -319,6 +912,32 @@ pub struct Pattern {
 pub enum PatternKind {
     /// Binds the value to an identifier name in scope.
     Binding(Identifier),
+    /// Binds the value to nothing.
+    Hole,
+    /// Binds the instance of a class a method was called on to `this`.
+    This,
+    /// Matches an instance of a class and matches some of its fields against other patterns.
+    Class(ClassPattern),
+    /// A pattern we could not parse. Binds nothing.
+    Error(DiagnosticRef),
+}
+
+/// Matches an instance of a class and matches some of its fields against other patterns.
+#[derive(Debug)]
+pub struct ClassPattern {
+    /// The class our value must be an instance of.
+    pub class: Identifier,
+    /// The fields we match against.
+    pub fields: Vec<ClassPatternField>,
+}
+
+/// A field in a [`ClassPattern`].
+#[derive(Debug)]
+pub struct ClassPatternField {
+    /// The name of the class field.
+    pub name: Identifier,
+    /// The pattern we match the field’s value against.
+    pub value: Pattern,
 }
 
 impl Pattern {
@@ -334,6 +953,26 @@ impl Pattern {
     pub fn binding(range: Range, identifier: Identifier) -> Self {
         Self::new(range, PatternKind::Binding(identifier))
     }
+
+    /// Creates a hole pattern.
+    pub fn hole(range: Range) -> Self {
+        Self::new(range, PatternKind::Hole)
+    }
+
+    /// Creates a `this` pattern.
+    pub fn this(range: Range) -> Self {
+        Self::new(range, PatternKind::This)
+    }
+
+    /// Creates a class pattern.
+    pub fn class(range: Range, class: Identifier, fields: Vec<ClassPatternField>) -> Self {
+        Self::new(range, PatternKind::Class(ClassPattern { class, fields }))
+    }
+
+    /// Creates an error pattern.
+    pub fn error(range: Range, error: DiagnosticRef) -> Self {
+        Self::new(range, PatternKind::Error(error))
+    }
 }
 
 /// Describes the values which may be assigned to a particular binding.
@@ -397,19 +1036,75 @@ pub enum TypeKind {
     ///
     /// [1]: https://en.wikipedia.org/wiki/IEEE_754
     Float,
+    /// A string is an immutable sequence of Unicode characters.
+    String,
+    /// The type of `null`. Null is only the subtype of itself and of optional types.
+    Null,
+    /// A value which may also be `null`. Written as `T?`. Always create optional types with
+    /// [`Type::optional`] which makes sure that we never wrap an error type, null, or another
+    /// optional type.
+    Optional(Box<Type>),
+    /// An immutable list of values which all have the same type. Written as `List<T>`.
+    List(Box<Type>),
+    /// The eventual value of some asynchronous work. Written as `Promise<T>`.
+    Promise(Box<Type>),
     /// The type of a function. Functions may be passed around just like any other value.
     Function(Rc<FunctionType>),
+    /// A type parameter of a generic function or class. Inside of the generic function or class we
+    /// know nothing about the type so a type parameter is only ever the subtype of itself.
+    Parameter(TypeParameter),
+    /// An instance of a class. Also the supertype of all the classes which extend this class.
+    Class(Rc<ClassType>),
 }
 
 /// The type of a function. Functions may be passed around just like any other value.
 #[derive(Clone, Debug)]
 pub struct FunctionType {
+    /// The type parameters of a generic function. Before we may call a generic function we
+    /// instantiate it by substituting a type for each type parameter.
+    pub type_parameters: Vec<TypeParameter>,
     /// The types of this function’s parameters.
     pub parameters: Vec<Type>,
+    /// The names of this function’s parameters. A parameter only has a name if its pattern is a
+    /// single binding. Calls may pass arguments to named parameters by name.
+    pub parameter_names: Vec<Option<Identifier>>,
+    /// Does each of this function’s parameters have a default value? Calls may leave out the
+    /// arguments for parameters with a default value.
+    pub parameter_defaults: Vec<bool>,
     /// The return type of this function.
     pub return_: Box<Type>,
 }
 
+/// A type parameter of a generic function or class.
+#[derive(Clone, Debug)]
+pub struct TypeParameter {
+    /// A unique identifier for our type parameter. Two type parameters may have the same name so we
+    /// use this identifier to tell them apart.
+    pub id: TypeParameterId,
+    /// The name the programmer gave our type parameter.
+    pub name: Identifier,
+}
+
+/// A unique identifier for a [`TypeParameter`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TypeParameterId(pub u32);
+
+/// The type of an instance of a class.
+#[derive(Clone, Debug)]
+pub struct ClassType {
+    /// A unique identifier for the class. Two classes may have the same name so we use this
+    /// identifier to tell them apart.
+    pub id: ClassId,
+    /// The name of the class.
+    pub name: Identifier,
+    /// The types we substitute for each of the class’s type parameters.
+    pub arguments: Vec<Type>,
+}
+
+/// A unique identifier for a class declaration.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ClassId(pub u32);
+
 impl Type {
     /// Creates a never type.
     pub fn never(range: Range) -> Self {
@@ -464,9 +1159,75 @@ impl Type {
         }
     }
 
+    /// Creates a string type.
+    pub fn string(range: Range) -> Self {
+        Type::Ok {
+            range,
+            kind: TypeKind::String,
+        }
+    }
+
+    /// Creates a null type.
+    pub fn null(range: Range) -> Self {
+        Type::Ok {
+            range,
+            kind: TypeKind::Null,
+        }
+    }
+
+    /// Creates an optional type. An optional error type is the error type. `T??` is the same as
+    /// `T?`, and since no value is typed as `Never` the only value of `Never?` is `null`.
+    pub fn optional(range: Range, type_: Type) -> Self {
+        let kind = match type_ {
+            Type::Error { .. } => return type_,
+            Type::Ok {
+                kind: TypeKind::Never,
+                ..
+            }
+            | Type::Ok {
+                kind: TypeKind::Null,
+                ..
+            } => TypeKind::Null,
+            Type::Ok {
+                kind: TypeKind::Optional(type_),
+                ..
+            } => TypeKind::Optional(type_),
+            Type::Ok { .. } => TypeKind::Optional(Box::new(type_)),
+        };
+        Type::Ok { range, kind }
+    }
+
+    /// Creates a list type.
+    pub fn list(range: Range, element: Type) -> Self {
+        Type::Ok {
+            range,
+            kind: TypeKind::List(Box::new(element)),
+        }
+    }
+
+    /// Creates a promise type.
+    pub fn promise(range: Range, value: Type) -> Self {
+        Type::Ok {
+            range,
+            kind: TypeKind::Promise(Box::new(value)),
+        }
+    }
+
     /// Creates a function type.
     pub fn function(range: Range, parameters: Vec<Type>, return_: Type) -> Self {
-        Self::from_function(range, FunctionType::new(parameters, return_))
+        Self::from_function(range, FunctionType::new(Vec::new(), parameters, return_))
+    }
+
+    /// Creates a class type.
+    pub fn class(range: Range, id: ClassId, name: Identifier, arguments: Vec<Type>) -> Self {
+        Type::Ok {
+            range,
+            kind: TypeKind::Class(Rc::new(ClassType {
+                id,
+                name,
+                arguments,
+            })),
+        }
     }
 
     /// Creates a function type from a `FunctionType`.
@@ -479,8 +1240,11 @@ impl Type {
 }
 
 impl FunctionType {
-    pub fn new(parameters: Vec<Type>, return_: Type) -> Self {
+    pub fn new(type_parameters: Vec<TypeParameter>, parameters: Vec<Type>, return_: Type) -> Self {
         FunctionType {
+            type_parameters,
+            parameter_names: vec![None; parameters.len()],
+            parameter_defaults: vec![false; parameters.len()],
             parameters,
             return_: Box::new(return_),
         }
@@ -497,7 +1261,17 @@ impl TypeKind {
             TypeKind::Number => TypeKindSnippet::Number,
             TypeKind::Integer => TypeKindSnippet::Integer,
             TypeKind::Float => TypeKindSnippet::Float,
+            TypeKind::String => TypeKindSnippet::String,
+            TypeKind::Null => TypeKindSnippet::Null,
+            TypeKind::Optional(type_) => match &**type_ {
+                Type::Ok { kind, .. } => TypeKindSnippet::Optional(Box::new(kind.snippet())),
+                Type::Error { .. } => unreachable!("Optional types never wrap an error type."),
+            },
+            TypeKind::List(_) => TypeKindSnippet::List,
+            TypeKind::Promise(_) => TypeKindSnippet::Promise,
             TypeKind::Function(_) => TypeKindSnippet::Function,
+            TypeKind::Parameter(parameter) => TypeKindSnippet::Parameter(parameter.name.clone()),
+            TypeKind::Class(class) => TypeKindSnippet::Class(class.name.clone()),
         }
     }
 }
@@ -1,13 +1,15 @@
 use super::avt::*;
+use super::checker2::DeclarationLazy;
 use crate::diagnostics::{
     Diagnostic, DiagnosticRef, DiagnosticsCollection, OperationSnippet, OperatorSnippet,
-    TypeKindSnippet,
+    TypeKindSnippet, VecSnippet,
 };
 use crate::language as ast;
 use crate::parser::{Identifier, Range};
 use crate::utils::vecn::Vec1;
 use std::cmp;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::mem;
 use std::rc::Rc;
 
 /// Checks the Brite Abstract Syntax Tree (AST) for errors and warnings. Reports diagnostics for any
@@ -27,6 +29,31 @@ pub struct Checker<'errs> {
     _diagnostics: &'errs mut DiagnosticsCollection,
     /// The scope contains all the variables accessible at different points in the program.
     scope: Scope,
+    /// The identifier we will give to the next type parameter we check.
+    next_type_parameter_id: u32,
+    /// Every class declared in our module. A [`ClassId`] is an index into this list.
+    classes: Vec<ClassEntry>,
+    /// Every type alias declared in our module. A `ScopeEntryKind::TypeAlias` has an index into
+    /// this list.
+    type_aliases: Vec<TypeAliasEntry>,
+    /// Every function declared in our module. A `ScopeEntryKind::Function` has an index into
+    /// this list.
+    functions: Vec<FunctionEntry>,
+    /// The loops we are currently checking the body of. The innermost loop is last.
+    loops: Vec<LoopEntry>,
+    /// Are we currently checking the body of an `async` function? We may only `await` a promise
+    /// in the body of an `async` function.
+    async_: bool,
+    /// If we are currently checking the body of a function then the type our `return` statements
+    /// must return. If our function’s return type is not annotated then we don’t know this type
+    /// until we check our first `return` statement.
+    return_type: Option<Option<Type>>,
+    /// The type of `this` if we are currently checking the body of a method with a
+    /// `this` parameter.
+    this: Option<Type>,
+    /// The ranges of the variables we have assigned a new value to. A variable declared with
+    /// `let mut` which is never assigned to is compiled like any other binding.
+    assigned: HashSet<Range>,
 }
 
 impl<'errs> Checker<'errs> {
@@ -35,31 +62,130 @@ impl<'errs> Checker<'errs> {
         Checker {
             _diagnostics: diagnostics,
             scope: Scope::new(),
+            next_type_parameter_id: 0,
+            classes: Vec::new(),
+            type_aliases: Vec::new(),
+            functions: Vec::new(),
+            loops: Vec::new(),
+            async_: false,
+            return_type: None,
+            this: None,
+            assigned: HashSet::new(),
         }
     }
 
+    /// Creates a type checker context which resumes checking a module in a [`ModuleGraph`]. We
+    /// start with the scope of our module and the state shared by every module in the graph.
+    pub(super) fn resume(
+        diagnostics: &'errs mut DiagnosticsCollection,
+        scope: Scope,
+        shared: SharedState,
+    ) -> Self {
+        Checker {
+            _diagnostics: diagnostics,
+            scope,
+            next_type_parameter_id: shared.next_type_parameter_id,
+            classes: shared.classes,
+            type_aliases: shared.type_aliases,
+            functions: shared.functions,
+            loops: Vec::new(),
+            async_: false,
+            return_type: None,
+            this: None,
+            assigned: HashSet::new(),
+        }
+    }
+
+    /// Suspends checking our module so that we may check another module in our [`ModuleGraph`].
+    /// Returns the scope of our module and the state shared by every module in the graph.
+    pub(super) fn suspend(self) -> (Scope, SharedState) {
+        let shared = SharedState {
+            next_type_parameter_id: self.next_type_parameter_id,
+            classes: self.classes,
+            type_aliases: self.type_aliases,
+            functions: self.functions,
+        };
+        (self.scope, shared)
+    }
+
     /// Checks an AST module for errors.
+    ///
+    /// We check our module on its own so there are no other modules to import from. Use a
+    /// [`ModuleGraph`] to check modules which import from one another.
     pub fn check_module(mut self, module: &ast::Module) -> Module {
-        // Add all our declarations to scope. We need to do this before type checking our
-        // declarations because all declarations are mutually recursive.
+        let declared = self.declare_module(module);
+        for declaration in &module.declarations {
+            if let ast::Declaration::Import(import) = declaration {
+                self.report_diagnostic(Diagnostic::module_not_found(
+                    import.path_range,
+                    import.path.clone(),
+                ));
+            }
+        }
+        self.check_module_type_aliases(&declared);
+        self.check_module_classes(module, &declared);
+        self.check_module_functions(module, &declared);
+        self.check_module_attributes(module);
+        self.check_module_declarations(module, &declared)
+    }
+
+    /// Adds all the declarations of our module to scope. We need to do this before type checking
+    /// our declarations because all declarations are mutually recursive.
+    pub(super) fn declare_module(&mut self, module: &ast::Module) -> DeclaredModule {
+        let mut declared = DeclaredModule {
+            classes: Vec::new(),
+            type_aliases: Vec::new(),
+            functions: Vec::new(),
+        };
         for declaration in &module.declarations {
             // Get the name and the scope entry kind of our declaration.
             let (name, entry_kind) = match declaration {
-                ast::Declaration::Function(function) => (&function.name, ScopeEntryKind::Function),
+                // We don’t check the signature of our function until we’ve declared all of our
+                // declarations. See `Checker::check_module_functions`.
+                ast::Declaration::Function(function) => {
+                    let index = self.functions.len();
+                    self.functions.push(FunctionEntry::default());
+                    declared.functions.push(index);
+                    (&function.name, ScopeEntryKind::Function(index))
+                }
                 ast::Declaration::Class(class) => {
-                    (&class.name, ScopeEntryKind::Class { base: class.base })
+                    let id = self.declare_class(class);
+                    declared.classes.push(id);
+                    (&class.name, ScopeEntryKind::Class(id))
+                }
+                // We don’t check the type we alias until we’ve declared all of our declarations.
+                // See `Checker::check_module_type_aliases`.
+                ast::Declaration::TypeAlias(type_alias) => {
+                    let index = self.type_aliases.len();
+                    self.type_aliases
+                        .push(DeclarationLazy::Unchecked(type_alias.value.clone()));
+                    declared.type_aliases.push(index);
+                    (&type_alias.name, ScopeEntryKind::TypeAlias(index))
                 }
+                // Imports are added to our scope once all the modules we import from have been
+                // declared. See `Checker::declare_import`.
+                ast::Declaration::Import(_) => continue,
+                // Error declarations don’t have a name so there’s nothing to add to our scope.
+                ast::Declaration::Error(_) => continue,
             };
-            // If we’ve already seen this declaration name then report an error. We’ll still
-            // type-check the declaration, but any references will get access to the first
-            // declaration we saw.
-            if let Some(entry) = self.scope.resolve_maybe(&name.identifier) {
+            self.declare_declaration(name, entry_kind);
+        }
+        declared
+    }
+
+    /// Adds a declaration imported from another module to our module’s scope.
+    ///
+    /// We add imports to scope after all the other declarations of our module, but imports come
+    /// first in the source code. So when an import uses the same name as a declaration which comes
+    /// after it, we report the error on the later declaration and give the name to our import.
+    pub(super) fn declare_import(&mut self, name: &ast::Name, entry_kind: ScopeEntryKind) {
+        match self.scope.resolve_maybe(&name.identifier) {
+            Some(entry) if entry.range.start() > name.range.start() => {
                 self.report_diagnostic(Diagnostic::declaration_name_already_used(
-                    name.range,
-                    name.identifier.clone(),
                     entry.range,
+                    name.identifier.clone(),
+                    name.range,
                 ));
-            } else {
                 self.scope.declare(
                     name.identifier.clone(),
                     ScopeEntry {
@@ -68,40 +194,373 @@ impl<'errs> Checker<'errs> {
                     },
                 );
             }
+            _ => self.declare_declaration(name, entry_kind),
         }
+    }
 
-        let mut declarations = Vec::with_capacity(module.declarations.len());
+    /// Adds a declaration to scope. If we’ve already seen this declaration name then report an
+    /// error. We’ll still type-check the declaration, but any references will get access to the
+    /// first declaration we saw.
+    fn declare_declaration(&mut self, name: &ast::Name, entry_kind: ScopeEntryKind) {
+        if let Some(entry) = self.scope.resolve_maybe(&name.identifier) {
+            self.report_diagnostic(Diagnostic::declaration_name_already_used(
+                name.range,
+                name.identifier.clone(),
+                entry.range,
+            ));
+        } else {
+            self.scope.declare(
+                name.identifier.clone(),
+                ScopeEntry {
+                    range: name.range,
+                    kind: entry_kind,
+                },
+            );
+        }
+    }
+
+    /// Now that all of our declarations are in scope, check our type aliases. We check a type alias
+    /// the first time it is referenced so a type alias may reference the type aliases declared
+    /// after it. Any type aliases which were not referenced are checked here.
+    pub(super) fn check_module_type_aliases(&mut self, declared: &DeclaredModule) {
+        for index in &declared.type_aliases {
+            self.check_type_alias(*index);
+        }
+    }
+
+    /// Checks a type alias if we have not checked it yet and returns the type it aliases. If we
+    /// are already in the middle of checking our type alias then we return `None`.
+    fn check_type_alias(&mut self, index: usize) -> Option<Type> {
+        if let Some(value) = self.type_aliases[index].start_checking() {
+            let type_ = self.check_type(&value);
+            self.type_aliases[index] = DeclarationLazy::Checked(type_);
+        }
+        self.type_aliases[index].checked().cloned()
+    }
+
+    /// Now that all of our declarations are in scope, check our classes. We check all of our
+    /// classes before any other declaration so that we know the fields of every class before
+    /// we check code which uses them.
+    pub(super) fn check_module_classes(&mut self, module: &ast::Module, declared: &DeclaredModule) {
+        let class_declarations =
+            module
+                .declarations
+                .iter()
+                .filter_map(|declaration| match declaration {
+                    ast::Declaration::Class(class) => Some(class),
+                    _ => None,
+                });
+        for (id, class) in declared.classes.iter().zip(class_declarations) {
+            self.check_class_declaration(*id, class);
+        }
+    }
 
-        // Now that all of our declarations are in scope, loop through our declaration list again
-        // and type check all our declarations.
+    /// Checks the attributes written before every declaration and class member in our module.
+    /// Attributes don’t have types so we only make sure that we know of every attribute and that
+    /// every attribute is written where it belongs.
+    pub(super) fn check_module_attributes(&mut self, module: &ast::Module) {
         for declaration in &module.declarations {
-            let declaration = self.check_declaration(declaration);
-            declarations.push(declaration);
+            let target = match declaration {
+                ast::Declaration::Function(_) => ast::AttributeTarget::Function,
+                ast::Declaration::Class(_) => ast::AttributeTarget::Class,
+                ast::Declaration::TypeAlias(_) => ast::AttributeTarget::TypeAlias,
+                ast::Declaration::Import(_) | ast::Declaration::Error(_) => continue,
+            };
+            self.check_attributes(declaration.attributes(), target);
+            if let ast::Declaration::Class(class) = declaration {
+                for member in &class.members {
+                    let target = match member {
+                        ast::ClassMember::Field(_) => ast::AttributeTarget::Field,
+                        ast::ClassMember::Method(_) => ast::AttributeTarget::Method,
+                        ast::ClassMember::BaseMethod(_) => ast::AttributeTarget::BaseMethod,
+                    };
+                    self.check_attributes(member.attributes(), target);
+                }
+            }
+        }
+    }
+
+    /// Checks the attributes written before a single declaration or class member.
+    fn check_attributes(&mut self, attributes: &[ast::Attribute], target: ast::AttributeTarget) {
+        let mut kinds = Vec::with_capacity(attributes.len());
+        for attribute in attributes {
+            let name = &attribute.name;
+
+            // If we don’t know of our attribute then report an error. We still check the
+            // arguments of our attribute so that the programmer sees the errors inside them.
+            let kind = match attribute.kind() {
+                Some(kind) => kind,
+                None => {
+                    self.report_diagnostic(Diagnostic::unknown_attribute(
+                        name.range,
+                        name.identifier.clone(),
+                    ));
+                    for argument in &attribute.arguments {
+                        self.check_expression(argument);
+                    }
+                    continue;
+                }
+            };
+
+            if kinds.contains(&kind) {
+                self.report_diagnostic(Diagnostic::duplicate_attribute(
+                    attribute.range,
+                    name.identifier.clone(),
+                ));
+            } else {
+                kinds.push(kind);
+            }
+
+            if !kind.allows(target) {
+                self.report_diagnostic(Diagnostic::unexpected_attribute(
+                    attribute.range,
+                    name.identifier.clone(),
+                    target,
+                ));
+            }
+
+            // Report an error for the arguments we don’t need. The only attribute argument we
+            // know of is the message of `@deprecated` so we check every argument we do need
+            // against `String`.
+            let max = kind.max_arguments();
+            if attribute.arguments.len() > max {
+                let range = attribute.arguments[max]
+                    .range
+                    .union(attribute.arguments.last().unwrap().range);
+                self.report_diagnostic(Diagnostic::incompatible_attribute_arguments(
+                    range,
+                    name.identifier.clone(),
+                    attribute.arguments.len(),
+                    max,
+                ));
+            }
+            for (i, argument) in attribute.arguments.iter().enumerate() {
+                if i < max {
+                    let operation = OperationSnippet::AttributeArgument(
+                        name.identifier.clone(),
+                        argument.snippet(),
+                    );
+                    self.check_expression_with_type(operation, argument, &Type::string(name.range));
+                } else {
+                    self.check_expression(argument);
+                }
+            }
         }
+    }
 
+    /// Loop through our declaration list again and type check all our other declarations.
+    pub(super) fn check_module_declarations(
+        &mut self,
+        module: &ast::Module,
+        declared: &DeclaredModule,
+    ) -> Module {
+        let mut declarations = Vec::with_capacity(module.declarations.len());
+        let mut functions = declared.functions.iter();
+        let mut classes = declared.classes.iter();
+        let mut type_aliases = declared.type_aliases.iter();
+        for declaration in &module.declarations {
+            let declaration = match declaration {
+                ast::Declaration::Function(function) => {
+                    let index = *functions.next().unwrap();
+                    let function = self.check_function_declaration(index, function);
+                    Declaration::Function(function)
+                }
+                ast::Declaration::Class(class) => {
+                    let id = *classes.next().unwrap();
+                    let functions = self.check_class_methods(id, class);
+                    Declaration::Class(self.class_declaration(id, class, functions))
+                }
+                // We already checked our type aliases in `Checker::check_module_type_aliases`.
+                ast::Declaration::TypeAlias(type_alias) => {
+                    let index = *type_aliases.next().unwrap();
+                    Declaration::TypeAlias(TypeAliasDeclaration {
+                        export: type_alias.export,
+                        name: type_alias.name.identifier.clone(),
+                        type_: self.check_type_alias(index).unwrap(),
+                    })
+                }
+                _ => self.check_declaration(declaration),
+            };
+            declarations.push(declaration);
+        }
         Module::new(declarations)
     }
 
     fn check_declaration(&mut self, declaration: &ast::Declaration) -> Declaration {
         match declaration {
-            ast::Declaration::Function(function) => {
-                let function = self.check_function_declaration(function);
-                Declaration::Function(function)
-            }
-            ast::Declaration::Class(class) => {
-                self.check_class_declaration(class);
-                Declaration::Unimplemented
+            // We check function declarations with their index in
+            // `Checker::check_module_declarations`.
+            ast::Declaration::Function(_) => unreachable!(),
+            // We create class declarations with their identifier in
+            // `Checker::check_module_declarations`. We already checked our classes in
+            // `Checker::check_module_classes`.
+            ast::Declaration::Class(_) => unreachable!(),
+            // We create type alias declarations with their index in
+            // `Checker::check_module_declarations`.
+            ast::Declaration::TypeAlias(_) => unreachable!(),
+            // We already added our imports to scope in `Checker::declare_import`. Keep the classes
+            // and the values we imported. If we could not import a name, or if it clashed with
+            // another declaration, then it is not in scope with the range of our import.
+            ast::Declaration::Import(import) => {
+                let mut classes = Vec::new();
+                let mut values = Vec::new();
+                for name in &import.names {
+                    match self.scope.resolve_maybe(&name.identifier) {
+                        Some(entry) if entry.range == name.range => {
+                            if let ScopeEntryKind::Class(_) = entry.kind {
+                                classes.push(name.identifier.clone());
+                            }
+                            if !entry.kind.is_type() {
+                                values.push(name.identifier.clone());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Declaration::Import(ImportDeclaration {
+                    path: import.path.clone(),
+                    classes,
+                    values,
+                })
             }
+            ast::Declaration::Error(error) => Declaration::Error(error.error.clone()),
+        }
+    }
+
+    /// Now that all of our declarations are in scope, check the signatures of our functions. We
+    /// check every signature before we check the body of any function so that a function may
+    /// reference the functions declared after it.
+    pub(super) fn check_module_functions(
+        &mut self,
+        module: &ast::Module,
+        declared: &DeclaredModule,
+    ) {
+        let function_declarations =
+            module
+                .declarations
+                .iter()
+                .filter_map(|declaration| match declaration {
+                    ast::Declaration::Function(function) => Some(function),
+                    _ => None,
+                });
+        for (index, function) in declared.functions.iter().zip(function_declarations) {
+            self.check_function_signature(*index, function);
+        }
+    }
+
+    /// Checks the type parameters and the annotations of a function declaration. If every
+    /// parameter and our return type are annotated then we know the type of our function before
+    /// we check its body.
+    fn check_function_signature(&mut self, index: usize, declaration: &ast::FunctionDeclaration) {
+        let function = &declaration.function;
+        self.scope.nest();
+        let type_parameters = self.check_type_parameters(&function.type_parameters);
+        let mut parameters = Vec::with_capacity(function.parameters.len());
+        for parameter in &function.parameters {
+            let type_ = parameter
+                .annotation
+                .as_ref()
+                .map(|annotation| self.check_type(annotation));
+            parameters.push(type_);
         }
+        let return_ = function
+            .return_type
+            .as_ref()
+            .map(|return_type| self.check_type(return_type));
+        self.scope.unnest();
+
+        // We only know the type of our function if we know all of the types in its signature. An
+        // async function returns a promise of its annotated return type.
+        let type_ = match (parameters.iter().cloned().collect(), &return_) {
+            (Some(parameter_types), Some(return_type)) => {
+                let return_type = if function.async_ {
+                    let range = function.return_type.as_ref().unwrap().range();
+                    Type::promise(range, return_type.clone())
+                } else {
+                    return_type.clone()
+                };
+                Some(Type::Ok {
+                    range: declaration.name.range,
+                    kind: TypeKind::Function(Rc::new(Self::function_type(
+                        &function.parameters,
+                        type_parameters.clone(),
+                        parameter_types,
+                        return_type,
+                    ))),
+                })
+            }
+            _ => None,
+        };
+
+        self.functions[index] = FunctionEntry {
+            type_parameters,
+            parameters,
+            return_,
+            type_,
+        };
     }
 
     fn check_function_declaration(
         &mut self,
-        function: &ast::FunctionDeclaration,
+        index: usize,
+        declaration: &ast::FunctionDeclaration,
     ) -> FunctionDeclaration {
-        let name = function.name.identifier.clone();
-        let function = self.check_function(function.name.range, &function.function, None);
-        FunctionDeclaration::new(name, function.node)
+        let name = declaration.name.identifier.clone();
+        let signature = self.functions[index].clone();
+        let function = self.check_function_with_signature(
+            declaration.name.range,
+            &declaration.function,
+            None,
+            Some(signature),
+        );
+
+        // Loading an outlined function is asynchronous so an outlined function must return a
+        // promise. Report our error at our return type annotation if we have one.
+        let outline = declaration.has_attribute(ast::AttributeKind::Outline);
+        if outline {
+            match &*function.type_.return_ {
+                Type::Error { .. }
+                | Type::Ok {
+                    kind: TypeKind::Promise(_),
+                    ..
+                }
+                | Type::Ok {
+                    kind: TypeKind::Never,
+                    ..
+                } => {}
+                Type::Ok { range, kind } => {
+                    let error_range = match &declaration.function.return_type {
+                        Some(return_type) => return_type.range(),
+                        None => declaration.name.range,
+                    };
+                    self.report_diagnostic(Diagnostic::outline_without_promise(
+                        error_range,
+                        declaration.name.identifier.clone(),
+                        *range,
+                        kind.snippet(),
+                    ));
+                }
+            }
+        }
+
+        // Now that we have checked our function we know its type even if it was not annotated.
+        let type_ = Rc::new(function.type_);
+        if self.functions[index].type_.is_none() {
+            self.functions[index].type_ = Some(Type::Ok {
+                range: declaration.name.range,
+                kind: TypeKind::Function(Rc::clone(&type_)),
+            });
+        }
+        let mut function = FunctionDeclaration::new(
+            declaration.export,
+            name,
+            declaration.name.range,
+            type_,
+            function.node,
+        );
+        function.inline = declaration.has_attribute(ast::AttributeKind::Inline);
+        function.outline = outline;
+        function
     }
 
     /// Checks a function and returns the type of the function.
@@ -116,14 +575,51 @@ impl<'errs> Checker<'errs> {
         range: Range,
         function: &ast::Function,
         expected: Option<WithFunctionType>,
+    ) -> CheckedFunction {
+        self.check_function_with_signature(range, function, expected, None)
+    }
+
+    /// Checks a function like [`Checker::check_function`]. If our function is a declaration then
+    /// we already checked its signature in [`Checker::check_function_signature`] so we use the
+    /// types from our signature instead of checking our annotations again.
+    fn check_function_with_signature(
+        &mut self,
+        range: Range,
+        function: &ast::Function,
+        expected: Option<WithFunctionType>,
+        signature: Option<FunctionEntry>,
     ) -> CheckedFunction {
         // When checking a function, we want to add parameters to the block. So introduce a level
         // of nesting in the scope.
         self.scope.nest();
 
+        // We may not break out of a function so our function body can’t see the loops around it.
+        let loops = mem::take(&mut self.loops);
+
+        // We may not return from the function around us either. We only know the return type of
+        // our own function once we check our body so the defaults of our parameters can’t return.
+        let outer_return_type = mem::take(&mut self.return_type);
+
+        // Add our type parameters to scope so that our parameter and return types may reference
+        // them. Our function is generalized over exactly the type parameters the programmer wrote.
+        let type_parameters = match &signature {
+            Some(signature) => {
+                for (name, type_parameter) in function
+                    .type_parameters
+                    .iter()
+                    .zip(&signature.type_parameters)
+                {
+                    self.declare_type_parameter(name, type_parameter.clone());
+                }
+                signature.type_parameters.clone()
+            }
+            None => self.check_type_parameters(&function.type_parameters),
+        };
+
         // Create our parameter vectors which we will push to as we type-check parameters.
         let mut parameters = Vec::with_capacity(function.parameters.len());
         let mut parameter_types = Vec::with_capacity(function.parameters.len());
+        let mut defaults = Vec::with_capacity(function.parameters.len());
 
         // If we have an expected function type, check to make sure that it has the same number of
         // parameters as our actual function expression.
@@ -142,6 +638,19 @@ impl<'errs> Checker<'errs> {
         for i in 0..function.parameters.len() {
             let parameter = &function.parameters[i];
 
+            // The first parameter of a method may be `this`, the instance of our class which our
+            // method was called on.
+            let this = match (&parameter.pattern.kind, &signature) {
+                (ast::PatternKind::This, Some(_)) if i == 0 => self.this.clone(),
+                _ => None,
+            };
+            if let Some(this) = this {
+                parameters.push(Pattern::this(parameter.pattern.range));
+                parameter_types.push(this);
+                defaults.push(None);
+                continue;
+            }
+
             // If we have an expected function type then get our expected function parameter type!
             // If we have more function expression parameters then function type parameters we will
             // return none.
@@ -155,12 +664,25 @@ impl<'errs> Checker<'errs> {
                 None
             };
 
+            // The default value of our parameter once we’ve checked it.
+            let mut checked_default = None;
+
             // Get the type for all of our function parameters. If a function parameter is missing
             // an annotation then we will report an error and will create an unsound error type.
-            let type_ = match (&parameter.annotation, expected_parameter_type) {
+            // Get the type of our parameter’s annotation. If we have a signature then we
+            // already checked our annotation.
+            let annotation = match (&parameter.annotation, &signature) {
+                (Some(annotation), Some(signature)) => {
+                    Some((annotation, signature.parameters[i].clone().unwrap()))
+                }
+                (Some(annotation), None) => Some((annotation, self.check_type(annotation))),
+                (None, _) => None,
+            };
+
+            let type_ = match (annotation, expected_parameter_type) {
                 // If our function parameter has an annotation and no expected type then the type
                 // of our parameter is the type of our annotation.
-                (Some(actual_type), None) => self.check_type(actual_type),
+                (Some((_, actual_type)), None) => actual_type,
 
                 // If our function parameter has no annotation, but it does have an expected type
                 // then use the expected type as the type of our function parameter.
@@ -172,42 +694,75 @@ impl<'errs> Checker<'errs> {
                 // If we have both a parameter type annotation _and_ an expected type then we need
                 // to subtype them. Remember that function parameters are contravariant so we
                 // subtype in the opposite direction.
-                (Some(actual_type), Some((operation, expected_type))) => {
-                    let range = actual_type.range;
-                    let actual_type = self.check_type(actual_type);
+                (Some((annotation, actual_type)), Some((operation, expected_type))) => {
+                    let range = annotation.range();
                     let _ = self.subtype(range, operation, expected_type, &actual_type);
                     actual_type
                 }
 
                 // If we have neither a function parameter annotation or an expected function
-                // parameter type then complain to the programmer that we are missing a function
-                // parameter type annotation.
-                (None, None) => Type::error(self.report_diagnostic(
-                    Diagnostic::missing_function_parameter_type(
-                        parameter.pattern.range,
-                        parameter.pattern.snippet(),
-                    ),
-                )),
+                // parameter type then we use the type of our default value or the class of a
+                // class pattern. Otherwise complain to the programmer that we are missing a
+                // function parameter type annotation.
+                (None, None) => match &parameter.default {
+                    Some(default) => {
+                        let default = self.check_expression(default);
+                        checked_default = Some(default.node);
+                        default.type_
+                    }
+                    None => match self.class_pattern_type(&parameter.pattern) {
+                        Some(type_) => type_,
+                        None => Type::error(self.report_diagnostic(
+                            Diagnostic::missing_function_parameter_type(
+                                parameter.pattern.range,
+                                parameter.pattern.snippet(),
+                            ),
+                        )),
+                    },
+                },
             };
 
+            // Check the default value of our parameter against our parameter’s type. We check
+            // the default before we add our parameter to scope so a default may only reference
+            // the parameters before it.
+            if let Some(default) = &parameter.default {
+                if parameter.annotation.is_some() || expected_parameter_type.is_some() {
+                    let operation = OperationSnippet::FunctionParameterDefault(
+                        parameter.pattern.snippet(),
+                        default.snippet(),
+                    );
+                    let default = self.check_expression_with_type(operation, default, &type_);
+                    checked_default = Some(default.node);
+                }
+            }
+
             // Check the pattern with this parameter’s type annotation.
-            let pattern = self.check_pattern(&parameter.pattern, type_.clone());
+            let pattern = self.check_irrefutable_pattern(&parameter.pattern, type_.clone(), false);
 
             // Add this parameter to our list.
             parameters.push(pattern);
             parameter_types.push(type_);
+            defaults.push(checked_default);
         }
 
+        // We may only await in the body of an async function. Not in the body of a function
+        // nested inside of it.
+        let async_ = mem::replace(&mut self.async_, function.async_);
+
         // Get the body of our function. If our return type was annotated then we need to
         // check that annotation against the function body. If our return type was not annotated
         // then use the inferred type of the function’s body.
         let body: Checked<Block> = match &function.return_type {
             // Check our annotated return type against the body of our function.
             Some(return_type) => {
-                let return_type = self.check_type(return_type);
+                let return_type = match &signature {
+                    Some(signature) => signature.return_.clone().unwrap(),
+                    None => self.check_type(return_type),
+                };
                 let operation = OperationSnippet::FunctionReturnAnnotation(
                     function.body.statements.last().map(ast::Statement::snippet),
                 );
+                self.return_type = Some(Some(return_type.clone()));
                 let body = self.check_block_without_nest(
                     &function.body,
                     Some(WithType::new(operation, &return_type)),
@@ -215,38 +770,175 @@ impl<'errs> Checker<'errs> {
                 Checked::new(return_type, body.node)
             }
 
-            // Infer a type based on our function body and return that.
-            None => self.check_block_without_nest(&function.body, None),
+            // Infer a type based on our function body and return that. If we return early then
+            // the type of our first `return` statement is our return type instead. Like we
+            // annotated our function with that type.
+            None => {
+                self.return_type = Some(None);
+                let body = self.check_block_without_nest(&function.body, None);
+                match self.return_type.take() {
+                    Some(Some(return_type)) => {
+                        let operation = OperationSnippet::FunctionReturnAnnotation(
+                            function.body.statements.last().map(ast::Statement::snippet),
+                        );
+                        let _ = self.subtype(
+                            function.body.return_range(),
+                            &operation,
+                            &body.type_,
+                            &return_type,
+                        );
+                        Checked::new(return_type, body.node)
+                    }
+                    _ => body,
+                }
+            }
+        };
+
+        // The range of our return type annotation (if we have one) or the range of the last
+        // statement in the function body.
+        let return_range = match &function.return_type {
+            Some(return_type) => return_type.range(),
+            None => function.body.return_range(),
+        };
+
+        // An async function returns a promise of the value its body evaluates to.
+        let return_type = if function.async_ {
+            Type::promise(return_range, body.type_)
+        } else {
+            body.type_
         };
 
         // If we have an expected function type then make sure we verify that the return type
-        // is correct!
+        // is correct! Report any subtyping errors against our return range.
         if let Some(expected) = &expected {
-            // Report any subtyping errors against the return type range (if we have one) or the
-            // range of the last statement in the function body.
-            let range = match &function.return_type {
-                Some(return_type) => return_type.range,
-                None => function.body.return_range(),
-            };
             let _ = self.subtype(
-                range,
+                return_range,
                 &expected.operation,
-                &body.type_,
+                &return_type,
                 &expected.function_type.return_,
             );
         }
 
         // Leave the scope we created for this function.
         self.scope.unnest();
+        self.loops = loops;
+        self.return_type = outer_return_type;
+        self.async_ = async_;
 
         // Return a function and its type.
+        let function_type = Self::function_type(
+            &function.parameters,
+            type_parameters,
+            parameter_types,
+            return_type,
+        );
         CheckedFunction::new(
-            FunctionType::new(parameter_types, body.type_),
-            Function::new(parameters, body.node),
+            function_type,
+            Function::new(function.async_, parameters, defaults, body.node),
         )
     }
 
-    fn check_class_declaration(&mut self, class: &ast::ClassDeclaration) {
+    /// Creates the type of a function from the types of its parameters and its return type. A
+    /// parameter has a name in our type if its pattern is a single binding.
+    fn function_type(
+        parameters: &[ast::FunctionParameter],
+        type_parameters: Vec<TypeParameter>,
+        parameter_types: Vec<Type>,
+        return_type: Type,
+    ) -> FunctionType {
+        let mut function_type = FunctionType::new(type_parameters, parameter_types, return_type);
+        function_type.parameter_names = parameters
+            .iter()
+            .map(|parameter| match &parameter.pattern.kind {
+                ast::PatternKind::Binding(identifier) => Some(identifier.clone()),
+                _ => None,
+            })
+            .collect();
+        function_type.parameter_defaults = parameters
+            .iter()
+            .map(|parameter| parameter.default.is_some())
+            .collect();
+        function_type
+    }
+
+    /// Declares a type for each type parameter in the current scope and returns our
+    /// type parameters.
+    fn check_type_parameters(&mut self, type_parameters: &[ast::Name]) -> Vec<TypeParameter> {
+        self.check_duplicate_type_parameters(type_parameters);
+        let mut checked_type_parameters = Vec::with_capacity(type_parameters.len());
+        for name in type_parameters {
+            let type_parameter = self.new_type_parameter(name);
+            self.declare_type_parameter(name, type_parameter.clone());
+            checked_type_parameters.push(type_parameter);
+        }
+        checked_type_parameters
+    }
+
+    /// Reports an error for every type parameter with the same name as a type parameter before it
+    /// in the same list. References to the name will get the last type parameter.
+    fn check_duplicate_type_parameters(&mut self, type_parameters: &[ast::Name]) {
+        for (i, name) in type_parameters.iter().enumerate() {
+            if let Some(other) = type_parameters[..i]
+                .iter()
+                .find(|other| other.identifier == name.identifier)
+            {
+                self.report_diagnostic(Diagnostic::declaration_name_already_used(
+                    name.range,
+                    name.identifier.clone(),
+                    other.range,
+                ));
+            }
+        }
+    }
+
+    /// Creates a new type parameter with a unique identifier.
+    fn new_type_parameter(&mut self, name: &ast::Name) -> TypeParameter {
+        let type_parameter = TypeParameter {
+            id: TypeParameterId(self.next_type_parameter_id),
+            name: name.identifier.clone(),
+        };
+        self.next_type_parameter_id += 1;
+        type_parameter
+    }
+
+    /// Declares a type for our type parameter in the current scope.
+    fn declare_type_parameter(&mut self, name: &ast::Name, type_parameter: TypeParameter) {
+        self.scope.declare(
+            name.identifier.clone(),
+            ScopeEntry {
+                range: name.range,
+                kind: ScopeEntryKind::Type(Type::Ok {
+                    range: name.range,
+                    kind: TypeKind::Parameter(type_parameter),
+                }),
+            },
+        );
+    }
+
+    /// Adds a class to our class table and returns its identifier. We don’t know what our class
+    /// extends or what fields it has until we call [`Checker::check_class_declaration`].
+    fn declare_class(&mut self, class: &ast::ClassDeclaration) -> ClassId {
+        let id = ClassId(self.classes.len() as u32);
+        let type_parameters = class
+            .type_parameters
+            .iter()
+            .map(|name| self.new_type_parameter(name))
+            .collect();
+        self.classes.push(ClassEntry {
+            name: class.name.identifier.clone(),
+            range: class.name.range,
+            base: class.base,
+            type_parameters,
+            extends: None,
+            fields: Vec::new(),
+            methods: Vec::new(),
+            static_methods: Vec::new(),
+            signatures: Vec::new(),
+        });
+        id
+    }
+
+    fn check_class_declaration(&mut self, id: ClassId, class: &ast::ClassDeclaration) {
         // Check to make sure that we are extending a base class.
         if let Some(extends) = &class.extends {
             match self.scope.resolve_name(extends) {
@@ -255,12 +947,32 @@ impl<'errs> Checker<'errs> {
                     self.report_diagnostic(diagnostic);
                 }
 
-                // If the identifier is a base class then yippee skippy!
+                // If the identifier is a base class then yippee skippy! As long as extending the
+                // base class does not create a cycle.
                 Ok(ScopeEntry {
-                    kind: ScopeEntryKind::Class { base },
-                    ..
-                }) if *base => {
-                    // TODO: Class extension cycle error?
+                    range,
+                    kind: ScopeEntryKind::Class(extends_id),
+                }) if self.class(*extends_id).base => {
+                    let declaration_range = *range;
+                    let extends_id = *extends_id;
+                    let extends_type_parameters = self.class(extends_id).type_parameters.len();
+                    if self.is_subclass(extends_id, id) {
+                        self.report_diagnostic(Diagnostic::declaration_cycle_detected(
+                            extends.range,
+                            extends.identifier.clone(),
+                            declaration_range,
+                        ));
+                    } else if extends_type_parameters != 0 {
+                        // We have no way to write type arguments for the class we extend.
+                        self.report_diagnostic(Diagnostic::incompatible_type_argument_lengths(
+                            extends.range,
+                            OperationSnippet::TypeReference(extends.identifier.clone()),
+                            (extends.range, 0),
+                            (declaration_range, extends_type_parameters),
+                        ));
+                    } else {
+                        self.classes[id.0 as usize].extends = Some(extends_id);
+                    }
                 }
 
                 // If the identifier is not a base class report an error!
@@ -273,6 +985,260 @@ impl<'errs> Checker<'errs> {
                 }
             }
         }
+
+        // Check the types of our fields with our class’s type parameters in scope.
+        self.check_duplicate_type_parameters(&class.type_parameters);
+        self.scope.nest();
+        let type_parameters = self.class(id).type_parameters.clone();
+        let mut this_arguments = Vec::with_capacity(type_parameters.len());
+        for (name, type_parameter) in class.type_parameters.iter().zip(type_parameters) {
+            this_arguments.push(Type::Ok {
+                range: name.range,
+                kind: TypeKind::Parameter(type_parameter.clone()),
+            });
+            self.declare_type_parameter(name, type_parameter);
+        }
+        let mut fields = Vec::new();
+        for member in &class.members {
+            if let ast::ClassMember::Field(field) = member {
+                let type_ = self.check_type(&field.value);
+                fields.push((field.name.identifier.clone(), type_));
+            }
+        }
+
+        // Check the signatures of our methods. Code may call our instance methods on an instance of
+        // our class. The `this` parameter of an instance method is an instance of our class.
+        let this = Type::class(
+            class.name.range,
+            id,
+            class.name.identifier.clone(),
+            this_arguments,
+        );
+        let mut methods = Vec::new();
+        let mut static_methods = Vec::new();
+        let mut signatures = Vec::new();
+        for member in &class.members {
+            let (name, type_parameters, parameters, return_type, async_) = match member {
+                ast::ClassMember::Field(_) => continue,
+                ast::ClassMember::Method(method) => (
+                    &method.name,
+                    &method.function.type_parameters,
+                    &method.function.parameters,
+                    method.function.return_type.as_ref(),
+                    method.function.async_,
+                ),
+                ast::ClassMember::BaseMethod(method) => (
+                    &method.name,
+                    &method.type_parameters,
+                    &method.parameters,
+                    Some(&method.return_type),
+                    false,
+                ),
+            };
+            let signature = if let Some(ast::FunctionParameter {
+                pattern:
+                    ast::Pattern {
+                        kind: ast::PatternKind::This,
+                        ..
+                    },
+                ..
+            }) = parameters.first()
+            {
+                let mut signature = self.check_method_signature(
+                    name.range,
+                    type_parameters,
+                    &parameters[1..],
+                    return_type,
+                    async_,
+                );
+                methods.push((name.identifier.clone(), name.range, signature.type_.clone()));
+                signature.parameters.insert(0, Some(this.clone()));
+                signature
+            } else {
+                let signature = self.check_method_signature(
+                    name.range,
+                    type_parameters,
+                    parameters,
+                    return_type,
+                    async_,
+                );
+                static_methods.push((name.identifier.clone(), name.range, signature.type_.clone()));
+                signature
+            };
+            // Base methods don’t have a body to check.
+            if let ast::ClassMember::Method(_) = member {
+                signatures.push(signature);
+            }
+        }
+        self.scope.unnest();
+        self.classes[id.0 as usize].fields = fields;
+        self.classes[id.0 as usize].methods = methods;
+        self.classes[id.0 as usize].static_methods = static_methods;
+        self.classes[id.0 as usize].signatures = signatures;
+    }
+
+    /// Checks the bodies of the methods of a class we checked in
+    /// [`Checker::check_class_declaration`] with our class’s type parameters in scope. Returns the
+    /// function of every method with a body in the order they are declared.
+    fn check_class_methods(&mut self, id: ClassId, class: &ast::ClassDeclaration) -> Vec<Function> {
+        self.scope.nest();
+        let type_parameters = self.class(id).type_parameters.clone();
+        for (name, type_parameter) in class.type_parameters.iter().zip(type_parameters) {
+            self.declare_type_parameter(name, type_parameter);
+        }
+        let signatures = self.class(id).signatures.clone();
+        let methods = class.members.iter().filter_map(|member| match member {
+            ast::ClassMember::Method(method) => Some(method),
+            _ => None,
+        });
+        let mut functions = Vec::with_capacity(signatures.len());
+        for (method, signature) in methods.zip(signatures) {
+            // We may reference `this` in the body of a method with a `this` parameter.
+            let this = match &method.function.parameters.first() {
+                Some(ast::FunctionParameter {
+                    pattern:
+                        ast::Pattern {
+                            kind: ast::PatternKind::This,
+                            ..
+                        },
+                    ..
+                }) => signature.parameters[0].clone(),
+                _ => None,
+            };
+            let this = mem::replace(&mut self.this, this);
+            let function = self.check_function_with_signature(
+                method.name.range,
+                &method.function,
+                None,
+                Some(signature),
+            );
+            self.this = this;
+            functions.push(function.node);
+        }
+        self.scope.unnest();
+        functions
+    }
+
+    /// Creates the AVT node for a class we checked in [`Checker::check_class_declaration`]. Takes
+    /// the functions of our methods from [`Checker::check_class_methods`].
+    fn class_declaration(
+        &self,
+        id: ClassId,
+        class: &ast::ClassDeclaration,
+        functions: Vec<Function>,
+    ) -> ClassDeclaration {
+        let entry = self.class(id);
+        let fields = entry
+            .fields
+            .iter()
+            .map(|(name, type_)| ClassField {
+                name: name.clone(),
+                type_: type_.clone(),
+            })
+            .collect();
+        let mut functions = functions.into_iter();
+        let mut methods = Vec::new();
+        for member in &class.members {
+            let (name, base) = match member {
+                ast::ClassMember::Field(_) => continue,
+                ast::ClassMember::Method(method) => (&method.name, false),
+                ast::ClassMember::BaseMethod(method) => (&method.name, true),
+            };
+            let find = |methods: &[(Identifier, Range, Option<Type>)]| {
+                methods
+                    .iter()
+                    .find(|(method, range, _)| *method == name.identifier && *range == name.range)
+                    .map(|(_, _, type_)| type_.clone())
+            };
+            let (static_, type_) = match find(&entry.methods) {
+                Some(type_) => (false, type_),
+                None => (true, find(&entry.static_methods).and_then(|type_| type_)),
+            };
+            methods.push(ClassMethod {
+                static_,
+                base,
+                name: name.identifier.clone(),
+                type_,
+                function: if base { None } else { functions.next() },
+            });
+        }
+        let mut declaration = ClassDeclaration::new(
+            class.export,
+            class.name.identifier.clone(),
+            class.name.range,
+            entry.type_parameters.clone(),
+            entry
+                .extends
+                .map(|extends| self.class(extends).name.clone()),
+            fields,
+            methods,
+        );
+        declaration.base = class.base;
+        declaration.inline = class.has_attribute(ast::AttributeKind::Inline);
+        declaration.inherited_fields = match entry.extends {
+            Some(extends) => self
+                .class_fields(extends)
+                .into_iter()
+                .map(|(name, type_)| ClassField { name, type_ })
+                .collect(),
+            None => Vec::new(),
+        };
+        declaration
+    }
+
+    /// Checks the signature of a method without its `this` parameter. Like a function declaration
+    /// we only know the type of our method if all of its parameters and its return type
+    /// are annotated.
+    fn check_method_signature(
+        &mut self,
+        range: Range,
+        type_parameters: &[ast::Name],
+        parameters: &[ast::FunctionParameter],
+        return_type: Option<&ast::Type>,
+        async_: bool,
+    ) -> FunctionEntry {
+        self.scope.nest();
+        let type_parameters = self.check_type_parameters(type_parameters);
+        let parameter_types: Vec<_> = parameters
+            .iter()
+            .map(|parameter| {
+                parameter
+                    .annotation
+                    .as_ref()
+                    .map(|annotation| self.check_type(annotation))
+            })
+            .collect();
+        let return_ = return_type.map(|return_type| self.check_type(return_type));
+        self.scope.unnest();
+
+        // An async method returns a promise of its annotated return type.
+        let type_ = match (parameter_types.iter().cloned().collect(), &return_) {
+            (Some(parameter_types), Some(return_)) => {
+                let return_ = if async_ {
+                    Type::promise(return_type.unwrap().range(), return_.clone())
+                } else {
+                    return_.clone()
+                };
+                let function_type = Self::function_type(
+                    parameters,
+                    type_parameters.clone(),
+                    parameter_types,
+                    return_,
+                );
+                Some(Type::Ok {
+                    range,
+                    kind: TypeKind::Function(Rc::new(function_type)),
+                })
+            }
+            _ => None,
+        };
+
+        FunctionEntry {
+            type_parameters,
+            parameters: parameter_types,
+            return_,
+            type_,
+        }
     }
 
     fn check_block(&mut self, block: &ast::Block, expected: Option<WithType>) -> Checked<Block> {
@@ -313,6 +1279,14 @@ impl<'errs> Checker<'errs> {
             statements.push(statement);
         }
 
+        // Now that we have checked every statement which may assign to the variables our block
+        // declares we know which of our bindings are reassigned.
+        for statement in &mut statements {
+            if let StatementKind::Binding(binding) = &mut statement.kind {
+                binding.reassigned = self.is_assigned(&binding.pattern);
+            }
+        }
+
         // If there were no statements then create a new void type.
         let block_type = match block_type {
             Some(block_type) => block_type,
@@ -366,14 +1340,22 @@ impl<'errs> Checker<'errs> {
                         &binding.value,
                         &annotation,
                     );
-                    let pattern = self.check_pattern(&binding.pattern, annotation);
+                    let pattern = self.check_irrefutable_pattern(
+                        &binding.pattern,
+                        annotation,
+                        binding.mutable,
+                    );
                     Checked::new(
                         Type::void(range),
                         Statement::binding(range, pattern, value.node),
                     )
                 } else {
                     let value = self.check_expression(&binding.value);
-                    let pattern = self.check_pattern(&binding.pattern, value.type_);
+                    let pattern = self.check_irrefutable_pattern(
+                        &binding.pattern,
+                        value.type_,
+                        binding.mutable,
+                    );
                     Checked::new(
                         Type::void(range),
                         Statement::binding(range, pattern, value.node),
@@ -381,17 +1363,43 @@ impl<'errs> Checker<'errs> {
                 }
             }
 
-            ast::StatementKind::Return(_) => unimplemented!(),
+            ast::StatementKind::Assignment(assignment) => self.check_assignment(range, assignment),
+
+            ast::StatementKind::Return(argument) => self.check_return(range, argument.as_ref()),
+
+            ast::StatementKind::Break(break_) => self.check_break(range, break_),
+
+            // Make sure we are continuing a loop we are inside of. If we aren’t then we insert an
+            // error statement which will panic at runtime. A continue statement never finishes
+            // executing so its type is never.
+            ast::StatementKind::Continue(continue_) => {
+                let statement = if self.find_loop(continue_.label.as_ref()).is_none() {
+                    let diagnostic = match &continue_.label {
+                        Some(label) => {
+                            Diagnostic::loop_label_not_found(label.range, label.identifier.clone())
+                        }
+                        None => Diagnostic::continue_outside_loop(range),
+                    };
+                    let error = self.report_diagnostic(diagnostic);
+                    Statement::error(range, error, Vec::new())
+                } else {
+                    let label = continue_.label.as_ref();
+                    Statement::continue_(range, label.map(|label| label.identifier.clone()))
+                };
+                Checked::new(Type::never(range), statement)
+            }
+
+            // We could not parse this statement so we insert an error statement which will panic
+            // at runtime.
+            ast::StatementKind::Error(error) => Checked::new(
+                Type::error(error.clone()),
+                Statement::error(range, error.clone(), Vec::new()),
+            ),
         };
 
         // If we have an expected type then let’s subtype it against our actual type.
         if let Some(expected) = expected {
-            let _ = self.subtype(
-                range,
-                &expected.operation,
-                &statement.type_,
-                &expected.type_,
-            );
+            let _ = self.subtype(range, &expected.operation, &statement.type_, expected.type_);
         }
 
         statement
@@ -400,10 +1408,12 @@ impl<'errs> Checker<'errs> {
     fn check_constant(&mut self, range: Range, constant: &ast::Constant) -> Type {
         match constant {
             ast::Constant::Boolean(_) => Type::boolean(range),
+            ast::Constant::Null => Type::null(range),
             ast::Constant::Integer(ast::IntegerBase::Decimal, _) => Type::number(range),
             ast::Constant::Integer(ast::IntegerBase::Binary, _) => Type::integer(range),
             ast::Constant::Integer(ast::IntegerBase::Hexadecimal, _) => Type::integer(range),
             ast::Constant::Float(_) => Type::float(range),
+            ast::Constant::String(_) => Type::string(range),
         }
     }
 
@@ -457,24 +1467,78 @@ impl<'errs> Checker<'errs> {
                     }
 
                     Ok(entry) => match &entry.kind {
-                        ScopeEntryKind::Type(_) => unimplemented!(),
-                        ScopeEntryKind::Function => unimplemented!(),
-                        ScopeEntryKind::Class { .. } => unimplemented!(),
+                        ScopeEntryKind::Type(_)
+                        | ScopeEntryKind::TypeAlias(_)
+                        | ScopeEntryKind::List
+                        | ScopeEntryKind::Promise => unimplemented!(),
+
+                        // A class is not a value. We may only access its static methods which we
+                        // check along with member expressions.
+                        ScopeEntryKind::Class(_) => {
+                            let diagnostic =
+                                Diagnostic::class_as_value(range, identifier.clone(), entry.range);
+                            let diagnostic = self.report_diagnostic(diagnostic);
+                            Checked::new(
+                                Type::error(diagnostic.clone()),
+                                Expression::error(range, diagnostic, None),
+                            )
+                        }
+
+                        // If we are referencing a function declaration then return the type of
+                        // our function. If we don’t know the type of our function yet then
+                        // report an error.
+                        ScopeEntryKind::Function(index) => match &self.functions[*index].type_ {
+                            Some(type_) => Checked::new(
+                                type_.clone(),
+                                Expression::reference(range, identifier.clone()),
+                            ),
+                            None => {
+                                let diagnostic =
+                                    self.report_diagnostic(Diagnostic::function_type_unknown(
+                                        range,
+                                        identifier.clone(),
+                                        entry.range,
+                                    ));
+                                Checked::new(
+                                    Type::error(diagnostic.clone()),
+                                    Expression::error(range, diagnostic, None),
+                                )
+                            }
+                        },
 
                         // If we are referencing a value then return that.
-                        ScopeEntryKind::Value(type_) => Checked::new(
-                            type_.clone(),
-                            Expression::reference(range, identifier.clone()),
-                        ),
+                        ScopeEntryKind::Value(type_) | ScopeEntryKind::Variable(type_) => {
+                            Checked::new(
+                                type_.clone(),
+                                Expression::reference(range, identifier.clone()),
+                            )
+                        }
                     },
                 }
             }
 
-            ast::ExpressionKind::This => unimplemented!(),
+            // We may only reference `this` in the body of a method with a `this` parameter.
+            ast::ExpressionKind::This => match &self.this {
+                Some(this) => Checked::new(this.clone(), Expression::this(range)),
+                None => {
+                    let error = self.report_diagnostic(Diagnostic::this_outside_method(range));
+                    Checked::new(
+                        Type::error(error.clone()),
+                        Expression::error(range, error, None),
+                    )
+                }
+            },
 
             ast::ExpressionKind::Function(function) => {
-                // Attempt to narrow our expected type to a function type.
-                let expected = match expected.take() {
+                // Attempt to narrow our expected type to a function type. We don’t narrow for
+                // generic functions. Instead we subtype our generic function against the expected
+                // type once we’ve checked it which instantiates our function’s type parameters.
+                let expected = if function.type_parameters.is_empty() {
+                    expected.take()
+                } else {
+                    None
+                };
+                let expected = match expected {
                     None => None,
                     Some(expected) => match expected.type_ {
                         // An error type is the supertype of everything.
@@ -487,7 +1551,7 @@ impl<'errs> Checker<'errs> {
                         } => Some(WithFunctionType::new(
                             expected.operation,
                             *range,
-                            &**function_type,
+                            function_type,
                         )),
 
                         // For everything else, report an error.
@@ -514,109 +1578,381 @@ impl<'errs> Checker<'errs> {
             }
 
             // Call a function type with some arguments...
-            ast::ExpressionKind::Call(call) => {
-                // We can infer either the type of function we are calling or we can infer the
-                // argument types. We choose to infer the argument types using the callee type which
-                // is why we don’t provide a type here.
-                let callee = self.check_expression(&call.callee);
-
-                // Narrow the callee type down to only function types. Error for any
-                // non-function types.
-                let callee_type = match callee.type_ {
-                    // An error type is the supertype of everything.
-                    Type::Error { error } => Err(error),
-
-                    // Function types may actually be called!
-                    Type::Ok {
-                        range,
-                        kind: TypeKind::Function(function_type),
-                    } => Ok((range, function_type)),
+            ast::ExpressionKind::Call(call) => self.check_call(
+                range,
+                &call.callee,
+                &call.type_arguments,
+                None,
+                &call.arguments,
+                &call.named_arguments,
+            ),
 
-                    // For everything else, report an error.
-                    Type::Ok {
-                        range: callee_range,
-                        kind: callee_type_kind,
-                    } => Err(self.report_diagnostic(Diagnostic::cannot_call(
-                        call.callee.range,
-                        callee_range,
-                        callee_type_kind.snippet(),
-                    ))),
-                };
+            // Construct an instance of a class with a value for each of its fields.
+            ast::ExpressionKind::Construct(construct) => self.check_construct(range, construct),
 
-                match callee_type {
-                    // If we have a function type then make sure to check our stuffs!
-                    Ok((callee_type_range, callee_type)) => {
-                        // If we called the function with an incorrect number of arguments then
-                        // report an error with the correct number of arguments.
-                        if call.arguments.len() != callee_type.parameters.len() {
-                            self.report_diagnostic(
-                                Diagnostic::incompatible_function_parameter_lengths(
-                                    call.callee.range,
-                                    OperationSnippet::FunctionCall(call.callee.snippet()),
-                                    (expression.range, call.arguments.len()),
-                                    (callee_type_range, callee_type.parameters.len()),
-                                ),
-                            );
-                        }
+            // Access a static method on a class.
+            ast::ExpressionKind::Member(member)
+                if self.class_reference(&member.object).is_some() =>
+            {
+                self.check_static_member(range, member)
+            }
 
-                        // Check all the arguments in our call expression for type errors...
-                        for i in 0..call.arguments.len() {
-                            let argument = &call.arguments[i];
-
-                            // If our expected callee type has a parameter in the same position as
-                            // this one then let’s check our expression with that argument type.
-                            if i < callee_type.parameters.len() {
-                                self.check_expression_with_type(
-                                    OperationSnippet::FunctionCall(call.callee.snippet()),
-                                    argument,
-                                    &callee_type.parameters[i],
+            // Access a field on an instance of a class. Every other type has no members. If our
+            // object might be `null` then the programmer needs to check for `null` first.
+            ast::ExpressionKind::Member(member) => {
+                let object = self.check_expression(&member.object);
+                let property = &member.property;
+                let member_type = match &object.type_ {
+                    Type::Error { error } => Type::error(error.clone()),
+                    Type::Ok {
+                        kind: TypeKind::Class(class),
+                        ..
+                    } => {
+                        let mut instantiation =
+                            Instantiation::new(&self.class(class.id).type_parameters);
+                        for (type_parameter, argument) in self
+                            .class(class.id)
+                            .type_parameters
+                            .iter()
+                            .zip(&class.arguments)
+                        {
+                            instantiation.set(type_parameter, argument.clone());
+                        }
+                        // If we don’t have a field with our name then look for an instance method
+                        // with our name. We need to know the type of our method to call it.
+                        let method = self.class_method(class.id, &property.identifier);
+                        match (self.class_field(class.id, &property.identifier), method) {
+                            (Some(field_type), _) => instantiation.apply(field_type),
+                            (None, Some((_, _, Some(method_type)))) => {
+                                instantiation.apply(method_type)
+                            }
+                            (None, Some((_, method_range, None))) => {
+                                let diagnostic = Diagnostic::function_type_unknown(
+                                    property.range,
+                                    property.identifier.clone(),
+                                    *method_range,
                                 );
-                            } else {
-                                self.check_expression(argument);
+                                Type::error(self.report_diagnostic(diagnostic))
+                            }
+                            (None, None) => {
+                                let class = self.class(class.id);
+                                let diagnostic = Diagnostic::field_not_found(
+                                    property.range,
+                                    property.identifier.clone(),
+                                    class.name.clone(),
+                                    class.range,
+                                );
+                                Type::error(self.report_diagnostic(diagnostic))
                             }
                         }
-
-                        // The type of our expression is the type returned by our callee’s
-                        // function type!
-                        Checked::new(
-                            (&*callee_type.return_).clone(),
-                            Expression::unimplemented(range),
-                        )
-                    }
-
-                    // If we have an error type then still make sure to check all our arguments.
-                    // Even if we don’t have any expected types for them.
-                    Err(error) => {
-                        for argument in &call.arguments {
-                            self.check_expression(argument);
-                        }
-                        Checked::new(Type::error(error), Expression::unimplemented(range))
                     }
-                }
+                    Type::Ok {
+                        range: object_range,
+                        kind: object_kind,
+                    } => Type::error(self.report_diagnostic(Diagnostic::cannot_access_member(
+                        member.object.range,
+                        property.identifier.clone(),
+                        *object_range,
+                        object_kind.snippet(),
+                    ))),
+                };
+                Checked::new(
+                    member_type,
+                    Expression::member(range, object.node, property.identifier.clone()),
+                )
             }
 
-            ast::ExpressionKind::Construct(_) => unimplemented!(),
-            ast::ExpressionKind::Member(_) => unimplemented!(),
-
             // Make sure the operand to a prefix expression is of the correct type.
             ast::ExpressionKind::Prefix(prefix) => match prefix.operator {
                 ast::PrefixOperator::Not => {
-                    self.check_expression_with_type(
+                    let operand = self.check_expression_with_type(
                         OperationSnippet::OperatorExpression(OperatorSnippet::Not),
                         &prefix.operand,
                         &Type::boolean(prefix.operand.range),
                     );
                     Checked::new(
                         Type::boolean(expression.range),
-                        Expression::unimplemented(range),
+                        Expression::prefix(range, PrefixOperator::Not, operand.node),
                     )
                 }
 
-                ast::PrefixOperator::Negative => unimplemented!(),
-                ast::PrefixOperator::Positive => unimplemented!(),
+                // Negating a number gives us the same kind of number. Like arithmetic, negating
+                // an integer wraps around.
+                ast::PrefixOperator::Negative | ast::PrefixOperator::Positive => {
+                    let (operator, snippet) = match prefix.operator {
+                        ast::PrefixOperator::Negative => {
+                            (PrefixOperator::Negative, OperatorSnippet::Negative)
+                        }
+                        _ => (PrefixOperator::Positive, OperatorSnippet::Positive),
+                    };
+                    let operand = self.check_expression_with_type(
+                        OperationSnippet::OperatorExpression(snippet),
+                        &prefix.operand,
+                        &Type::number(prefix.operand.range),
+                    );
+                    let type_ = match &operand.type_ {
+                        Type::Error { error } => Type::error(error.clone()),
+                        Type::Ok {
+                            kind: TypeKind::Integer,
+                            ..
+                        } => Type::integer(range),
+                        Type::Ok {
+                            kind: TypeKind::Float,
+                            ..
+                        } => Type::float(range),
+                        Type::Ok { .. } => Type::number(range),
+                    };
+                    let node = match operator {
+                        PrefixOperator::Negative if is_integer(&type_) => {
+                            Expression::integer_negative(range, operand.node)
+                        }
+                        _ => Expression::prefix(range, operator, operand.node),
+                    };
+                    Checked::new(type_, node)
+                }
+
+                ast::PrefixOperator::BitwiseNot => {
+                    let operand = self.check_expression_with_type(
+                        OperationSnippet::OperatorExpression(OperatorSnippet::BitwiseNot),
+                        &prefix.operand,
+                        &Type::integer(prefix.operand.range),
+                    );
+                    Checked::new(
+                        Type::integer(expression.range),
+                        Expression::prefix(range, PrefixOperator::BitwiseNot, operand.node),
+                    )
+                }
             },
 
-            ast::ExpressionKind::Infix(_) => unimplemented!(),
+            // Awaiting a promise gives us the value of the promise. We may only await inside of an
+            // async function, but we still check our operand when we aren’t in one. JavaScript
+            // can’t compile an await outside of an async function so we insert an error node which
+            // will throw at runtime.
+            ast::ExpressionKind::Await(await_) => {
+                let outside_async = if self.async_ {
+                    None
+                } else {
+                    Some(self.report_diagnostic(Diagnostic::await_outside_async(range)))
+                };
+                let operand = self.check_expression(&await_.operand);
+                let value_type = match &operand.type_ {
+                    Type::Error { error } => Type::error(error.clone()),
+                    Type::Ok {
+                        kind: TypeKind::Promise(value),
+                        ..
+                    } => (**value).clone(),
+                    Type::Ok {
+                        range: operand_range,
+                        kind: operand_kind,
+                    } => Type::error(self.report_diagnostic(Diagnostic::cannot_await(
+                        await_.operand.range,
+                        *operand_range,
+                        operand_kind.snippet(),
+                    ))),
+                };
+                let await_ = Expression::await_(range, operand.node);
+                let await_ = match outside_async {
+                    None => await_,
+                    Some(error) => Expression::error(range, error, Some(await_)),
+                };
+                Checked::new(value_type, await_)
+            }
+
+            // The first element of a list decides the type for the rest. Unless we expect a list of
+            // some type in which case we check every element against that type. An empty list has
+            // no elements so it is a list of `Never`.
+            ast::ExpressionKind::List(list) => {
+                let mut element_type = None;
+                let mut elements = Vec::with_capacity(list.elements.len());
+                let expected_element = match &expected {
+                    Some(WithType {
+                        operation,
+                        type_:
+                            Type::Ok {
+                                kind: TypeKind::List(element),
+                                ..
+                            },
+                    }) => Some(WithType::new(operation.clone(), element)),
+                    _ => None,
+                };
+                for element in &list.elements {
+                    let element = match (&expected_element, &element_type) {
+                        (Some(expected_element), _) => self.check_expression_with_type(
+                            expected_element.operation.clone(),
+                            element,
+                            expected_element.type_,
+                        ),
+                        (None, Some(element_type)) => self.check_expression_with_type(
+                            OperationSnippet::ListElement(element.snippet()),
+                            element,
+                            element_type,
+                        ),
+                        (None, None) => {
+                            let element = self.check_expression(element);
+                            element_type = Some(element.type_.clone());
+                            element
+                        }
+                    };
+                    elements.push(element.node);
+                }
+                let element_type = match expected_element {
+                    Some(expected_element) => expected_element.type_.clone(),
+                    None => element_type.unwrap_or_else(|| Type::never(range)),
+                };
+                Checked::new(
+                    Type::list(range, element_type),
+                    Expression::list(range, elements),
+                )
+            }
+
+            // Get a value from a list. Decimal integer constants are numbers so we may index a
+            // list with any number. Like in JavaScript, indexing a list out of bounds or with a
+            // fraction is Undefined Behavior.
+            ast::ExpressionKind::Index(index) => {
+                let object = self.check_expression(&index.object);
+                let index_ = self.check_expression_with_type(
+                    OperationSnippet::ListIndex(index.object.snippet(), index.index.snippet()),
+                    &index.index,
+                    &Type::number(index.index.range),
+                );
+                let element_type = match &object.type_ {
+                    Type::Error { error } => Type::error(error.clone()),
+                    Type::Ok {
+                        kind: TypeKind::List(element),
+                        ..
+                    } => (**element).clone(),
+                    Type::Ok {
+                        range: object_range,
+                        kind: object_kind,
+                    } => Type::error(self.report_diagnostic(Diagnostic::cannot_index(
+                        index.object.range,
+                        *object_range,
+                        object_kind.snippet(),
+                    ))),
+                };
+                Checked::new(
+                    element_type,
+                    Expression::index(range, object.node, index_.node),
+                )
+            }
+
+            ast::ExpressionKind::Infix(infix) => match infix.operator {
+                ast::InfixOperator::Equals => {
+                    self.check_equality(range, infix, OperatorSnippet::Equals)
+                }
+                ast::InfixOperator::NotEquals => {
+                    self.check_equality(range, infix, OperatorSnippet::NotEquals)
+                }
+                ast::InfixOperator::Add
+                | ast::InfixOperator::Subtract
+                | ast::InfixOperator::Multiply
+                | ast::InfixOperator::Divide
+                | ast::InfixOperator::Remainder
+                | ast::InfixOperator::Exponent => {
+                    let operation = OperationSnippet::OperatorExpression(OperatorSnippet::Infix(
+                        infix.operator.clone(),
+                    ));
+                    let left = self.check_expression_with_type(
+                        operation.clone(),
+                        &infix.left,
+                        &Type::number(infix.left.range),
+                    );
+                    let right = self.check_expression_with_type(
+                        operation,
+                        &infix.right,
+                        &Type::number(infix.right.range),
+                    );
+                    let type_ = arithmetic_type(range, &infix.operator, &left.type_, &right.type_);
+                    let operator = infix.operator.clone();
+                    let expression = if is_integer(&type_) {
+                        Expression::integer_infix(range, operator, left.node, right.node)
+                    } else {
+                        Expression::infix(range, operator, left.node, right.node)
+                    };
+                    Checked::new(type_, expression)
+                }
+
+                // Bitwise operators only work on integers since JavaScript converts the operands
+                // of a bitwise operator to 32-bit integers.
+                ast::InfixOperator::BitwiseAnd
+                | ast::InfixOperator::BitwiseOr
+                | ast::InfixOperator::BitwiseXor
+                | ast::InfixOperator::LeftShift
+                | ast::InfixOperator::RightShift
+                | ast::InfixOperator::UnsignedRightShift => {
+                    let operation = OperationSnippet::OperatorExpression(OperatorSnippet::Infix(
+                        infix.operator.clone(),
+                    ));
+                    let left = self.check_expression_with_type(
+                        operation.clone(),
+                        &infix.left,
+                        &Type::integer(infix.left.range),
+                    );
+                    let right = self.check_expression_with_type(
+                        operation,
+                        &infix.right,
+                        &Type::integer(infix.right.range),
+                    );
+                    Checked::new(
+                        Type::integer(range),
+                        Expression::infix(range, infix.operator.clone(), left.node, right.node),
+                    )
+                }
+
+                ast::InfixOperator::LessThan
+                | ast::InfixOperator::LessThanOrEqual
+                | ast::InfixOperator::GreaterThan
+                | ast::InfixOperator::GreaterThanOrEqual => {
+                    let left = self.check_comparison_operand(&infix.operator, &infix.left);
+                    let right = self.check_comparison_operand(&infix.operator, &infix.right);
+                    Checked::new(
+                        Type::boolean(range),
+                        Expression::infix(range, infix.operator.clone(), left, right),
+                    )
+                }
+            },
+
+            // Every operand in a comparison chain must be a number. We also make sure that every
+            // comparison in the chain goes in the same direction as the first. Otherwise a chain
+            // like `a < b > c` would tell us nothing about how `a` relates to `c`.
+            ast::ExpressionKind::Comparison(comparison) => {
+                let first = &comparison.comparisons[0].operator;
+                let mut comparisons = Vec::with_capacity(comparison.comparisons.len());
+                let first_operand = self.check_comparison_operand(first, &comparison.left);
+                let mut left = &comparison.left;
+                for ast::Comparison { operator, right } in &comparison.comparisons {
+                    comparisons.push(Comparison {
+                        operator: operator.clone(),
+                        right: self.check_comparison_operand(operator, right),
+                    });
+                    if operator.is_less_than() != first.is_less_than() {
+                        self.report_diagnostic(Diagnostic::inconsistent_comparison(
+                            left.range.union(right.range),
+                            first.clone(),
+                            operator.clone(),
+                        ));
+                    }
+                    left = right;
+                }
+                Checked::new(
+                    Type::boolean(range),
+                    Expression::comparison(range, first_operand, comparisons),
+                )
+            }
+
+            // Pipe our value into a call as its first argument. If the right-hand-side is not a
+            // call then we call it with only our value.
+            ast::ExpressionKind::Pipe(pipe) => match &pipe.right.kind {
+                ast::ExpressionKind::Call(call) => self.check_call(
+                    range,
+                    &call.callee,
+                    &call.type_arguments,
+                    Some(&pipe.left),
+                    &call.arguments,
+                    &call.named_arguments,
+                ),
+                _ => self.check_call(range, &pipe.right, &[], Some(&pipe.left), &[], &[]),
+            },
 
             // Make sure both operands to a logical expression are of the correct type.
             ast::ExpressionKind::Logical(logical) => {
@@ -640,7 +1976,54 @@ impl<'errs> Checker<'errs> {
                 )
             }
 
-            ast::ExpressionKind::Conditional(_) => unimplemented!(),
+            // A conditional without an else branch has no value when its test fails so we leave
+            // our expected type to be checked against void below.
+            ast::ExpressionKind::Conditional(conditional) => {
+                let expected = if conditional.alternate.is_some() {
+                    expected.take()
+                } else {
+                    None
+                };
+                self.check_conditional(range, conditional, expected)
+            }
+
+            ast::ExpressionKind::Case(case) => self.check_case(range, case, expected.take()),
+
+            // A while loop’s test must be a boolean. A while loop may finish so its type is void.
+            ast::ExpressionKind::While(while_) => {
+                let test = self.check_expression_with_type(
+                    OperationSnippet::WhileTest(while_.test.snippet()),
+                    &while_.test,
+                    &Type::boolean(while_.test.range),
+                );
+                self.loops.push(LoopEntry {
+                    label: while_.label.as_ref().map(|label| label.identifier.clone()),
+                    kind: LoopKind::While,
+                    break_type: None,
+                });
+                let body = self.check_block(&while_.body, None);
+                self.loops.pop();
+                let label = while_.label.as_ref().map(|label| label.identifier.clone());
+                Checked::new(
+                    Type::void(range),
+                    Expression::while_(range, label, test.node, body.node),
+                )
+            }
+
+            // The type of a loop is the type of the values we break out of it with. If we never
+            // break out of our loop then it never finishes so its type is never.
+            ast::ExpressionKind::Loop(loop_) => {
+                self.loops.push(LoopEntry {
+                    label: loop_.label.as_ref().map(|label| label.identifier.clone()),
+                    kind: LoopKind::Loop,
+                    break_type: None,
+                });
+                let body = self.check_block(&loop_.body, None);
+                let loop_type = self.loops.pop().unwrap().break_type;
+                let loop_type = loop_type.unwrap_or_else(|| Type::never(range));
+                let label = loop_.label.as_ref().map(|label| label.identifier.clone());
+                Checked::new(loop_type, Expression::loop_(range, label, body.node))
+            }
 
             // Checking a block is simple.
             ast::ExpressionKind::Block(block) => {
@@ -665,6 +2048,29 @@ impl<'errs> Checker<'errs> {
                     self.check_expression_with_optional_type(&wrapped.expression, expected.take())
                 }
             }
+
+            // Check every expression interpolated into our template and make sure that we know how
+            // to print it. A template is always a string.
+            ast::ExpressionKind::Template(template) => {
+                let mut spans = Vec::with_capacity(template.spans.len());
+                for span in &template.spans {
+                    let expression = self.check_expression(&span.expression);
+                    self.check_printable(span.expression.range, &expression.type_);
+                    spans.push((expression.node, span.text.clone()));
+                }
+                Checked::new(
+                    Type::string(range),
+                    Expression::template(range, template.head.clone(), spans),
+                )
+            }
+
+            // We could not parse this expression so we insert an error expression which will panic
+            // at runtime. The error type will make sure we don’t report any more diagnostics for
+            // this expression.
+            ast::ExpressionKind::Error(error) => Checked::new(
+                Type::error(error.clone()),
+                Expression::error(range, error.clone(), None),
+            ),
         };
 
         // If we have an expected type then let’s subtype it against our actual type.
@@ -673,53 +2079,1530 @@ impl<'errs> Checker<'errs> {
                 range,
                 &expected.operation,
                 &expression.type_,
-                &expected.type_,
+                expected.type_,
             );
         }
 
-        expression
-    }
+        expression
+    }
+
+    /// Checks an assignment statement. We may only assign to variables declared with `let mut` and
+    /// the value we assign must have the variable’s type. A compound assignment like `+=` also
+    /// needs our variable to be a number and the result of its operator to have the
+    /// variable’s type. So we may not use `/=` on an integer.
+    fn check_assignment(
+        &mut self,
+        range: Range,
+        assignment: &ast::AssignmentStatement,
+    ) -> Checked<Statement> {
+        let target = &assignment.target;
+        let target_type = match &target.kind {
+            ast::ExpressionKind::Reference(identifier) => {
+                match self.scope.resolve(&target.range, identifier) {
+                    Err(diagnostic) => Err(diagnostic),
+                    Ok(ScopeEntry {
+                        kind: ScopeEntryKind::Variable(type_),
+                        range: declaration_range,
+                    }) => Ok((identifier.clone(), *declaration_range, type_.clone())),
+                    Ok(ScopeEntry {
+                        kind: ScopeEntryKind::Value(_),
+                        range: declaration_range,
+                    }) => Err(Diagnostic::assign_immutable(
+                        target.range,
+                        identifier.clone(),
+                        *declaration_range,
+                    )),
+                    Ok(_) => Err(Diagnostic::invalid_assignment_target(
+                        target.range,
+                        target.snippet(),
+                    )),
+                }
+            }
+            _ => Err(Diagnostic::invalid_assignment_target(
+                target.range,
+                target.snippet(),
+            )),
+        };
+
+        let statement = match target_type {
+            Ok((identifier, declaration_range, type_)) => {
+                self.assigned.insert(declaration_range);
+                let operation = assignment.operator.as_ref().map(|operator| {
+                    OperationSnippet::OperatorExpression(OperatorSnippet::Assignment(
+                        operator.clone(),
+                    ))
+                });
+                let number = match &operation {
+                    Some(operation) => self
+                        .subtype(target.range, operation, &type_, &Type::number(target.range))
+                        .is_ok(),
+                    None => false,
+                };
+                let value = self.check_expression_with_type(
+                    OperationSnippet::Assignment(target.snippet(), assignment.value.snippet()),
+                    &assignment.value,
+                    &type_,
+                );
+
+                // If our variable is a number then check the result of our compound assignment’s
+                // operator like we would check `x = x + y`. We already checked our value against
+                // the type of our variable so we use that type for both operands. Otherwise we
+                // would report a second error for a value of the wrong type.
+                let mut integer = false;
+                if let (true, Some(operator), Some(operation)) =
+                    (number, &assignment.operator, &operation)
+                {
+                    let operator = operator.infix_operator();
+                    let result = arithmetic_type(range, &operator, &type_, &type_);
+                    integer = is_integer(&result);
+                    let _ = self.subtype(range, operation, &result, &type_);
+                }
+
+                Statement::assignment(
+                    range,
+                    identifier,
+                    assignment.operator.clone(),
+                    integer,
+                    value.node,
+                )
+            }
+
+            // If we can not assign to our target then report an error and insert an error
+            // statement which will panic at runtime. We still check our value.
+            Err(diagnostic) => {
+                let error = self.report_diagnostic(diagnostic);
+                let value = self.check_expression(&assignment.value);
+                Statement::error(range, error, vec![value.node])
+            }
+        };
+
+        // An assignment statement has no value.
+        Checked::new(Type::void(range), statement)
+    }
+
+    /// Checks a break statement. The first break out of a `loop` decides the type of our loop. All
+    /// the breaks after it must have a value of that type. A break statement never finishes
+    /// executing so its type is never.
+    fn check_break(&mut self, range: Range, break_: &ast::BreakStatement) -> Checked<Statement> {
+        let index = self.find_loop(break_.label.as_ref());
+        let error = if index.is_none() {
+            let diagnostic = match &break_.label {
+                Some(label) => {
+                    Diagnostic::loop_label_not_found(label.range, label.identifier.clone())
+                }
+                None => Diagnostic::break_outside_loop(range),
+            };
+            Some(self.report_diagnostic(diagnostic))
+        } else {
+            None
+        };
+        let entry = index.map(|index| {
+            (
+                index,
+                self.loops[index].kind,
+                self.loops[index].break_type.clone(),
+            )
+        });
+
+        let argument = match (entry, &break_.argument) {
+            // If we could not find our loop we still check our argument for errors.
+            (None, Some(argument)) => Some(self.check_expression(argument).node),
+            (None, None) => None,
+
+            // Only `loop` expressions may be exited with a value.
+            (Some((_, LoopKind::While, _)), Some(argument)) => {
+                self.report_diagnostic(Diagnostic::while_break_value(argument.range));
+                Some(self.check_expression(argument).node)
+            }
+            (Some((_, LoopKind::While, _)), None) => None,
+
+            (Some((_, LoopKind::Loop, Some(break_type))), Some(argument)) => {
+                let argument = self.check_expression_with_type(
+                    OperationSnippet::LoopBreak(Some(argument.snippet())),
+                    argument,
+                    &break_type,
+                );
+                Some(argument.node)
+            }
+            (Some((_, LoopKind::Loop, Some(break_type))), None) => {
+                let _ = self.subtype(
+                    range,
+                    &OperationSnippet::LoopBreak(None),
+                    &Type::void(range),
+                    &break_type,
+                );
+                None
+            }
+            (Some((index, LoopKind::Loop, None)), Some(argument)) => {
+                let argument = self.check_expression(argument);
+                self.loops[index].break_type = Some(argument.type_);
+                Some(argument.node)
+            }
+            (Some((index, LoopKind::Loop, None)), None) => {
+                self.loops[index].break_type = Some(Type::void(range));
+                None
+            }
+        };
+
+        // If we could not find our loop then we insert an error statement which will panic
+        // at runtime after evaluating our argument.
+        let statement = match error {
+            None => {
+                let label = break_.label.as_ref().map(|label| label.identifier.clone());
+                Statement::break_(range, label, argument)
+            }
+            Some(error) => Statement::error(range, error, argument.into_iter().collect()),
+        };
+        Checked::new(Type::never(range), statement)
+    }
+
+    /// Checks a return statement. We check the value we return against the return type of the
+    /// function we are in. If that function’s return type is not annotated then the first return
+    /// statement we check picks it. A return statement never finishes executing so its type
+    /// is never.
+    fn check_return(
+        &mut self,
+        range: Range,
+        argument: Option<&ast::Expression>,
+    ) -> Checked<Statement> {
+        let operation = OperationSnippet::FunctionReturn(argument.map(ast::Expression::snippet));
+        let statement = match (self.return_type.clone(), argument) {
+            // If we are not inside a function then we insert an error statement which will panic
+            // at runtime after evaluating our argument.
+            (None, argument) => {
+                let error = self.report_diagnostic(Diagnostic::return_outside_function(range));
+                let argument = argument.map(|argument| self.check_expression(argument).node);
+                Statement::error(range, error, argument.into_iter().collect())
+            }
+
+            (Some(Some(return_type)), Some(argument)) => {
+                let argument = self.check_expression_with_type(operation, argument, &return_type);
+                Statement::return_(range, Some(argument.node))
+            }
+            (Some(Some(return_type)), None) => {
+                let _ = self.subtype(range, &operation, &Type::void(range), &return_type);
+                Statement::return_(range, None)
+            }
+            (Some(None), Some(argument)) => {
+                let argument = self.check_expression(argument);
+                self.return_type = Some(Some(argument.type_));
+                Statement::return_(range, Some(argument.node))
+            }
+            (Some(None), None) => {
+                self.return_type = Some(Some(Type::void(range)));
+                Statement::return_(range, None)
+            }
+        };
+        Checked::new(Type::never(range), statement)
+    }
+
+    /// Finds the index of the loop a break or continue statement with this label references. If
+    /// there is no label then we reference the innermost loop.
+    fn find_loop(&self, label: Option<&ast::Name>) -> Option<usize> {
+        match label {
+            None => self.loops.len().checked_sub(1),
+            Some(label) => self
+                .loops
+                .iter()
+                .rposition(|entry| entry.label.as_ref() == Some(&label.identifier)),
+        }
+    }
+
+    /// Checks a case expression. Every arm of our case expression must return the same type and
+    /// together the arms must match every value our test expression might be.
+    fn check_case(
+        &mut self,
+        range: Range,
+        case: &ast::CaseExpression,
+        expected: Option<WithType>,
+    ) -> Checked<Expression> {
+        let test = self.check_expression(&case.test);
+
+        // The classes our test expression might be an instance of. If our test expression is not
+        // an instance of a class then only a pattern which matches everything will do. If our test
+        // expression has an error type then we don’t check that our case expression is exhaustive
+        // so that we don’t report more diagnostics.
+        let cases = match &test.type_ {
+            Type::Error { .. } => None,
+            Type::Ok {
+                kind: TypeKind::Class(class),
+                ..
+            } => Some(Some(self.concrete_subclasses(class.id))),
+            Type::Ok { .. } => Some(None),
+        };
+
+        // The type of our case expression. If we have an expected type then we check all our arms
+        // against it. Otherwise the first arm decides the type for the rest.
+        let mut case_type = expected.as_ref().map(|expected| expected.type_.clone());
+
+        // The patterns of the arms we have checked so far. If we don’t know what some arm matches
+        // then we don’t report missing cases so that we don’t report more diagnostics.
+        let mut matched: Vec<PatternCoverage> = Vec::new();
+        let mut matched_unknown = false;
+
+        let mut arms = Vec::with_capacity(case.arms.len());
+        for arm in &case.arms {
+            // Check our arm’s pattern and body in a new level of nesting so that the names bound by
+            // our pattern are only available to our arm’s body.
+            self.scope.nest();
+            let (pattern, coverage) = self.check_pattern(&arm.pattern, test.type_.clone(), false);
+
+            // Figure out which values this arm matches. If every one of them is already matched by
+            // an arm before this one then warn the programmer that this arm is useless.
+            match (&cases, coverage) {
+                (None, _) | (_, PatternCoverage::Unknown) => matched_unknown = true,
+                (Some(cases), coverage) => {
+                    // If our pattern matches none of the classes we test then we will have already
+                    // reported an error while checking our pattern.
+                    let matches_none = match (cases, &coverage) {
+                        (Some(cases), PatternCoverage::Class(class_id, _)) => {
+                            !cases.iter().any(|id| self.is_subclass(*id, *class_id))
+                        }
+                        (None, PatternCoverage::Class(_, _)) => true,
+                        _ => false,
+                    };
+                    if matches_none {
+                        matched_unknown = true;
+                    } else if !self.is_useful_pattern(cases, &matched, &coverage) {
+                        self.report_diagnostic(Diagnostic::redundant_case(
+                            arm.pattern.range,
+                            arm.pattern.snippet(),
+                        ));
+                    } else {
+                        matched.push(coverage);
+                    }
+                }
+            }
+
+            let body = match (&expected, &case_type) {
+                (Some(expected), _) => self.check_expression_with_type(
+                    expected.operation.clone(),
+                    &arm.body,
+                    expected.type_,
+                ),
+                (None, Some(case_type)) => self.check_expression_with_type(
+                    OperationSnippet::CaseArm(arm.body.snippet()),
+                    &arm.body,
+                    case_type,
+                ),
+                (None, None) => {
+                    let body = self.check_expression(&arm.body);
+                    case_type = Some(body.type_.clone());
+                    body
+                }
+            };
+            arms.push(CaseArm {
+                pattern,
+                body: body.node,
+            });
+            self.scope.unnest();
+        }
+
+        // Report an error if there are some values that none of our arms match.
+        if let Some(cases) = cases {
+            if !matched_unknown {
+                let missing = match &cases {
+                    None => {
+                        if self.is_useful_pattern(&cases, &matched, &PatternCoverage::Everything) {
+                            Some(VecSnippet::Vec0)
+                        } else {
+                            None
+                        }
+                    }
+                    Some(classes) => {
+                        let missing: Vec<_> = classes
+                            .iter()
+                            .filter(|id| {
+                                let class = PatternCoverage::Class(**id, Vec::new());
+                                self.is_useful_pattern(&cases, &matched, &class)
+                            })
+                            .map(|id| self.class(*id).name.clone())
+                            .collect();
+                        if missing.is_empty() {
+                            None
+                        } else {
+                            Some(VecSnippet::from_iter(missing.into_iter()))
+                        }
+                    }
+                };
+                if let Some(missing) = missing {
+                    self.report_diagnostic(Diagnostic::missing_cases(
+                        case.test.range,
+                        case.test.snippet(),
+                        missing,
+                    ));
+                }
+            }
+        }
+
+        // If we have no arms then our case expression will never return.
+        let case_type = case_type.unwrap_or_else(|| Type::never(range));
+        Checked::new(case_type, Expression::case(range, test.node, arms))
+    }
+
+    /// Tests if a pattern matches some value of a type which none of the patterns we have already
+    /// matched against match. The classes are the classes every instance of our type is an
+    /// instance of. If our type is not a class then we don’t have any classes.
+    fn is_useful_pattern(
+        &self,
+        classes: &Option<Vec<ClassId>>,
+        matched: &[PatternCoverage],
+        pattern: &PatternCoverage,
+    ) -> bool {
+        let columns = [classes.clone()];
+        let rows: Vec<_> = matched.iter().map(|matched| vec![Some(matched)]).collect();
+        self.is_useful_row(&columns, &rows, &[Some(pattern)])
+    }
+
+    /// Tests if a row of patterns matches some values which none of the rows we have already
+    /// matched against match. Every column has the classes of the values we match in that column.
+    /// A pattern of `None` matches everything.
+    ///
+    /// We split our first column by the class of its values whenever some pattern in that column
+    /// matches a class. Then we check the fields of every class as columns of their own. That way
+    /// a group of class patterns which together match every value of every field covers the class
+    /// even when none of them does on its own.
+    fn is_useful_row<'c>(
+        &self,
+        columns: &[Option<Vec<ClassId>>],
+        rows: &[Vec<Option<&'c PatternCoverage>>],
+        row: &[Option<&'c PatternCoverage>],
+    ) -> bool {
+        let (column, rest_columns) = match columns.split_first() {
+            Some(split) => split,
+            None => return rows.is_empty(),
+        };
+        match row[0] {
+            // Our pattern only matches one class and the classes which extend it so split our
+            // column into every one of those classes.
+            Some(PatternCoverage::Class(class_id, _)) => {
+                let classes = match column {
+                    Some(classes) => classes
+                        .iter()
+                        .cloned()
+                        .filter(|id| self.is_subclass(*id, *class_id))
+                        .collect(),
+                    None => self.concrete_subclasses(*class_id),
+                };
+                classes
+                    .into_iter()
+                    .any(|id| self.is_useful_class_row(id, rest_columns, rows, row))
+            }
+
+            // Our pattern matches everything. If the rows we matched against match every class in
+            // our column then we need to check the fields of every class. Otherwise our pattern
+            // is useful when it is useful in the rows which match everything in our column.
+            Some(PatternCoverage::Everything) | Some(PatternCoverage::Unknown) | None => {
+                let matched_classes: Vec<_> = rows
+                    .iter()
+                    .filter_map(|row| match row[0] {
+                        Some(PatternCoverage::Class(id, _)) => Some(*id),
+                        _ => None,
+                    })
+                    .collect();
+                match column {
+                    Some(classes)
+                        if !matched_classes.is_empty()
+                            && classes.iter().all(|id| {
+                                matched_classes
+                                    .iter()
+                                    .any(|class_id| self.is_subclass(*id, *class_id))
+                            }) =>
+                    {
+                        classes
+                            .iter()
+                            .any(|id| self.is_useful_class_row(*id, rest_columns, rows, row))
+                    }
+                    _ => {
+                        let rows: Vec<_> = rows
+                            .iter()
+                            .filter(|row| !matches!(row[0], Some(PatternCoverage::Class(_, _))))
+                            .map(|row| row[1..].to_vec())
+                            .collect();
+                        self.is_useful_row(rest_columns, &rows, &row[1..])
+                    }
+                }
+            }
+        }
+    }
+
+    /// Tests if a row of patterns is useful for the values in our first column which are instances
+    /// of exactly the provided class. We replace our first column with a column for every field
+    /// of our class.
+    fn is_useful_class_row<'c>(
+        &self,
+        id: ClassId,
+        rest_columns: &[Option<Vec<ClassId>>],
+        rows: &[Vec<Option<&'c PatternCoverage>>],
+        row: &[Option<&'c PatternCoverage>],
+    ) -> bool {
+        let fields = self.class_fields(id);
+        let specialize = |row: &[Option<&'c PatternCoverage>]| {
+            let mut specialized: Vec<_> = match row[0] {
+                Some(PatternCoverage::Class(class_id, patterns)) => {
+                    if !self.is_subclass(id, *class_id) {
+                        return None;
+                    }
+                    fields
+                        .iter()
+                        .map(|(name, _)| {
+                            patterns
+                                .iter()
+                                .find(|(field, _)| field == name)
+                                .map(|(_, pattern)| pattern)
+                        })
+                        .collect()
+                }
+                _ => fields.iter().map(|_| None).collect(),
+            };
+            specialized.extend(row[1..].iter().cloned());
+            Some(specialized)
+        };
+        let mut columns: Vec<_> = fields
+            .iter()
+            .map(|(_, type_)| match type_ {
+                Type::Ok {
+                    kind: TypeKind::Class(class),
+                    ..
+                } => Some(self.concrete_subclasses(class.id)),
+                _ => None,
+            })
+            .collect();
+        columns.extend(rest_columns.iter().cloned());
+        let rows: Vec<_> = rows.iter().filter_map(|row| specialize(row)).collect();
+        match specialize(row) {
+            Some(row) => self.is_useful_row(&columns, &rows, &row),
+            None => false,
+        }
+    }
+
+    /// Checks a call to a function. If we have a piped argument then it comes before our other
+    /// arguments and errors for it use pipe wording.
+    fn check_call(
+        &mut self,
+        range: Range,
+        callee: &ast::Expression,
+        type_arguments: &[ast::Type],
+        piped: Option<&ast::Expression>,
+        arguments: &[ast::Expression],
+        named_arguments: &[ast::NamedArgument],
+    ) -> Checked<Expression> {
+        let operation = OperationSnippet::FunctionCall(callee.snippet());
+        let arguments: Vec<_> = piped
+            .map(|piped| {
+                let operation = OperationSnippet::Pipe(piped.snippet(), callee.snippet());
+                (piped, operation)
+            })
+            .into_iter()
+            .chain(
+                arguments
+                    .iter()
+                    .map(|argument| (argument, operation.clone())),
+            )
+            .collect();
+
+        // We can infer either the type of function we are calling or we can infer the
+        // argument types. We choose to infer the argument types using the callee type which
+        // is why we don’t provide a type here.
+        let Checked {
+            type_: callee_type,
+            node: mut callee_node,
+        } = self.check_expression(callee);
+        let mut call_arguments = Vec::with_capacity(arguments.len() + named_arguments.len());
+
+        // Narrow the callee type down to only function types. Error for any
+        // non-function types.
+        let callee_type = match callee_type {
+            // An error type is the supertype of everything.
+            Type::Error { error } => Err(error),
+
+            // Function types may actually be called!
+            Type::Ok {
+                range,
+                kind: TypeKind::Function(function_type),
+            } => Ok((range, function_type)),
+
+            // For everything else, report an error. We replace our callee with an error
+            // expression which will panic at runtime instead of calling a value which is not a
+            // function.
+            Type::Ok {
+                range: callee_range,
+                kind: callee_type_kind,
+            } => {
+                let error = self.report_diagnostic(Diagnostic::cannot_call(
+                    callee.range,
+                    callee_range,
+                    callee_type_kind.snippet(),
+                ));
+                callee_node = Expression::error(callee.range, error.clone(), Some(callee_node));
+                Err(error)
+            }
+        };
+
+        match callee_type {
+            // If we have a function type then make sure to check our stuffs!
+            Ok((callee_type_range, callee_type)) => {
+                // If our function is generic then we need to pick a type for each of its
+                // type parameters. If the programmer wrote type arguments then we use
+                // those. Otherwise we infer our type arguments from our arguments.
+                let mut instantiation = Instantiation::new(&callee_type.type_parameters);
+                if !type_arguments.is_empty() {
+                    let type_arguments: Vec<_> = type_arguments
+                        .iter()
+                        .map(|type_argument| self.check_type(type_argument))
+                        .collect();
+                    if type_arguments.len() != callee_type.type_parameters.len() {
+                        self.report_diagnostic(Diagnostic::incompatible_type_argument_lengths(
+                            callee.range,
+                            operation.clone(),
+                            (range, type_arguments.len()),
+                            (callee_type_range, callee_type.type_parameters.len()),
+                        ));
+                    } else {
+                        for (type_parameter, type_argument) in
+                            callee_type.type_parameters.iter().zip(type_arguments)
+                        {
+                            instantiation.set(type_parameter, type_argument);
+                        }
+                    }
+                }
+
+                // Pick the parameter every argument is passed to. Positional arguments are
+                // passed to the parameter in the same position and named arguments are passed
+                // to the parameter with the same name.
+                //
+                // If we can’t pick a parameter for an argument then we keep the error we
+                // reported for it. We report one error for all the positional arguments we
+                // have too many of below so we don’t have their error yet.
+                let mut provided = vec![false; callee_type.parameters.len()];
+                let mut arguments: Vec<_> = arguments
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, (argument, operation))| {
+                        if i < provided.len() {
+                            provided[i] = true;
+                            (argument, operation, Ok(i))
+                        } else {
+                            (argument, operation, Err(None))
+                        }
+                    })
+                    .collect();
+                let positional_len = arguments.len();
+                for named_argument in named_arguments {
+                    let name = &named_argument.name;
+                    let index = callee_type
+                        .parameter_names
+                        .iter()
+                        .position(|parameter_name| {
+                            parameter_name.as_ref() == Some(&name.identifier)
+                        });
+                    let index = match index {
+                        None => Err(Some(self.report_diagnostic(Diagnostic::unknown_argument(
+                            name.range,
+                            operation.clone(),
+                            name.identifier.clone(),
+                        )))),
+                        Some(i) if provided[i] => Err(Some(self.report_diagnostic(
+                            Diagnostic::duplicate_argument(
+                                name.range,
+                                operation.clone(),
+                                name.identifier.clone(),
+                            ),
+                        ))),
+                        Some(i) => {
+                            provided[i] = true;
+                            Ok(i)
+                        }
+                    };
+                    arguments.push((&named_argument.value, operation.clone(), index));
+                }
+
+                // If we called the function with too many arguments, or with too few arguments
+                // when neither our call has named arguments nor our function has defaults, then
+                // report an error with the correct number of arguments. Otherwise report an
+                // error for every parameter we are missing an argument for.
+                let missing: Vec<_> = (0..provided.len())
+                    .filter(|i| !provided[*i] && !callee_type.parameter_defaults[*i])
+                    .collect();
+                let mut lengths_error = None;
+                if positional_len > provided.len()
+                    || (!missing.is_empty()
+                        && named_arguments.is_empty()
+                        && !callee_type.parameter_defaults.contains(&true))
+                {
+                    lengths_error = Some(self.report_diagnostic(
+                        Diagnostic::incompatible_function_parameter_lengths(
+                            callee.range,
+                            operation.clone(),
+                            (range, arguments.len()),
+                            (callee_type_range, callee_type.parameters.len()),
+                        ),
+                    ));
+                } else {
+                    for i in missing {
+                        match &callee_type.parameter_names[i] {
+                            Some(name) => {
+                                self.report_diagnostic(Diagnostic::missing_argument(
+                                    callee.range,
+                                    operation.clone(),
+                                    name.clone(),
+                                ));
+                            }
+                            None => {
+                                self.report_diagnostic(
+                                    Diagnostic::incompatible_function_parameter_lengths(
+                                        callee.range,
+                                        operation.clone(),
+                                        (range, arguments.len()),
+                                        (callee_type_range, callee_type.parameters.len()),
+                                    ),
+                                );
+                                break;
+                            }
+                        }
+                    }
+                }
+
+                // Check all the arguments in our call for type errors...
+                for (argument, operation, index) in &arguments {
+                    // If our expected callee type has a parameter for this argument then let’s
+                    // check our expression with that parameter type.
+                    //
+                    // If the parameter type references a type parameter we have not picked
+                    // a type for yet then we check our argument without a type and use the
+                    // argument’s type to infer the type parameter.
+                    let argument = if let Ok(i) = index {
+                        let parameter_type = instantiation.apply(&callee_type.parameters[*i]);
+                        if instantiation.is_unknown(&parameter_type) {
+                            let checked = self.check_expression(argument);
+                            instantiation.infer(&parameter_type, &checked.type_);
+                            let _ = self.subtype(
+                                argument.range,
+                                operation,
+                                &checked.type_,
+                                &instantiation.apply(&parameter_type),
+                            );
+                            checked
+                        } else {
+                            self.check_expression_with_type(
+                                operation.clone(),
+                                argument,
+                                &parameter_type,
+                            )
+                        }
+                    } else {
+                        self.check_expression(argument)
+                    };
+                    // We always report an error when we have too many positional arguments.
+                    let parameter = match index {
+                        Ok(i) => Ok(*i),
+                        Err(Some(error)) => Err(error.clone()),
+                        Err(None) => Err(lengths_error.clone().unwrap()),
+                    };
+                    call_arguments.push(CallArgument {
+                        parameter,
+                        value: argument.node,
+                    });
+                }
+
+                // The type of our expression is the type returned by our callee’s
+                // function type! Any type parameters we could not infer a type for will
+                // never have a value so we use `Never`.
+                instantiation.default_to_never(range);
+                Checked::new(
+                    instantiation.apply(&callee_type.return_),
+                    Expression::call(range, callee_node, call_arguments),
+                )
+            }
+
+            // If we have an error type then still make sure to check all our arguments.
+            // Even if we don’t have any expected types for them.
+            Err(error) => {
+                for (i, (argument, _)) in arguments.iter().enumerate() {
+                    call_arguments.push(CallArgument {
+                        parameter: Ok(i),
+                        value: self.check_expression(argument).node,
+                    });
+                }
+                for named_argument in named_arguments {
+                    call_arguments.push(CallArgument {
+                        parameter: Err(error.clone()),
+                        value: self.check_expression(&named_argument.value).node,
+                    });
+                }
+                Checked::new(
+                    Type::error(error),
+                    Expression::call(range, callee_node, call_arguments),
+                )
+            }
+        }
+    }
+
+    /// Checks a member expression which accesses a static method of a class, like
+    /// `Point.origin`. The object of our member expression must be a reference to a class.
+    fn check_static_member(
+        &mut self,
+        range: Range,
+        member: &ast::MemberExpression,
+    ) -> Checked<Expression> {
+        let (id, identifier) = self.class_reference(&member.object).unwrap();
+        let property = &member.property;
+        let type_ = match self.class_static_method(id, &property.identifier) {
+            Some((_, _, Some(method_type))) => method_type.clone(),
+            Some((_, method_range, None)) => {
+                let diagnostic = Diagnostic::function_type_unknown(
+                    property.range,
+                    property.identifier.clone(),
+                    *method_range,
+                );
+                Type::error(self.report_diagnostic(diagnostic))
+            }
+            None => {
+                let class = self.class(id);
+                let diagnostic = Diagnostic::field_not_found(
+                    property.range,
+                    property.identifier.clone(),
+                    class.name.clone(),
+                    class.range,
+                );
+                Type::error(self.report_diagnostic(diagnostic))
+            }
+        };
+        Checked::new(
+            type_,
+            Expression::member(
+                range,
+                Expression::reference(member.object.range, identifier.clone()),
+                property.identifier.clone(),
+            ),
+        )
+    }
+
+    /// Checks a construct expression. We may construct any class which is not a base class. Every
+    /// field of our class, including the fields of the classes it extends, needs a value. If our
+    /// class is generic then we infer its type arguments from the values of our fields.
+    fn check_construct(
+        &mut self,
+        range: Range,
+        construct: &ast::ConstructExpression,
+    ) -> Checked<Expression> {
+        let name = &construct.constructor;
+        let id = match self.scope.resolve_name(name) {
+            Ok(ScopeEntry {
+                kind: ScopeEntryKind::Class(id),
+                ..
+            }) if self.class(*id).base => Err(Diagnostic::cannot_construct_base_class(
+                name.range,
+                name.identifier.clone(),
+                self.class(*id).range,
+            )),
+            Ok(ScopeEntry {
+                kind: ScopeEntryKind::Class(id),
+                ..
+            }) => Ok(*id),
+            Ok(entry) => Err(Diagnostic::can_only_construct_class(
+                name.range,
+                name.identifier.clone(),
+                entry.range,
+            )),
+            Err(diagnostic) => Err(diagnostic),
+        };
+
+        // If we can’t construct our class then report an error and insert an error expression for
+        // our class’s name which will panic at runtime. We still check the values of all our
+        // fields so that we report the errors inside of them.
+        let id = match id {
+            Ok(id) => id,
+            Err(diagnostic) => {
+                let error = self.report_diagnostic(diagnostic);
+                for type_argument in &construct.type_arguments {
+                    self.check_type(type_argument);
+                }
+                let class = Expression::error(name.range, error.clone(), None);
+                let fields = construct
+                    .fields
+                    .iter()
+                    .map(|field| ConstructField {
+                        name: field.name.identifier.clone(),
+                        value: self.check_expression(&field.value).node,
+                    })
+                    .collect();
+                return Checked::new(
+                    Type::error(error),
+                    Expression::construct(range, class, fields),
+                );
+            }
+        };
+
+        let operation = OperationSnippet::Construct(name.identifier.clone());
+        let class_fields = self.class_fields(id);
+        let type_parameters = self.class(id).type_parameters.clone();
+        let mut instantiation = Instantiation::new(&type_parameters);
+
+        // If the programmer wrote type arguments then we use those for our class’s type
+        // parameters. Otherwise we infer our type arguments from the values of our fields.
+        if !construct.type_arguments.is_empty() {
+            let type_arguments: Vec<_> = construct
+                .type_arguments
+                .iter()
+                .map(|type_argument| self.check_type(type_argument))
+                .collect();
+            if type_arguments.len() != type_parameters.len() {
+                let class_range = self.class(id).range;
+                self.report_diagnostic(Diagnostic::incompatible_type_argument_lengths(
+                    name.range,
+                    operation.clone(),
+                    (range, type_arguments.len()),
+                    (class_range, type_parameters.len()),
+                ));
+            } else {
+                for (type_parameter, type_argument) in type_parameters.iter().zip(type_arguments) {
+                    instantiation.set(type_parameter, type_argument);
+                }
+            }
+        }
+
+        // Check the value of every field against the type of that field. If the field’s type
+        // references a type parameter we have not inferred a type for yet then we check our value
+        // without a type and use the value’s type to infer the type parameter.
+        let mut provided = vec![false; class_fields.len()];
+        let mut fields = Vec::with_capacity(construct.fields.len());
+        for field in &construct.fields {
+            let index = class_fields
+                .iter()
+                .position(|(field_name, _)| *field_name == field.name.identifier);
+            let index = match index {
+                Some(i) => i,
+                None => {
+                    let class = self.class(id);
+                    let diagnostic = Diagnostic::field_not_found(
+                        field.name.range,
+                        field.name.identifier.clone(),
+                        class.name.clone(),
+                        class.range,
+                    );
+                    self.report_diagnostic(diagnostic);
+                    fields.push(ConstructField {
+                        name: field.name.identifier.clone(),
+                        value: self.check_expression(&field.value).node,
+                    });
+                    continue;
+                }
+            };
+            provided[index] = true;
+            let field_type = instantiation.apply(&class_fields[index].1);
+            let value = if instantiation.is_unknown(&field_type) {
+                let value = self.check_expression(&field.value);
+                instantiation.infer(&field_type, &value.type_);
+                let _ = self.subtype(
+                    field.value.range,
+                    &operation,
+                    &value.type_,
+                    &instantiation.apply(&field_type),
+                );
+                value
+            } else {
+                self.check_expression_with_type(operation.clone(), &field.value, &field_type)
+            };
+            fields.push(ConstructField {
+                name: field.name.identifier.clone(),
+                value: value.node,
+            });
+        }
+
+        // Report an error for every field we don’t have a value for.
+        for (i, (field_name, _)) in class_fields.iter().enumerate() {
+            if !provided[i] {
+                self.report_diagnostic(Diagnostic::missing_field(
+                    name.range,
+                    operation.clone(),
+                    field_name.clone(),
+                ));
+            }
+        }
+
+        // Any type parameters we could not infer a type for will never have a value so we
+        // use `Never`.
+        instantiation.default_to_never(range);
+        let arguments = type_parameters
+            .into_iter()
+            .map(|type_parameter| {
+                instantiation.apply(&Type::Ok {
+                    range,
+                    kind: TypeKind::Parameter(type_parameter),
+                })
+            })
+            .collect();
+        Checked::new(
+            Type::class(range, id, name.identifier.clone(), arguments),
+            Expression::construct(
+                range,
+                Expression::reference(name.range, name.identifier.clone()),
+                fields,
+            ),
+        )
+    }
+
+    /// Checks an equality test between two values of the same type. We check our right operand
+    /// against the type of our left operand. Unless our left operand is `null` in which case we
+    /// flip our operands so that `null == x` behaves the same as `x == null`.
+    fn check_equality(
+        &mut self,
+        range: Range,
+        infix: &ast::InfixExpression,
+        operator: OperatorSnippet,
+    ) -> Checked<Expression> {
+        let (flipped, first, second) = match &infix.left.kind {
+            ast::ExpressionKind::Constant(ast::Constant::Null) => (true, &infix.right, &infix.left),
+            _ => (false, &infix.left, &infix.right),
+        };
+        let first = self.check_expression(first);
+        let second = self.check_expression_with_type(
+            OperationSnippet::OperatorExpression(operator),
+            second,
+            &first.type_,
+        );
+        // Our operands are evaluated in the order they were written.
+        let (left, right) = if flipped {
+            (second.node, first.node)
+        } else {
+            (first.node, second.node)
+        };
+        Checked::new(
+            Type::boolean(range),
+            Expression::infix(range, infix.operator.clone(), left, right),
+        )
+    }
+
+    /// Checks an operand of a relational operator like `<`. We may only compare numbers.
+    fn check_comparison_operand(
+        &mut self,
+        operator: &ast::InfixOperator,
+        operand: &ast::Expression,
+    ) -> Expression {
+        self.check_expression_with_type(
+            OperationSnippet::OperatorExpression(OperatorSnippet::Infix(operator.clone())),
+            operand,
+            &Type::number(operand.range),
+        )
+        .node
+    }
+
+    /// Checks a conditional expression. If we have an expected type then we check both our
+    /// branches against it. Otherwise our consequent decides the type for our alternate.
+    ///
+    /// If our test compares a value to `null` then we narrow the value’s optional type in the
+    /// branch where we know the value is not `null`.
+    fn check_conditional(
+        &mut self,
+        range: Range,
+        conditional: &ast::ConditionalExpressionIf,
+        expected: Option<WithType>,
+    ) -> Checked<Expression> {
+        let test = self.check_expression_with_type(
+            OperationSnippet::ConditionalTest(conditional.test.snippet()),
+            &conditional.test,
+            &Type::boolean(conditional.test.range),
+        );
+        let (consequent_narrowing, alternate_narrowing) = match self.narrow_null(&conditional.test)
+        {
+            None => (None, None),
+            Some((true, narrowing)) => (Some(narrowing), None),
+            Some((false, narrowing)) => (None, Some(narrowing)),
+        };
+
+        // Without an alternate our conditional has no value if our test fails so its type
+        // is void.
+        let alternate = match &conditional.alternate {
+            Some(alternate) => alternate,
+            None => {
+                self.scope.nest();
+                self.declare_narrowing(consequent_narrowing);
+                let consequent = self.check_block_without_nest(&conditional.consequent, None);
+                self.scope.unnest();
+                let conditional_type = Type::void(range);
+                if let Some(expected) = expected {
+                    let _ = self.subtype(
+                        range,
+                        &expected.operation,
+                        &conditional_type,
+                        expected.type_,
+                    );
+                }
+                return Checked::new(
+                    conditional_type,
+                    Expression::conditional(range, test.node, consequent.node, None),
+                );
+            }
+        };
+
+        self.scope.nest();
+        self.declare_narrowing(consequent_narrowing);
+        let consequent = self.check_block_without_nest(
+            &conditional.consequent,
+            expected
+                .as_ref()
+                .map(|expected| WithType::new(expected.operation.clone(), expected.type_)),
+        );
+        self.scope.unnest();
+        let conditional_type = match &expected {
+            Some(expected) => expected.type_.clone(),
+            None => consequent.type_,
+        };
+        let expected = match expected {
+            Some(expected) => expected,
+            None => WithType::new(OperationSnippet::ConditionalAlternate, &conditional_type),
+        };
+
+        self.scope.nest();
+        self.declare_narrowing(alternate_narrowing);
+        let alternate = match alternate {
+            ast::ConditionalExpressionElse::Else(block) => {
+                let alternate = self.check_block_without_nest(block, Some(expected));
+                Expression::block(block.range, alternate.node)
+            }
+            ast::ConditionalExpressionElse::ElseIf(alternate) => {
+                let alternate_range = alternate.test.range.union(alternate.last_block().range);
+                self.check_conditional(alternate_range, alternate, Some(expected))
+                    .node
+            }
+        };
+        self.scope.unnest();
+
+        Checked::new(
+            conditional_type,
+            Expression::conditional(range, test.node, consequent.node, Some(alternate)),
+        )
+    }
+
+    /// If our test compares a value to `null` then returns the type of our value when it is not
+    /// `null`. Also returns true if our test is true when our value is not `null`. We only narrow
+    /// values and not variables since a variable may be assigned `null` after our test.
+    fn narrow_null(&self, test: &ast::Expression) -> Option<(bool, Narrowing)> {
+        let infix = match &test.kind {
+            ast::ExpressionKind::Infix(infix) => infix,
+            _ => return None,
+        };
+        let not_null = match infix.operator {
+            ast::InfixOperator::NotEquals => true,
+            ast::InfixOperator::Equals => false,
+            _ => return None,
+        };
+        let identifier = match (&infix.left.kind, &infix.right.kind) {
+            (
+                ast::ExpressionKind::Reference(identifier),
+                ast::ExpressionKind::Constant(constant),
+            )
+            | (
+                ast::ExpressionKind::Constant(constant),
+                ast::ExpressionKind::Reference(identifier),
+            ) => match constant {
+                ast::Constant::Null => identifier,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        match self.scope.resolve_maybe(identifier) {
+            Some(ScopeEntry {
+                range,
+                kind:
+                    ScopeEntryKind::Value(Type::Ok {
+                        kind: TypeKind::Optional(type_),
+                        ..
+                    }),
+            }) => Some((
+                not_null,
+                Narrowing {
+                    identifier: identifier.clone(),
+                    range: *range,
+                    type_: (**type_).clone(),
+                },
+            )),
+            _ => None,
+        }
+    }
+
+    /// Declares a value with its narrowed type in our current scope.
+    fn declare_narrowing(&mut self, narrowing: Option<Narrowing>) {
+        if let Some(narrowing) = narrowing {
+            self.declare_value(
+                narrowing.range,
+                narrowing.identifier,
+                narrowing.type_,
+                false,
+            );
+        }
+    }
+
+    /// Gets the type of a class pattern’s class so that a function parameter like `Point { x, y }`
+    /// does not need an annotation. We can’t know the type arguments of a generic class so we
+    /// only use classes without type parameters.
+    fn class_pattern_type(&self, pattern: &ast::Pattern) -> Option<Type> {
+        if let ast::PatternKind::Class(class) = &pattern.kind {
+            if let Some(ScopeEntry {
+                kind: ScopeEntryKind::Class(id),
+                ..
+            }) = self.scope.resolve_maybe(&class.class.identifier)
+            {
+                if self.class(*id).type_parameters.is_empty() {
+                    let name = &class.class;
+                    return Some(Type::class(
+                        name.range,
+                        *id,
+                        name.identifier.clone(),
+                        Vec::new(),
+                    ));
+                }
+            }
+        }
+        None
+    }
+
+    /// Checks a pattern which must match every value of the provided type, like the pattern of a
+    /// binding statement. If the pattern might not match then we report a diagnostic.
+    fn check_irrefutable_pattern(
+        &mut self,
+        pattern: &ast::Pattern,
+        type_: Type,
+        mutable: bool,
+    ) -> Pattern {
+        let (checked_pattern, coverage) = self.check_pattern(pattern, type_, mutable);
+        match coverage {
+            PatternCoverage::Everything | PatternCoverage::Unknown => {}
+            PatternCoverage::Class(_, _) => {
+                self.report_diagnostic(Diagnostic::refutable_pattern(
+                    pattern.range,
+                    pattern.snippet(),
+                ));
+            }
+        }
+        checked_pattern
+    }
+
+    /// Checks a pattern which is supposed to bind a value with the provided type. If the pattern
+    /// is of a different type, say we are trying to bind a number to an object pattern, then we
+    /// will report a diagnostic.
+    ///
+    /// Also returns the values of our type which the pattern matches. If `mutable` is true then the
+    /// names bound by our pattern may be assigned to.
+    fn check_pattern(
+        &mut self,
+        pattern: &ast::Pattern,
+        type_: Type,
+        mutable: bool,
+    ) -> (Pattern, PatternCoverage) {
+        let range = pattern.range;
+
+        match &pattern.kind {
+            // Declare a value variable in this scope with the pattern’s binding identifier.
+            ast::PatternKind::Binding(identifier) => {
+                self.declare_value(range, identifier.clone(), type_, mutable);
+                (
+                    Pattern::binding(range, identifier.clone()),
+                    PatternCoverage::Everything,
+                )
+            }
+
+            // A hole binds nothing so it may match any type.
+            ast::PatternKind::Hole => (Pattern::hole(range), PatternCoverage::Everything),
+
+            // We check the `this` parameter of a method in
+            // `Checker::check_function_with_signature`. Anywhere else `this` is an error.
+            ast::PatternKind::This => {
+                let error = self.report_diagnostic(Diagnostic::this_outside_method(range));
+                (Pattern::error(range, error), PatternCoverage::Unknown)
+            }
+
+            ast::PatternKind::Class(class) => {
+                self.check_class_pattern(range, class, type_, mutable)
+            }
+
+            // We could not parse this pattern so it binds nothing.
+            ast::PatternKind::Error(error) => (
+                Pattern::error(range, error.clone()),
+                PatternCoverage::Unknown,
+            ),
+        }
+    }
+
+    /// Checks a class pattern. The class must either extend the type of our value, so that we may
+    /// test if our value is an instance of that class, or be a class our value’s type extends.
+    /// Every field we match against must exist on the class.
+    fn check_class_pattern(
+        &mut self,
+        range: Range,
+        pattern: &ast::ClassPattern,
+        type_: Type,
+        mutable: bool,
+    ) -> (Pattern, PatternCoverage) {
+        let name = &pattern.class;
+        let id = match self.scope.resolve_name(name) {
+            Ok(ScopeEntry {
+                kind: ScopeEntryKind::Class(id),
+                ..
+            }) => Ok(*id),
+            Ok(entry) => Err(Diagnostic::can_only_match_class(
+                name.range,
+                name.identifier.clone(),
+                entry.range,
+            )),
+            Err(diagnostic) => Err(diagnostic),
+        };
+
+        // If we could not find our class then report an error. We still check all our fields with
+        // an error type so that we don’t report errors every time a name they bind is referenced.
+        let id = match id {
+            Ok(id) => id,
+            Err(diagnostic) => {
+                let error = self.report_diagnostic(diagnostic);
+                for field in &pattern.fields {
+                    self.check_class_pattern_field(field, Type::error(error.clone()), mutable);
+                }
+                return (Pattern::error(range, error), PatternCoverage::Unknown);
+            }
+        };
+
+        // If our value is an instance of exactly this class then we know the types of our class’s
+        // type parameters. Otherwise we know nothing about them.
+        let arguments = match &type_ {
+            Type::Ok {
+                kind: TypeKind::Class(class),
+                ..
+            } if class.id == id => class.arguments.clone(),
+            _ => self
+                .class(id)
+                .type_parameters
+                .iter()
+                .map(|type_parameter| Type::Ok {
+                    range: name.range,
+                    kind: TypeKind::Parameter(type_parameter.clone()),
+                })
+                .collect(),
+        };
+        let mut instantiation = Instantiation::new(&self.class(id).type_parameters);
+        for (type_parameter, argument) in self.class(id).type_parameters.iter().zip(&arguments) {
+            instantiation.set(type_parameter, argument.clone());
+        }
+        let class_type = Type::class(name.range, id, name.identifier.clone(), arguments);
+
+        // Make sure our value might actually be an instance of our class. If our value’s type
+        // extends our class then our pattern will always match.
+        let always_matches = match &type_ {
+            Type::Ok {
+                kind: TypeKind::Class(class),
+                ..
+            } => self.is_subclass(class.id, id),
+            _ => false,
+        };
+        let mut known = if always_matches {
+            true
+        } else if let Type::Error { .. } = &type_ {
+            false
+        } else {
+            self.subtype(
+                name.range,
+                &OperationSnippet::ClassPattern(name.identifier.clone()),
+                &class_type,
+                &type_,
+            )
+            .is_ok()
+        };
+
+        // Check every field in our pattern against the type of that field. If the pattern of one
+        // of our fields might not match then our pattern only matches the instances of our class
+        // whose field matches. If we don’t know what the pattern of one of our fields matches then
+        // we don’t know what our pattern matches either.
+        let mut field_coverages = Vec::new();
+        let mut fields = Vec::with_capacity(pattern.fields.len());
+        for field in &pattern.fields {
+            let field_type = match self.class_field(id, &field.name.identifier) {
+                Some(field_type) => instantiation.apply(field_type),
+                None => {
+                    let class = self.class(id);
+                    let diagnostic = Diagnostic::field_not_found(
+                        field.name.range,
+                        field.name.identifier.clone(),
+                        class.name.clone(),
+                        class.range,
+                    );
+                    Type::error(self.report_diagnostic(diagnostic))
+                }
+            };
+            let (field, field_coverage) =
+                self.check_class_pattern_field(field, field_type, mutable);
+            match field_coverage {
+                PatternCoverage::Everything => {}
+                PatternCoverage::Unknown => known = false,
+                PatternCoverage::Class(_, _) => {
+                    field_coverages.push((field.name.clone(), field_coverage));
+                }
+            }
+            fields.push(field);
+        }
+
+        let coverage = if !known {
+            PatternCoverage::Unknown
+        } else if always_matches && field_coverages.is_empty() {
+            PatternCoverage::Everything
+        } else {
+            PatternCoverage::Class(id, field_coverages)
+        };
 
-    /// Checks a pattern which is supposed to bind a value with the provided type. If the pattern
-    /// is of a different type, say we are trying to bind a number to an object pattern, then we
-    /// will report a diagnostic.
-    fn check_pattern(&mut self, pattern: &ast::Pattern, type_: Type) -> Pattern {
-        let range = pattern.range;
+        (
+            Pattern::class(range, name.identifier.clone(), fields),
+            coverage,
+        )
+    }
 
-        match &pattern.kind {
-            // Declare a value variable in this scope with the pattern’s binding identifier.
-            ast::PatternKind::Binding(identifier) => {
-                self.scope.declare(
-                    identifier.clone(),
-                    ScopeEntry {
-                        range: pattern.range,
-                        kind: ScopeEntryKind::Value(type_),
-                    },
+    /// Checks a field in a class pattern. A field without a pattern binds the field’s value to a
+    /// variable of the same name.
+    fn check_class_pattern_field(
+        &mut self,
+        field: &ast::ClassPatternField,
+        type_: Type,
+        mutable: bool,
+    ) -> (ClassPatternField, PatternCoverage) {
+        let (value, coverage) = match &field.value {
+            Some(value) => self.check_pattern(value, type_, mutable),
+            None => {
+                self.declare_value(
+                    field.name.range,
+                    field.name.identifier.clone(),
+                    type_,
+                    mutable,
                 );
-                Pattern::binding(range, identifier.clone())
+                (
+                    Pattern::binding(field.name.range, field.name.identifier.clone()),
+                    PatternCoverage::Everything,
+                )
             }
+        };
+        let field = ClassPatternField {
+            name: field.name.identifier.clone(),
+            value,
+        };
+        (field, coverage)
+    }
+
+    /// Declares a value bound by a pattern in our current scope. If `mutable` is true then we
+    /// declare a variable which may be assigned to.
+    fn declare_value(&mut self, range: Range, identifier: Identifier, type_: Type, mutable: bool) {
+        let kind = if mutable {
+            ScopeEntryKind::Variable(type_)
+        } else {
+            ScopeEntryKind::Value(type_)
+        };
+        self.scope.declare(identifier, ScopeEntry { range, kind });
+    }
 
-            ast::PatternKind::Hole => unimplemented!(),
-            ast::PatternKind::This => unimplemented!(),
+    /// Have we assigned a new value to one of the names a pattern binds?
+    fn is_assigned(&self, pattern: &Pattern) -> bool {
+        match &pattern.kind {
+            PatternKind::Binding(_) => self.assigned.contains(&pattern.range),
+            PatternKind::Class(class) => class
+                .fields
+                .iter()
+                .any(|field| self.is_assigned(&field.value)),
+            PatternKind::Hole | PatternKind::This | PatternKind::Error(_) => false,
         }
     }
 
     fn check_type(&mut self, type_: &ast::Type) -> Type {
-        match &type_.kind {
-            ast::TypeKind::Reference(reference) => {
-                match self.scope.resolve(&type_.range, &reference.identifier) {
+        match type_ {
+            ast::Type::Reference(reference) => {
+                match self.scope.resolve(&reference.range, &reference.identifier) {
                     // If the identifier was not found report our error and return the unsound
                     // error type.
                     Err(diagnostic) => Type::error(self.report_diagnostic(diagnostic)),
 
                     Ok(entry) => match &entry.kind {
-                        ScopeEntryKind::Value(_) => unimplemented!(),
-                        ScopeEntryKind::Function => unimplemented!(),
-                        ScopeEntryKind::Class { .. } => unimplemented!(),
+                        ScopeEntryKind::Value(_) | ScopeEntryKind::Variable(_) => unimplemented!(),
+                        ScopeEntryKind::Function(_) => unimplemented!(),
+
+                        // If we are referencing a class then we have an instance of that class. We
+                        // need a type argument for every one of the class’s type parameters.
+                        ScopeEntryKind::Class(id) => {
+                            let id = *id;
+                            let declaration_range = entry.range;
+                            let arguments: Vec<_> = reference
+                                .arguments
+                                .iter()
+                                .map(|argument| self.check_type(argument))
+                                .collect();
+                            let type_parameters = self.class(id).type_parameters.len();
+                            if arguments.len() != type_parameters {
+                                Type::error(self.report_diagnostic(
+                                    Diagnostic::incompatible_type_argument_lengths(
+                                        reference.range,
+                                        OperationSnippet::TypeReference(
+                                            reference.identifier.clone(),
+                                        ),
+                                        (reference.range, arguments.len()),
+                                        (declaration_range, type_parameters),
+                                    ),
+                                ))
+                            } else {
+                                Type::class(
+                                    reference.range,
+                                    id,
+                                    reference.identifier.clone(),
+                                    arguments,
+                                )
+                            }
+                        }
+
+                        // If we are referencing the list or the promise type then we need a type
+                        // argument for the type of our list’s elements or our promise’s value.
+                        ScopeEntryKind::List | ScopeEntryKind::Promise => {
+                            let create: fn(Range, Type) -> Type = match &entry.kind {
+                                ScopeEntryKind::Promise => Type::promise,
+                                _ => Type::list,
+                            };
+                            let declaration_range = entry.declaration_range(reference.range);
+                            let mut arguments: Vec<_> = reference
+                                .arguments
+                                .iter()
+                                .map(|argument| self.check_type(argument))
+                                .collect();
+                            if arguments.len() != 1 {
+                                Type::error(self.report_diagnostic(
+                                    Diagnostic::incompatible_type_argument_lengths(
+                                        reference.range,
+                                        OperationSnippet::TypeReference(
+                                            reference.identifier.clone(),
+                                        ),
+                                        (reference.range, arguments.len()),
+                                        (declaration_range, 1),
+                                    ),
+                                ))
+                            } else {
+                                create(reference.range, arguments.pop().unwrap())
+                            }
+                        }
 
-                        // If we are referencing a type then return that.
-                        ScopeEntryKind::Type(referenced_type) => {
-                            let mut referenced_type = referenced_type.clone();
+                        // If we are referencing a type then return that. A type alias is
+                        // transparent so we return the type it aliases.
+                        ScopeEntryKind::Type(_) | ScopeEntryKind::TypeAlias(_) => {
+                            let declaration_range = entry.declaration_range(reference.range);
+                            let mut referenced_type = match &entry.kind {
+                                ScopeEntryKind::Type(referenced_type) => referenced_type.clone(),
+                                ScopeEntryKind::TypeAlias(index) => {
+                                    let index = *index;
+                                    match self.check_type_alias(index) {
+                                        Some(referenced_type) => referenced_type,
+                                        // If we are still checking our type alias then it
+                                        // references itself.
+                                        None => Type::error(self.report_diagnostic(
+                                            Diagnostic::declaration_cycle_detected(
+                                                reference.range,
+                                                reference.identifier.clone(),
+                                                declaration_range,
+                                            ),
+                                        )),
+                                    }
+                                }
+                                _ => unreachable!(),
+                            };
 
                             // TODO: Find a better way to do this then mutating the type’s range
                             // which is very hacky!
@@ -728,7 +3611,26 @@ impl<'errs> Checker<'errs> {
                                 kind: _,
                             } = referenced_type
                             {
-                                *range = type_.range;
+                                *range = reference.range;
+                            }
+
+                            // None of the types we may declare in scope are generic, so if our
+                            // reference has type arguments then report an error. We still check
+                            // our type arguments so that they are not ignored.
+                            if !reference.arguments.is_empty() {
+                                for argument in &reference.arguments {
+                                    self.check_type(argument);
+                                }
+                                self.report_diagnostic(
+                                    Diagnostic::incompatible_type_argument_lengths(
+                                        reference.range,
+                                        OperationSnippet::TypeReference(
+                                            reference.identifier.clone(),
+                                        ),
+                                        (reference.range, reference.arguments.len()),
+                                        (declaration_range, 0),
+                                    ),
+                                );
                             }
 
                             referenced_type
@@ -737,17 +3639,30 @@ impl<'errs> Checker<'errs> {
                 }
             }
 
-            ast::TypeKind::This => unimplemented!(),
+            ast::Type::This(_) => unimplemented!(),
 
-            ast::TypeKind::Function(function) => {
-                let parameters = function
-                    .parameters
-                    .iter()
-                    .map(|type_| self.check_type(type_))
-                    .collect();
-                let return_ = self.check_type(&function.return_);
-                Type::function(type_.range, parameters, return_)
-            }
+            ast::Type::Resolved(ast::ResolvedType::Scalar(scalar)) => match scalar.kind {
+                ast::ScalarTypeKind::Void => Type::void(scalar.range),
+            },
+
+            ast::Type::Resolved(ast::ResolvedType::Composite(composite)) => match &**composite {
+                ast::CompositeType::Function(function) => {
+                    let parameters = function
+                        .parameters
+                        .iter()
+                        .map(|type_| self.check_type(type_))
+                        .collect();
+                    let return_ = self.check_type(&function.return_);
+                    Type::function(function.range, parameters, return_)
+                }
+                ast::CompositeType::Optional(optional) => {
+                    let type_ = self.check_type(&optional.type_);
+                    Type::optional(optional.range, type_)
+                }
+            },
+
+            // We could not parse this type so it is the unsound error type.
+            ast::Type::Resolved(ast::ResolvedType::Error(error)) => Type::error(error.clone()),
         }
     }
 
@@ -808,6 +3723,9 @@ impl<'errs> Checker<'errs> {
             (Integer, Integer) => Ok(()),
             (Float, Float) => Ok(()),
 
+            // String is only the subtype of itself.
+            (String, String) => Ok(()),
+
             // Functions will subtype with other functions.
             //
             // **IMPORTANT:** If you update the subtyping logic of functions down here, also make
@@ -815,6 +3733,17 @@ impl<'errs> Checker<'errs> {
             (Function(function1), Function(function2)) => {
                 let mut result = Ok(());
 
+                // If our first function is generic then we instantiate it by inferring its type
+                // parameters from the second function.
+                let function1 = if function1.type_parameters.is_empty() {
+                    Rc::clone(function1)
+                } else {
+                    let mut instantiation = Instantiation::new(&function1.type_parameters);
+                    instantiation.infer_function(function1, function2);
+                    instantiation.default_to_never(*range1);
+                    Rc::new(instantiation.apply_function(function1))
+                };
+
                 // If the two functions have different numbers of parameters then error.
                 //
                 // NOTE: We could allow `function1` to have less parameters then
@@ -861,6 +3790,50 @@ impl<'errs> Checker<'errs> {
                 result
             }
 
+            // Outside of a generic function we know nothing about its type parameters. So a type
+            // parameter is only the subtype of itself.
+            (Parameter(parameter1), Parameter(parameter2)) if parameter1.id == parameter2.id => {
+                Ok(())
+            }
+
+            // An instance of a class is only the subtype of the same class with the same type
+            // arguments. We don’t know how the class uses its type parameters so we check both
+            // subtyping directions for each type argument.
+            (Class(class1), Class(class2)) if class1.id == class2.id => {
+                let mut result = Ok(());
+                for (argument1, argument2) in class1.arguments.iter().zip(&class2.arguments) {
+                    let argument_result = self.subtype(range, operation, argument1, argument2);
+                    let argument_result = match argument_result {
+                        Ok(()) => self.subtype(range, operation, argument2, argument1),
+                        Err(error) => Err(error),
+                    };
+                    result = result.and(argument_result);
+                }
+                result
+            }
+
+            // An instance of a class is also an instance of all the classes it extends. We don’t
+            // allow a class to extend a generic class so there are no type arguments to check.
+            (Class(class1), Class(class2)) if self.is_subclass(class1.id, class2.id) => Ok(()),
+
+            // Null is the subtype of itself and of every optional type.
+            (Null, Null) => Ok(()),
+            (Null, Optional(_)) => Ok(()),
+
+            // An optional type is the subtype of another optional type if the types they wrap are
+            // subtypes. Every other type is the subtype of an optional type if it is the subtype of
+            // the type wrapped by the optional type.
+            (Optional(type1), Optional(type2)) => self.subtype(range, operation, type1, type2),
+            (_, Optional(type2)) => self.subtype(range, operation, type1, type2),
+
+            // Lists are immutable so a list is the subtype of another list if its elements are
+            // subtypes of the other list’s elements.
+            (List(element1), List(element2)) => self.subtype(range, operation, element1, element2),
+
+            // A promise is the subtype of another promise if the values they resolve to
+            // are subtypes.
+            (Promise(value1), Promise(value2)) => self.subtype(range, operation, value1, value2),
+
             // Error cases. We don’t use a hole (`_`) because we want the compiler to warn us
             // whenever we are missing a subtyping case.
             (_, Never)
@@ -869,7 +3842,14 @@ impl<'errs> Checker<'errs> {
             | (Number, _)
             | (Integer, _)
             | (Float, _)
-            | (Function(_), _) => Err(self.report_diagnostic(Diagnostic::incompatible_types(
+            | (String, _)
+            | (Null, _)
+            | (Optional(_), _)
+            | (List(_), _)
+            | (Promise(_), _)
+            | (Function(_), _)
+            | (Parameter(_), _)
+            | (Class(_), _) => Err(self.report_diagnostic(Diagnostic::incompatible_types(
                 range,
                 operation.clone(),
                 (*range1, kind1.snippet()),
@@ -878,14 +3858,207 @@ impl<'errs> Checker<'errs> {
         }
     }
 
+    /// Makes sure that we know how to print values of a type into a string. If we don’t then a
+    /// diagnostic error is reported.
+    fn check_printable(&mut self, range: Range, type_: &Type) {
+        use self::TypeKind::*;
+
+        match type_ {
+            // An error type is printable so that we don’t report more than one error.
+            Type::Error { .. } => {}
+
+            Type::Ok {
+                range: type_range,
+                kind,
+            } => match kind {
+                // The never type is our bottom type so we may print it just like every other type.
+                Never => {}
+
+                // We know how to print all of our primitive values.
+                Boolean | Number | Integer | Float | String => {}
+
+                // There is no sensible way to print void, a function, or a class instance.
+                // Printing a function in JavaScript gives us its source code, for instance. We
+                // don’t know what a type parameter will be so it could be a function. A value which
+                // might be `null` should be checked for `null` before printing.
+                Void | Null | Optional(_) | List(_) | Promise(_) | Function(_) | Parameter(_)
+                | Class(_) => {
+                    self.report_diagnostic(Diagnostic::cannot_print(
+                        range,
+                        *type_range,
+                        kind.snippet(),
+                    ));
+                }
+            },
+        }
+    }
+
+    /// Gets a class from our class table.
+    fn class(&self, id: ClassId) -> &ClassEntry {
+        &self.classes[id.0 as usize]
+    }
+
+    /// Is the first class the same as the second class or does it extend the second class?
+    fn is_subclass(&self, mut id: ClassId, superclass: ClassId) -> bool {
+        loop {
+            if id == superclass {
+                return true;
+            }
+            match self.class(id).extends {
+                Some(extends) => id = extends,
+                None => return false,
+            }
+        }
+    }
+
+    /// Gets all the classes which are the same as or extend our class and which are not base
+    /// classes. Every instance of our class will be an instance of exactly one of these classes.
+    fn concrete_subclasses(&self, superclass: ClassId) -> Vec<ClassId> {
+        (0..self.classes.len())
+            .map(|id| ClassId(id as u32))
+            .filter(|id| !self.class(*id).base && self.is_subclass(*id, superclass))
+            .collect()
+    }
+
+    /// Gets every field of a class including the fields of the classes it extends. The fields of
+    /// the classes we extend come first.
+    fn class_fields(&self, id: ClassId) -> Vec<(Identifier, Type)> {
+        let class = self.class(id);
+        let mut fields = match class.extends {
+            Some(extends) => self.class_fields(extends),
+            None => Vec::new(),
+        };
+        fields.extend(class.fields.iter().cloned());
+        fields
+    }
+
+    /// Finds an instance method on a class or on one of the classes it extends.
+    fn class_method(
+        &self,
+        mut id: ClassId,
+        method: &Identifier,
+    ) -> Option<&(Identifier, Range, Option<Type>)> {
+        loop {
+            let class = self.class(id);
+            if let Some(entry) = class.methods.iter().find(|(name, _, _)| name == method) {
+                return Some(entry);
+            }
+            id = class.extends?;
+        }
+    }
+
+    /// Finds a static method on a class or on one of the classes it extends.
+    fn class_static_method(
+        &self,
+        mut id: ClassId,
+        method: &Identifier,
+    ) -> Option<&(Identifier, Range, Option<Type>)> {
+        loop {
+            let class = self.class(id);
+            if let Some(entry) = class
+                .static_methods
+                .iter()
+                .find(|(name, _, _)| name == method)
+            {
+                return Some(entry);
+            }
+            id = class.extends?;
+        }
+    }
+
+    /// Finds the class an expression references by name. A class is not a value so we only allow
+    /// a reference to a class as the object of a member expression.
+    fn class_reference<'b>(
+        &self,
+        expression: &'b ast::Expression,
+    ) -> Option<(ClassId, &'b Identifier)> {
+        match &expression.kind {
+            ast::ExpressionKind::Reference(identifier) => {
+                match self.scope.resolve_maybe(identifier) {
+                    Some(ScopeEntry {
+                        kind: ScopeEntryKind::Class(id),
+                        ..
+                    }) => Some((*id, identifier)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Finds the type of a field on a class or on one of the classes it extends.
+    fn class_field(&self, mut id: ClassId, field: &Identifier) -> Option<&Type> {
+        loop {
+            let class = self.class(id);
+            if let Some((_, type_)) = class.fields.iter().find(|(name, _)| name == field) {
+                return Some(type_);
+            }
+            id = class.extends?;
+        }
+    }
+
     /// Reports a diagnostic.
     ///
     /// Written so that we may swap out the implementation at any time.
-    fn report_diagnostic(&mut self, diagnostic: Diagnostic) -> DiagnosticRef {
+    pub(super) fn report_diagnostic(&mut self, diagnostic: Diagnostic) -> DiagnosticRef {
         self._diagnostics.report(diagnostic)
     }
 }
 
+/// Gets the type of an arithmetic operation on two numbers. Arithmetic on two integers returns an
+/// integer and arithmetic on two floats returns a float. Except that dividing or exponentiating two
+/// integers might not return an integer. Anything else returns a number.
+fn arithmetic_type(range: Range, operator: &ast::InfixOperator, left: &Type, right: &Type) -> Type {
+    match (left, right) {
+        (Type::Error { error }, _) | (_, Type::Error { error }) => Type::error(error.clone()),
+        (
+            Type::Ok {
+                kind: TypeKind::Integer,
+                ..
+            },
+            Type::Ok {
+                kind: TypeKind::Integer,
+                ..
+            },
+        ) => match operator {
+            ast::InfixOperator::Divide | ast::InfixOperator::Exponent => Type::number(range),
+            _ => Type::integer(range),
+        },
+        (
+            Type::Ok {
+                kind: TypeKind::Float,
+                ..
+            },
+            Type::Ok {
+                kind: TypeKind::Float,
+                ..
+            },
+        ) => Type::float(range),
+        _ => Type::number(range),
+    }
+}
+
+/// Is this the integer type?
+fn is_integer(type_: &Type) -> bool {
+    matches!(
+        type_,
+        Type::Ok {
+            kind: TypeKind::Integer,
+            ..
+        }
+    )
+}
+
+/// A value whose type we narrowed in some branch of a conditional expression.
+struct Narrowing {
+    /// The name of our value.
+    identifier: Identifier,
+    /// The range our value was declared at.
+    range: Range,
+    /// The narrowed type of our value.
+    type_: Type,
+}
+
 /// Struct for representing a type associated with an operation which required checking that type
 /// against the actual program’s type.
 struct WithType<'a> {
@@ -916,6 +4089,119 @@ impl<'a> WithFunctionType<'a> {
     }
 }
 
+/// A loop we are currently checking the body of.
+struct LoopEntry {
+    /// The label of our loop, if it has one.
+    label: Option<Identifier>,
+    /// What kind of loop is this?
+    kind: LoopKind,
+    /// The type of the values we have broken out of our loop with so far. Always `None` for
+    /// while loops.
+    break_type: Option<Type>,
+}
+
+/// The kind of a [`LoopEntry`].
+#[derive(Clone, Copy)]
+enum LoopKind {
+    While,
+    Loop,
+}
+
+/// The state a checker shares with the checkers of every other module in a [`ModuleGraph`]. Types
+/// in one module may refer to a class declared in another so all modules share one class table.
+pub(super) struct SharedState {
+    /// The identifier we will give to the next type parameter we check.
+    next_type_parameter_id: u32,
+    /// Every class declared in our modules. A [`ClassId`] is an index into this list.
+    classes: Vec<ClassEntry>,
+    /// Every type alias declared in our modules.
+    type_aliases: Vec<TypeAliasEntry>,
+    /// Every function declared in our modules.
+    functions: Vec<FunctionEntry>,
+}
+
+impl SharedState {
+    /// Creates the state for a new [`ModuleGraph`].
+    pub(super) fn new() -> Self {
+        SharedState {
+            next_type_parameter_id: 0,
+            classes: Vec::new(),
+            type_aliases: Vec::new(),
+            functions: Vec::new(),
+        }
+    }
+}
+
+/// The classes and type aliases we declared for a module in [`Checker::declare_module`].
+pub(super) struct DeclaredModule {
+    /// The identifier we gave to each class in our module.
+    classes: Vec<ClassId>,
+    /// The index we gave to each type alias in our module.
+    type_aliases: Vec<usize>,
+    /// The index we gave to each function in our module.
+    functions: Vec<usize>,
+}
+
+/// A class declared in our module. Types refer to a class by its [`ClassId`] so we keep our classes
+/// in a table instead of in our scope.
+struct ClassEntry {
+    /// The name of our class.
+    name: Identifier,
+    /// The range of our class’s name.
+    range: Range,
+    /// Is this a base class?
+    base: bool,
+    /// The type parameters of a generic class.
+    type_parameters: Vec<TypeParameter>,
+    /// The base class our class extends. We never allow the classes we extend to form a cycle.
+    extends: Option<ClassId>,
+    /// The fields declared by our class. Does not include the fields of the class we extend.
+    fields: Vec<(Identifier, Type)>,
+    /// The instance methods declared by our class along with the range of their names. We only
+    /// know the type of a method if its signature is fully annotated. Does not include the methods
+    /// of the class we extend.
+    methods: Vec<(Identifier, Range, Option<Type>)>,
+    /// The methods declared by our class without a `this` parameter along with the range of
+    /// their names.
+    static_methods: Vec<(Identifier, Range, Option<Type>)>,
+    /// The signature of every method with a body in the order they are declared. We check the
+    /// bodies of our methods with these signatures.
+    signatures: Vec<FunctionEntry>,
+}
+
+/// A function declared in our module. We check the signature of every function before we check
+/// the body of any function.
+#[derive(Clone, Default)]
+struct FunctionEntry {
+    /// The type parameters of a generic function.
+    type_parameters: Vec<TypeParameter>,
+    /// The type of each parameter which has an annotation. The `this` parameter of a method has
+    /// the type of its class.
+    parameters: Vec<Option<Type>>,
+    /// Our return type if it has an annotation.
+    return_: Option<Type>,
+    /// The type of our function. We know our type before we check our function if all of our
+    /// parameters and our return type are annotated. Otherwise we know our type once we’ve
+    /// checked our function.
+    type_: Option<Type>,
+}
+
+/// A type alias declared in our module. We check a type alias lazily, the first time it is
+/// referenced, so that a type alias may reference the type aliases declared after it. Has the type
+/// our type alias gives a name to.
+type TypeAliasEntry = DeclarationLazy<ast::Type, Type>;
+
+/// The values of some type matched by a pattern.
+enum PatternCoverage {
+    /// The pattern matches every value.
+    Everything,
+    /// The pattern matches instances of a class and all the classes which extend it whose fields
+    /// match. We only have the fields with a pattern which might not match.
+    Class(ClassId, Vec<(Identifier, PatternCoverage)>),
+    /// We don’t know which values the pattern matches.
+    Unknown,
+}
+
 /// A typed AVT node. All AVT nodes carry around enough type information for compilation and IDE
 /// tooling. During type checking, though, we want to remember the type of every block.
 struct Checked<Node> {
@@ -943,8 +4229,242 @@ impl CheckedFunction {
     }
 }
 
+/// Picks a type for every type parameter of a generic function. Either from type arguments the
+/// programmer wrote or by inferring types from how the function is used.
+///
+/// We infer type parameters locally. The first type we see for a type parameter is the one we
+/// pick. Later uses will then be subtyped against that type like normal.
+struct Instantiation {
+    types: HashMap<TypeParameterId, Option<Type>>,
+}
+
+impl Instantiation {
+    /// Creates a new instantiation where we don’t yet know the type of any of our type parameters.
+    fn new(type_parameters: &[TypeParameter]) -> Self {
+        Instantiation {
+            types: type_parameters
+                .iter()
+                .map(|type_parameter| (type_parameter.id, None))
+                .collect(),
+        }
+    }
+
+    /// Picks the type for a type parameter.
+    fn set(&mut self, type_parameter: &TypeParameter, type_: Type) {
+        self.types.insert(type_parameter.id, Some(type_));
+    }
+
+    /// Picks a type for every type parameter we don’t yet know the type of. We use `Never` since
+    /// no value could have been given for the type parameter.
+    fn default_to_never(&mut self, range: Range) {
+        for type_ in self.types.values_mut() {
+            if type_.is_none() {
+                *type_ = Some(Type::never(range));
+            }
+        }
+    }
+
+    /// Does our type reference a type parameter whose type we don’t know yet?
+    fn is_unknown(&self, type_: &Type) -> bool {
+        match type_ {
+            Type::Error { .. } => false,
+            Type::Ok { kind, .. } => match kind {
+                TypeKind::Never
+                | TypeKind::Void
+                | TypeKind::Boolean
+                | TypeKind::Number
+                | TypeKind::Integer
+                | TypeKind::Float
+                | TypeKind::String
+                | TypeKind::Null => false,
+                TypeKind::Optional(type_) | TypeKind::List(type_) | TypeKind::Promise(type_) => {
+                    self.is_unknown(type_)
+                }
+                TypeKind::Function(function) => {
+                    function
+                        .parameters
+                        .iter()
+                        .any(|type_| self.is_unknown(type_))
+                        || self.is_unknown(&function.return_)
+                }
+                TypeKind::Parameter(type_parameter) => match self.types.get(&type_parameter.id) {
+                    Some(None) => true,
+                    Some(Some(_)) | None => false,
+                },
+                TypeKind::Class(class) => {
+                    class.arguments.iter().any(|type_| self.is_unknown(type_))
+                }
+            },
+        }
+    }
+
+    /// Infers types for the type parameters referenced in `type1` by finding the types in the same
+    /// positions in `type2`.
+    fn infer(&mut self, type1: &Type, type2: &Type) {
+        match (type1, type2) {
+            (
+                Type::Ok {
+                    kind: TypeKind::Parameter(type_parameter),
+                    ..
+                },
+                _,
+            ) => {
+                if let Some(type_ @ None) = self.types.get_mut(&type_parameter.id) {
+                    *type_ = Some(type2.clone());
+                }
+            }
+            (
+                Type::Ok {
+                    kind: TypeKind::Function(function1),
+                    ..
+                },
+                Type::Ok {
+                    kind: TypeKind::Function(function2),
+                    ..
+                },
+            ) => self.infer_function(function1, function2),
+            (
+                Type::Ok {
+                    kind: TypeKind::Class(class1),
+                    ..
+                },
+                Type::Ok {
+                    kind: TypeKind::Class(class2),
+                    ..
+                },
+            ) if class1.id == class2.id => {
+                for (argument1, argument2) in class1.arguments.iter().zip(&class2.arguments) {
+                    self.infer(argument1, argument2);
+                }
+            }
+            (
+                Type::Ok {
+                    kind: TypeKind::Optional(type1),
+                    ..
+                },
+                Type::Ok {
+                    kind: TypeKind::Optional(type2),
+                    ..
+                },
+            ) => self.infer(type1, type2),
+            (
+                Type::Ok {
+                    kind: TypeKind::Optional(_),
+                    ..
+                },
+                Type::Ok {
+                    kind: TypeKind::Null,
+                    ..
+                },
+            ) => {}
+            (
+                Type::Ok {
+                    kind: TypeKind::List(element1),
+                    ..
+                },
+                Type::Ok {
+                    kind: TypeKind::List(element2),
+                    ..
+                },
+            ) => self.infer(element1, element2),
+            (
+                Type::Ok {
+                    kind: TypeKind::Promise(value1),
+                    ..
+                },
+                Type::Ok {
+                    kind: TypeKind::Promise(value2),
+                    ..
+                },
+            ) => self.infer(value1, value2),
+            (
+                Type::Ok {
+                    kind: TypeKind::Optional(type1),
+                    ..
+                },
+                _,
+            ) => self.infer(type1, type2),
+            _ => {}
+        }
+    }
+
+    /// Infers types for the type parameters referenced in `function1` by finding the types in the
+    /// same positions in `function2`.
+    fn infer_function(&mut self, function1: &FunctionType, function2: &FunctionType) {
+        for (parameter1, parameter2) in function1.parameters.iter().zip(&function2.parameters) {
+            self.infer(parameter1, parameter2);
+        }
+        self.infer(&function1.return_, &function2.return_);
+    }
+
+    /// Substitutes every type parameter we know the type of in our type.
+    fn apply(&self, type_: &Type) -> Type {
+        match type_ {
+            Type::Ok {
+                range,
+                kind: TypeKind::Function(function),
+            } => Type::from_function(*range, self.apply_function(function)),
+            Type::Ok {
+                range,
+                kind: TypeKind::Class(class),
+            } => Type::class(
+                *range,
+                class.id,
+                class.name.clone(),
+                class
+                    .arguments
+                    .iter()
+                    .map(|type_| self.apply(type_))
+                    .collect(),
+            ),
+            Type::Ok {
+                range,
+                kind: TypeKind::Optional(type_),
+            } => Type::optional(*range, self.apply(type_)),
+            Type::Ok {
+                range,
+                kind: TypeKind::List(element),
+            } => Type::list(*range, self.apply(element)),
+            Type::Ok {
+                range,
+                kind: TypeKind::Promise(value),
+            } => Type::promise(*range, self.apply(value)),
+            Type::Ok {
+                kind: TypeKind::Parameter(type_parameter),
+                ..
+            } => match self.types.get(&type_parameter.id) {
+                Some(Some(type_)) => type_.clone(),
+                Some(None) | None => type_.clone(),
+            },
+            _ => type_.clone(),
+        }
+    }
+
+    /// Substitutes every type parameter we know the type of in our function type. The type
+    /// parameters we are instantiating are removed from the function type.
+    fn apply_function(&self, function: &FunctionType) -> FunctionType {
+        let mut applied = FunctionType::new(
+            function
+                .type_parameters
+                .iter()
+                .filter(|type_parameter| !self.types.contains_key(&type_parameter.id))
+                .cloned()
+                .collect(),
+            function
+                .parameters
+                .iter()
+                .map(|type_| self.apply(type_))
+                .collect(),
+            self.apply(&function.return_),
+        );
+        applied.parameter_names = function.parameter_names.clone();
+        applied.parameter_defaults = function.parameter_defaults.clone();
+        applied
+    }
+}
+
 /// The scope of a program contains all the variables accessible at different points in the program.
-struct Scope {
+pub(super) struct Scope {
     stack: Vec1<HashMap<Identifier, ScopeEntry>>,
 }
 
@@ -958,23 +4478,65 @@ struct ScopeEntry {
 }
 
 /// The kind of a [`ScopeEntry`].
-#[derive(Debug)]
-enum ScopeEntryKind {
+#[derive(Clone, Debug)]
+pub(super) enum ScopeEntryKind {
     /// Some value bound at runtime.
     Value(Type),
+    /// Some value bound at runtime with `let mut`. Unlike `ScopeEntryKind::Value`, a variable may
+    /// be assigned a new value.
+    Variable(Type),
     /// A declared type.
     Type(Type),
     /// The name references a function declaration. Very similar to `ScopeEntryKind::Value` with a
-    /// function type except we know the exact function which is bound.
-    Function,
+    /// function type except we know the exact function which is bound. Has an index into
+    /// `Checker::functions`.
+    Function(usize),
     /// The name references a class declaration.
-    Class { base: bool },
+    Class(ClassId),
+    /// The name references a type alias declaration. Has an index into `Checker::type_aliases`.
+    TypeAlias(usize),
+    /// The name references the built-in list type. Unlike the other types in our prelude the list
+    /// type needs a type argument.
+    List,
+    /// The name references the built-in promise type. Like the list type, the promise type needs
+    /// a type argument.
+    Promise,
+}
+
+impl ScopeEntry {
+    /// The range of the declaration our entry was created for. Entries in our prelude are not
+    /// declared in any document so we use the range of the code which references them instead.
+    fn declaration_range(&self, reference_range: Range) -> Range {
+        if self.range == Range::initial() {
+            reference_range
+        } else {
+            self.range
+        }
+    }
+}
+
+impl ScopeEntryKind {
+    /// Does this entry only exist as a type? Types don’t exist at runtime.
+    pub(super) fn is_type(&self) -> bool {
+        match self {
+            ScopeEntryKind::Type(_)
+            | ScopeEntryKind::TypeAlias(_)
+            | ScopeEntryKind::List
+            | ScopeEntryKind::Promise => true,
+            ScopeEntryKind::Value(_)
+            | ScopeEntryKind::Variable(_)
+            | ScopeEntryKind::Function(_)
+            | ScopeEntryKind::Class(_) => false,
+        }
+    }
 }
 
 impl Scope {
     /// Creates a new scope.
-    fn new() -> Self {
-        // TODO: Use proper ranges for the prelude.
+    pub(super) fn new() -> Self {
+        // Our prelude is not declared in any document so its entries have an empty range at the
+        // start of the document. See [`ScopeEntry::declaration_range`].
+        //
         // TODO: It should be ok to shadow names in the prelude.
         let mut root = HashMap::new();
         let range = Range::initial();
@@ -984,6 +4546,21 @@ impl Scope {
         insert_root_entry(&mut root, "Num", range, Type::number(range));
         insert_root_entry(&mut root, "Int", range, Type::integer(range));
         insert_root_entry(&mut root, "Float", range, Type::float(range));
+        insert_root_entry(&mut root, "String", range, Type::string(range));
+        root.insert(
+            Identifier::new("List").unwrap(),
+            ScopeEntry {
+                range,
+                kind: ScopeEntryKind::List,
+            },
+        );
+        root.insert(
+            Identifier::new("Promise").unwrap(),
+            ScopeEntry {
+                range,
+                kind: ScopeEntryKind::Promise,
+            },
+        );
 
         fn insert_root_entry(
             root: &mut HashMap<Identifier, ScopeEntry>,
@@ -1025,6 +4602,17 @@ impl Scope {
         self.stack.last_mut().insert(identifier, entry);
     }
 
+    /// The names declared by our prelude. Every module may reference these names.
+    pub(super) fn prelude_identifiers() -> Vec<Identifier> {
+        Scope::new().stack.last().keys().cloned().collect()
+    }
+
+    /// Resolves a declaration in our module’s scope so that another module may import it.
+    pub(super) fn resolve_export(&self, identifier: &Identifier) -> Option<ScopeEntryKind> {
+        self.resolve_maybe(identifier)
+            .map(|entry| entry.kind.clone())
+    }
+
     /// Resolves a name in our current scope. If we could not find it then return `None`.
     fn resolve_maybe(&self, identifier: &Identifier) -> Option<&ScopeEntry> {
         for entries in self.stack.iter().rev() {
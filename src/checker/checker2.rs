@@ -1,3 +1,4 @@
+use super::checker::Scope;
 use crate::diagnostics::{Diagnostic, DiagnosticRef, DiagnosticsCollection};
 use crate::language::*;
 use crate::parser::{Identifier, Range};
@@ -8,6 +9,20 @@ pub fn precheck_module(diagnostics: &mut DiagnosticsCollection, module: &Module)
     let mut declarations: PrecheckDeclarationMap =
         HashMap::with_capacity(module.declarations.len());
 
+    // Add our prelude so that our declarations may reference types like `Int`. Our prelude is not
+    // declared in any document so it has an empty range at the start of the document.
+    for identifier in Scope::prelude_identifiers() {
+        declarations.insert(
+            identifier,
+            PrecheckDeclarationLazy {
+                name_range: Range::initial(),
+                type_alias: false,
+                references: Vec::new(),
+                declaration: DeclarationLazy::Checked(PrecheckDeclaration::Prelude),
+            },
+        );
+    }
+
     let mut recheck_declarations = Vec::with_capacity(module.declarations.len());
 
     // Add all the declarations in our module to our `PrecheckDeclarationMap`. If two declarations
@@ -16,14 +31,19 @@ pub fn precheck_module(diagnostics: &mut DiagnosticsCollection, module: &Module)
     // We add a reference to `recheck_declarations` since after we’ve seen all our declarations we
     // will go back and actually type check them all.
     for declaration in &module.declarations {
-        let declaration_name = declaration.name();
+        // Import and error declarations don’t have a name. The parser already reported a
+        // diagnostic for error declarations so there’s nothing left for us to check.
+        let declaration_name = match declaration.name() {
+            Some(name) => name,
+            None => continue,
+        };
 
         let recheck_declaration =
             if let Some(other_declaration) = declarations.get(&declaration_name.identifier) {
                 diagnostics.report(Diagnostic::declaration_name_already_used(
                     declaration_name.range,
                     declaration_name.identifier.clone(),
-                    other_declaration.name_range(),
+                    other_declaration.name_range,
                 ));
                 Err(declaration)
             } else {
@@ -67,18 +87,37 @@ type PrecheckDeclarationMap<'src> = HashMap<Identifier, PrecheckDeclarationLazy<
 enum PrecheckDeclaration {
     Function(PrecheckFunctionDeclaration),
     Class(PrecheckClassDeclaration),
+    TypeAlias(PrecheckTypeAliasDeclaration),
+    /// A type from our prelude like `Int`.
+    Prelude,
 }
 
 impl PrecheckDeclaration {
     fn resolve_type(&self, reference: &ReferenceType) {
-        unimplemented!()
+        match self {
+            // A type alias is transparent so a reference to our type alias resolves to the very
+            // type we alias.
+            PrecheckDeclaration::TypeAlias(PrecheckTypeAliasDeclaration {
+                value: Some(value),
+                ..
+            }) => {
+                reference.resolve(value.clone());
+            }
+            // There is no resolved type for classes or the types in our prelude so we leave
+            // references to them unresolved. The checker resolves those references instead.
+            PrecheckDeclaration::TypeAlias(_)
+            | PrecheckDeclaration::Class(_)
+            | PrecheckDeclaration::Prelude => {}
+            // The checker reports an error for functions used as types.
+            PrecheckDeclaration::Function(_) => {}
+        }
     }
 }
 
-fn precheck_declaration(
+fn precheck_declaration<'src>(
     diagnostics: &mut DiagnosticsCollection,
-    declarations: &mut PrecheckDeclarationMap,
-    declaration: &Declaration,
+    declarations: &mut PrecheckDeclarationMap<'src>,
+    declaration: &'src Declaration,
 ) -> PrecheckDeclaration {
     match declaration {
         Declaration::Function(declaration) => PrecheckDeclaration::Function(
@@ -89,11 +128,16 @@ fn precheck_declaration(
             declarations,
             declaration,
         )),
+        Declaration::TypeAlias(declaration) => PrecheckDeclaration::TypeAlias(
+            precheck_type_alias_declaration(diagnostics, declarations, declaration),
+        ),
+        Declaration::Import(_) | Declaration::Error(_) => {
+            unreachable!("We skip nameless declarations before we get around to checking them.")
+        }
     }
 }
 
 struct PrecheckFunctionDeclaration {
-    name_range: Range,
     // name: Identifier,
     // parameters: Vec<(Pattern, Type)>,
     // return_type: Type,
@@ -103,74 +147,112 @@ struct PrecheckFunctionDeclaration {
 fn precheck_function_declaration(
     diagnostics: &mut DiagnosticsCollection,
     declarations: &mut PrecheckDeclarationMap,
-    declaration: &FunctionDeclaration,
+    _declaration: &FunctionDeclaration,
 ) -> PrecheckFunctionDeclaration {
     // unimplemented!()
-    PrecheckFunctionDeclaration {
-        name_range: declaration.name.range,
-    }
+    PrecheckFunctionDeclaration {}
 }
 
 struct PrecheckClassDeclaration {
-    name_range: Range,
     // name: Identifier,
 }
 
 fn precheck_class_declaration(
     diagnostics: &mut DiagnosticsCollection,
     declarations: &mut PrecheckDeclarationMap,
-    declaration: &ClassDeclaration,
+    _declaration: &ClassDeclaration,
 ) -> PrecheckClassDeclaration {
     // unimplemented!()
-    PrecheckClassDeclaration {
-        name_range: declaration.name.range,
-    }
+    PrecheckClassDeclaration {}
+}
+
+struct PrecheckTypeAliasDeclaration {
+    /// The type we alias. If we alias a type which has no resolved type, like a class, then we
+    /// don’t have a value.
+    value: Option<ResolvedType>,
+}
+
+fn precheck_type_alias_declaration<'src>(
+    diagnostics: &mut DiagnosticsCollection,
+    declarations: &mut PrecheckDeclarationMap<'src>,
+    declaration: &'src TypeAliasDeclaration,
+) -> PrecheckTypeAliasDeclaration {
+    precheck_type(diagnostics, declarations, &declaration.value);
+    let value = match &declaration.value {
+        Type::Resolved(value) => Some(value.clone()),
+        // We check the type aliases we reference before our own type alias finishes checking, so
+        // if we reference a type alias it will already be resolved. References to classes and to
+        // our prelude are never resolved during the precheck.
+        Type::Reference(reference) => reference.resolved(),
+        Type::This(_) => None,
+    };
+    PrecheckTypeAliasDeclaration { value }
 }
 
 /// To type check a module, we first look at all the declarations in our module and add “unchecked“
 /// references to the declaration. We then go through each declaration and type check it. If one
 /// declaration depends on another (like `class extends`) we will make sure to check that class even
 /// if it appears after our own in source code.
-enum PrecheckDeclarationLazy<'src> {
-    /// The declaration is unchecked or is currently in the process of type checking.
-    Unchecked {
-        /// The range of the declaration’s name.
-        name_range: Range,
-        /// The declaration which needs to be checked. If `None` then we are currently type checking
-        /// this declaration.
-        declaration: Option<&'src Declaration>,
-        /// References to the declaration that we find before we finish checking.
-        references: Vec<&'src ReferenceType>,
-    },
-    /// We’ve finished checking the declaration.
-    Checked(PrecheckDeclaration),
+struct PrecheckDeclarationLazy<'src> {
+    /// The range of the declaration’s name.
+    name_range: Range,
+    /// Is the declaration a type alias? We can’t wait for a type alias to finish checking before
+    /// resolving references to it like we do for other declarations.
+    type_alias: bool,
+    /// References to the declaration that we find before we finish checking.
+    references: Vec<&'src ReferenceType>,
+    /// The declaration which we check lazily.
+    declaration: DeclarationLazy<&'src Declaration, PrecheckDeclaration>,
 }
 
 impl<'src> PrecheckDeclarationLazy<'src> {
     fn unchecked(name_range: Range, declaration: &'src Declaration) -> Self {
-        PrecheckDeclarationLazy::Unchecked {
+        PrecheckDeclarationLazy {
             name_range,
-            declaration: Some(declaration),
+            type_alias: match declaration {
+                Declaration::TypeAlias(_) => true,
+                Declaration::Function(_)
+                | Declaration::Class(_)
+                | Declaration::Import(_)
+                | Declaration::Error(_) => false,
+            },
             references: Vec::new(),
+            declaration: DeclarationLazy::Unchecked(declaration),
         }
     }
+}
 
-    fn name_range(&self) -> Range {
-        match self {
-            PrecheckDeclarationLazy::Unchecked { name_range, .. } => *name_range,
-            PrecheckDeclarationLazy::Checked(PrecheckDeclaration::Function(declaration)) => {
-                declaration.name_range
-            }
-            PrecheckDeclarationLazy::Checked(PrecheckDeclaration::Class(declaration)) => {
-                declaration.name_range
+/// A declaration which we check lazily, the first time it is needed, so that a declaration may
+/// depend on the declarations which come after it. Both the precheck and the checker use this to
+/// find declarations which depend on themselves.
+pub(super) enum DeclarationLazy<Unchecked, Checked> {
+    /// We have not checked our declaration yet.
+    Unchecked(Unchecked),
+    /// We are in the middle of checking our declaration. If we need our declaration again then it
+    /// depends on itself.
+    Checking,
+    /// We have finished checking our declaration.
+    Checked(Checked),
+}
+
+impl<Unchecked, Checked> DeclarationLazy<Unchecked, Checked> {
+    /// If we have not checked our declaration yet then we start checking it and return the
+    /// declaration to check. Otherwise we return `None`.
+    pub(super) fn start_checking(&mut self) -> Option<Unchecked> {
+        match mem::replace(self, DeclarationLazy::Checking) {
+            DeclarationLazy::Unchecked(declaration) => Some(declaration),
+            lazy => {
+                *self = lazy;
+                None
             }
         }
     }
 
-    fn is_checking(&self) -> bool {
+    /// Returns our declaration if we have finished checking it.
+    pub(super) fn checked(&self) -> Option<&Checked> {
         match self {
-            PrecheckDeclarationLazy::Unchecked { declaration, .. } => declaration.is_none(),
-            _ => false,
+            DeclarationLazy::Checked(declaration) => Some(declaration),
+            DeclarationLazy::Unchecked(_) | DeclarationLazy::Checking => None,
         }
     }
 }
@@ -178,55 +260,54 @@ impl<'src> PrecheckDeclarationLazy<'src> {
 /// Resolves a precheck declaration. If we are trying to resolve an unchecked declaration then we
 /// will check that declaration and return the result. If the declaration does not exist or we try
 /// to check ourselves then an error will be returned.
-fn resolve_precheck_declaration<'scope>(
+fn resolve_precheck_declaration<'scope, 'src>(
     diagnostics: &mut DiagnosticsCollection,
-    declarations: &'scope mut PrecheckDeclarationMap,
+    declarations: &'scope mut PrecheckDeclarationMap<'src>,
     range: &Range,
     identifier: &Identifier,
 ) -> Result<&'scope PrecheckDeclaration, DiagnosticRef> {
-    if let Some(declaration_lazy) = declarations.get_mut(identifier) {
-        match declaration_lazy {
-            // If we’ve already checked this declaration then do nothing...
-            PrecheckDeclarationLazy::Checked(_) => {}
-
-            // If we have not yet checked this declaration...
-            PrecheckDeclarationLazy::Unchecked {
-                name_range,
-                declaration,
-                references,
-            } => {
-                // Take the declaration we need to check. This will set the `declaration` field to
-                // `None`. That way we can detect circular references.
-                if let Some(declaration) = declaration.take() {
-                    let references = mem::replace(references, Vec::new());
-                    let declaration = precheck_declaration(diagnostics, declarations, declaration);
-                    let declaration_lazy = declarations.get_mut(identifier).unwrap();
-                    debug_assert!(declaration_lazy.is_checking());
-                    for reference in references {
-                        declaration.resolve_type(reference);
-                    }
-                    *declaration_lazy = PrecheckDeclarationLazy::Checked(declaration);
-                } else {
-                    // If we are already checking this declaration then we found a
-                    // circular reference!
-                    return Err(diagnostics.report(Diagnostic::declaration_cycle_detected(
-                        range.clone(),
-                        identifier.clone(),
-                        name_range.clone(),
-                    )));
-                }
+    let declaration_lazy = match declarations.get_mut(identifier) {
+        Some(declaration_lazy) => declaration_lazy,
+        // Uh oh. We could not find the declaration that was requested.
+        None => {
+            return Err(
+                diagnostics.report(Diagnostic::identifier_not_found(*range, identifier.clone()))
+            );
+        }
+    };
+    match &declaration_lazy.declaration {
+        // If we’ve already checked this declaration then do nothing...
+        DeclarationLazy::Checked(_) => {}
+
+        // If we are already checking this declaration then we found a circular reference!
+        DeclarationLazy::Checking => {
+            return Err(diagnostics.report(Diagnostic::declaration_cycle_detected(
+                *range,
+                identifier.clone(),
+                declaration_lazy.name_range,
+            )));
+        }
+
+        // If we have not yet checked this declaration then check it now. While we check our
+        // declaration it will be `DeclarationLazy::Checking` so that we can detect
+        // circular references.
+        DeclarationLazy::Unchecked(_) => {
+            let declaration = declaration_lazy.declaration.start_checking().unwrap();
+            let references = mem::take(&mut declaration_lazy.references);
+            let declaration = precheck_declaration(diagnostics, declarations, declaration);
+            for reference in references {
+                declaration.resolve_type(reference);
             }
+            declarations.get_mut(identifier).unwrap().declaration =
+                DeclarationLazy::Checked(declaration);
         }
-    } else {
-        // Uh oh. We could not find the declaration that was requested.
-        return Err(diagnostics.report(Diagnostic::identifier_not_found(
-            range.clone(),
-            identifier.clone(),
-        )));
     }
-    match declarations.get(identifier) {
-        Some(PrecheckDeclarationLazy::Checked(declaration)) => Ok(declaration),
-        _ => unreachable!(),
+    match declarations
+        .get(identifier)
+        .and_then(|declaration_lazy| declaration_lazy.declaration.checked())
+    {
+        Some(declaration) => Ok(declaration),
+        None => unreachable!(),
     }
 }
 
@@ -241,31 +322,58 @@ fn precheck_type<'src>(
     type_: &'src Type,
 ) {
     match type_ {
-        Type::Reference(reference) => match declarations.get_mut(&reference.identifier) {
-            // If we have a checked declaration then use it to resolve our reference type.
-            Some(PrecheckDeclarationLazy::Checked(declaration)) => {
-                declaration.resolve_type(reference);
+        Type::Reference(reference) => {
+            // Precheck the type arguments of our reference no matter what it references.
+            for argument in &reference.arguments {
+                precheck_type(diagnostics, declarations, argument);
             }
+            match declarations.get_mut(&reference.identifier) {
+                // If we have a checked declaration then use it to resolve our reference type.
+                Some(PrecheckDeclarationLazy {
+                    declaration: DeclarationLazy::Checked(declaration),
+                    ..
+                }) => {
+                    declaration.resolve_type(reference);
+                }
 
-            // If there’s a declaration with the referenced name but the declaration hasn’t been
-            // checked yet, add our reference to its internal list so that we can resolve the
-            // reference once the declaration has type checked.
-            Some(PrecheckDeclarationLazy::Unchecked { references, .. }) => {
-                references.push(reference);
-            }
+                // A type alias is transparent so we can’t wait for it to finish checking. Check the
+                // type alias now. If we are already checking the type alias then it references
+                // itself and `resolve_precheck_declaration` will report a cycle.
+                Some(PrecheckDeclarationLazy {
+                    type_alias: true, ..
+                }) => {
+                    match resolve_precheck_declaration(
+                        diagnostics,
+                        declarations,
+                        &reference.range,
+                        &reference.identifier,
+                    ) {
+                        Ok(declaration) => declaration.resolve_type(reference),
+                        Err(error) => reference.resolve(error.into()),
+                    }
+                }
 
-            // If there is no declaration with the referenced name then resolve our reference type
-            // to an error type.
-            None => {
-                let error = diagnostics.report(Diagnostic::identifier_not_found(
-                    reference.range,
-                    reference.identifier.clone(),
-                ));
-                reference.resolve(error.into());
+                // If there’s a declaration with the referenced name but the declaration hasn’t been
+                // checked yet, add our reference to its internal list so that we can resolve the
+                // reference once the declaration has type checked.
+                Some(PrecheckDeclarationLazy { references, .. }) => {
+                    references.push(reference);
+                }
+
+                // If there is no declaration with the referenced name then resolve our reference
+                // type to an error type.
+                None => {
+                    let error = diagnostics.report(Diagnostic::identifier_not_found(
+                        reference.range,
+                        reference.identifier.clone(),
+                    ));
+                    reference.resolve(error.into());
+                }
             }
-        },
+        }
 
-        Type::This(_) => unimplemented!(),
+        // The precheck doesn’t know about classes so there’s nothing to check for `This`.
+        Type::This(_) => {}
 
         // Scalar and error types are ok as they are!
         Type::Resolved(ResolvedType::Scalar(_)) => {}
@@ -0,0 +1,310 @@
+use super::avt::Module;
+use super::checker::{Checker, Scope, SharedState};
+use crate::diagnostics::{Diagnostic, DiagnosticsCollection};
+use crate::language as ast;
+use std::collections::HashMap;
+use std::mem;
+
+/// A graph of modules which import declarations from one another. We check all the modules in a
+/// graph together so that every module may use the declarations it imports.
+///
+/// Every module in our graph has a path. A path is relative to the root of our graph, separates
+/// directories with `/`, and does not have a file extension. For example, `lib/math`. An import
+/// path must start with `./` or `../` and is relative to the directory of the importing module.
+pub struct ModuleGraph<'a> {
+    /// The modules in our graph in the order they were added.
+    modules: Vec<GraphModule<'a>>,
+    /// The index of every module in our graph by its path.
+    paths: HashMap<String, usize>,
+}
+
+/// A module in our [`ModuleGraph`].
+struct GraphModule<'a> {
+    /// The path of our module.
+    path: String,
+    /// The AST of our module.
+    module: &'a ast::Module,
+    /// The collection we report our module’s diagnostics to.
+    diagnostics: &'a mut DiagnosticsCollection,
+}
+
+/// An import declaration along with the index of the module it imports from. If we could not find
+/// the module then we don’t have an index.
+type ResolvedImport<'a> = (&'a ast::ImportDeclaration, Option<usize>);
+
+/// The state of a module while we visit our graph looking for import cycles.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Visit {
+    /// We have not visited this module yet.
+    New,
+    /// We are currently visiting the modules this module imports from.
+    Active,
+    /// We have visited this module and all the modules it imports from.
+    Done,
+}
+
+impl<'a> ModuleGraph<'a> {
+    /// Creates a new, empty, module graph.
+    pub fn new() -> Self {
+        ModuleGraph {
+            modules: Vec::new(),
+            paths: HashMap::new(),
+        }
+    }
+
+    /// Adds a module to our graph. We report the diagnostics for our module to the
+    /// provided collection.
+    ///
+    /// Panics if a module with the same path was already added.
+    pub fn add(
+        &mut self,
+        path: &str,
+        module: &'a ast::Module,
+        diagnostics: &'a mut DiagnosticsCollection,
+    ) {
+        let index = self.modules.len();
+        let previous = self.paths.insert(path.to_string(), index);
+        assert!(previous.is_none(), "Module `{}` was already added.", path);
+        self.modules.push(GraphModule {
+            path: path.to_string(),
+            module,
+            diagnostics,
+        });
+    }
+
+    /// Checks every module in our graph for errors. Returns our checked modules in the order they
+    /// were added.
+    pub fn check(mut self) -> Vec<Module> {
+        let imports = self.resolve_imports();
+        let order = self.sort_imports(&imports);
+
+        let mut shared = SharedState::new();
+        let mut scopes = Vec::with_capacity(self.modules.len());
+        let mut declared = Vec::with_capacity(self.modules.len());
+
+        // Add the declarations of every module to scope before we resolve any imports. That way a
+        // module may import any declaration from any other module. Even when the modules import
+        // each other in a cycle.
+        for module in &mut self.modules {
+            let mut checker = Checker::resume(&mut *module.diagnostics, Scope::new(), shared);
+            declared.push(checker.declare_module(module.module));
+            let (scope, next_shared) = checker.suspend();
+            scopes.push(scope);
+            shared = next_shared;
+        }
+
+        // Add the declarations we import to the scope of every module.
+        for (i, imports) in imports.iter().enumerate() {
+            let mut entries = Vec::new();
+            for (import, index) in imports {
+                let index = match index {
+                    Some(index) => *index,
+                    None => continue,
+                };
+                for name in &import.names {
+                    let declaration =
+                        self.modules[index]
+                            .module
+                            .declarations
+                            .iter()
+                            .find(|declaration| match declaration.name() {
+                                Some(declaration_name) => {
+                                    declaration_name.identifier == name.identifier
+                                }
+                                None => false,
+                            });
+                    let entry = match declaration {
+                        None => Err(Diagnostic::export_not_found(
+                            name.range,
+                            name.identifier.clone(),
+                            import.path.clone(),
+                        )),
+                        Some(declaration) if !declaration.export() => {
+                            Err(Diagnostic::import_not_exported(
+                                name.range,
+                                name.identifier.clone(),
+                                import.path.clone(),
+                            ))
+                        }
+                        Some(_) => match scopes[index].resolve_export(&name.identifier) {
+                            Some(entry_kind) => Ok(entry_kind),
+                            None => Err(Diagnostic::export_not_found(
+                                name.range,
+                                name.identifier.clone(),
+                                import.path.clone(),
+                            )),
+                        },
+                    };
+                    entries.push((name, entry));
+                }
+            }
+            let scope = mem::replace(&mut scopes[i], Scope::new());
+            let mut checker = Checker::resume(&mut *self.modules[i].diagnostics, scope, shared);
+            for (name, entry) in entries {
+                match entry {
+                    Ok(entry_kind) => checker.declare_import(name, entry_kind),
+                    Err(diagnostic) => {
+                        checker.report_diagnostic(diagnostic);
+                    }
+                }
+            }
+            let (scope, next_shared) = checker.suspend();
+            scopes[i] = scope;
+            shared = next_shared;
+        }
+
+        // Check the type aliases of every module. We check a module after the modules it imports
+        // from so that we check a type alias in the scope of the module which declared it.
+        for &i in &order {
+            let scope = mem::replace(&mut scopes[i], Scope::new());
+            let mut checker = Checker::resume(&mut *self.modules[i].diagnostics, scope, shared);
+            checker.check_module_type_aliases(&declared[i]);
+            let (scope, next_shared) = checker.suspend();
+            scopes[i] = scope;
+            shared = next_shared;
+        }
+
+        // Check the classes of every module before we check any other declaration so that we know
+        // the fields of every class before we check code which uses them. Also check the signature
+        // of every function so that code may call the functions of any module.
+        for ((module, scope), declared) in self.modules.iter_mut().zip(&mut scopes).zip(&declared) {
+            let mut checker = Checker::resume(
+                &mut *module.diagnostics,
+                mem::replace(scope, Scope::new()),
+                shared,
+            );
+            checker.check_module_classes(module.module, declared);
+            checker.check_module_functions(module.module, declared);
+            let (next_scope, next_shared) = checker.suspend();
+            *scope = next_scope;
+            shared = next_shared;
+        }
+
+        // Finally, check the attributes and all the other declarations of every module. We check a
+        // module after the modules it imports from so that we know the type of every function we
+        // import even when it is not annotated.
+        let mut checked: Vec<_> = (0..self.modules.len()).map(|_| None).collect();
+        for i in order {
+            let scope = mem::replace(&mut scopes[i], Scope::new());
+            let module = &mut self.modules[i];
+            let mut checker = Checker::resume(&mut *module.diagnostics, scope, shared);
+            checker.check_module_attributes(module.module);
+            checked[i] = Some(checker.check_module_declarations(module.module, &declared[i]));
+            let (_, next_shared) = checker.suspend();
+            shared = next_shared;
+        }
+        checked.into_iter().map(Option::unwrap).collect()
+    }
+
+    /// Finds the module imported by every import declaration in our graph. If we could not find
+    /// the module for an import then we report an error.
+    fn resolve_imports(&mut self) -> Vec<Vec<ResolvedImport<'a>>> {
+        let mut imports = Vec::with_capacity(self.modules.len());
+        let paths = &self.paths;
+        for module in &mut self.modules {
+            let mut module_imports = Vec::new();
+            let ast_module: &'a ast::Module = module.module;
+            for declaration in &ast_module.declarations {
+                if let ast::Declaration::Import(import) = declaration {
+                    let index = resolve_path(&module.path, &import.path)
+                        .and_then(|path| paths.get(&path).cloned());
+                    if index.is_none() {
+                        module.diagnostics.report(Diagnostic::module_not_found(
+                            import.path_range,
+                            import.path.clone(),
+                        ));
+                    }
+                    module_imports.push((import, index));
+                }
+            }
+            imports.push(module_imports);
+        }
+        imports
+    }
+
+    /// Sorts our modules so that every module comes after the modules it imports from. Returns the
+    /// index of every module in sorted order. Reports an error for every import which closes a
+    /// cycle of modules that import from one another since we can’t sort those modules.
+    fn sort_imports(&mut self, imports: &[Vec<ResolvedImport<'a>>]) -> Vec<usize> {
+        let mut visits = vec![Visit::New; self.modules.len()];
+        let mut stack = Vec::new();
+        let mut order = Vec::with_capacity(self.modules.len());
+        for i in 0..self.modules.len() {
+            self.visit(i, imports, &mut visits, &mut stack, &mut order);
+        }
+        order
+    }
+
+    /// Visits a module and all the modules it imports from, depth first. If we find an import of a
+    /// module we are still visiting then that import closes a cycle. The stack holds every module we
+    /// are still visiting so that we can report all the modules in a cycle. Once we are done
+    /// visiting our module we add it to the sorted order.
+    fn visit(
+        &mut self,
+        i: usize,
+        imports: &[Vec<ResolvedImport<'a>>],
+        visits: &mut [Visit],
+        stack: &mut Vec<usize>,
+        order: &mut Vec<usize>,
+    ) {
+        if visits[i] != Visit::New {
+            return;
+        }
+        visits[i] = Visit::Active;
+        stack.push(i);
+        for (import, index) in &imports[i] {
+            if let Some(index) = index {
+                match visits[*index] {
+                    Visit::New => self.visit(*index, imports, visits, stack, order),
+                    Visit::Active => {
+                        // The cycle starts at our module, goes through every module on the stack
+                        // from the one we import, and comes back to our module.
+                        let start = stack.iter().position(|j| j == index).unwrap();
+                        let cycle = Some(&i)
+                            .into_iter()
+                            .chain(&stack[start..])
+                            .map(|&j| self.modules[j].path.clone())
+                            .collect();
+                        self.modules[i]
+                            .diagnostics
+                            .report(Diagnostic::import_cycle_detected(
+                                import.path_range,
+                                import.path.clone(),
+                                cycle,
+                            ));
+                    }
+                    Visit::Done => {}
+                }
+            }
+        }
+        stack.pop();
+        visits[i] = Visit::Done;
+        order.push(i);
+    }
+}
+
+impl<'a> Default for ModuleGraph<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Resolves the path of the module an import declaration imports from. Returns `None` if the path
+/// is not relative or if the path goes above the root of our graph.
+fn resolve_path(importer: &str, path: &str) -> Option<String> {
+    if !path.starts_with("./") && !path.starts_with("../") {
+        return None;
+    }
+    let mut segments: Vec<_> = importer.split('/').collect();
+    segments.pop();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            segment => segments.push(segment),
+        }
+    }
+    Some(segments.join("/"))
+}
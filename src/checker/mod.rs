@@ -1,11 +1,14 @@
 //! Confirming that code written by a programmer will have correct runtime semantics according to
 //! the Brite language.
 
-// mod checker;
+#[allow(clippy::module_inception)]
+mod checker;
 mod checker2;
+mod graph;
 // mod types;
 
-// pub mod avt;
+pub mod avt;
 
-// pub use self::checker::*;
+pub use self::checker::*;
 pub use self::checker2::*;
+pub use self::graph::*;
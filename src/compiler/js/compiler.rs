@@ -1,10 +1,15 @@
 use super::js;
-use crate::language::*;
-use crate::parser::Identifier;
+use crate::checker::avt::*;
+use crate::diagnostics::{Diagnostic, DiagnosticRef};
+use crate::parser::{Document, Identifier};
 use crate::utils::binding::BindingMap;
+use num::ToPrimitive;
+use std::collections::{HashMap, HashSet};
+use std::iter;
+use std::mem;
 
 /// Manages the compilation of a Brite program into JavaScript code.
-pub struct Compiler {
+pub struct Compiler<'a> {
     /// Keeps track of all the Brite bindings currently in scope. We can lookup the JavaScript
     /// identifier for a Brite identifier by looking in this map.
     bindings: BindingMap<Identifier, Binding>,
@@ -12,58 +17,517 @@ pub struct Compiler {
     /// Keeps track of all the JavaScript variables currently in scope so that we don’t create a new
     /// variable name that conflicts with an existing one.
     bindings_js: BindingMap<js::Identifier, ()>,
+
+    /// The loops we are currently compiling the body of. The innermost loop is last.
+    loops: Vec<Loop>,
+
+    /// The functions in our module declared with `@inline`. We substitute the body of an inline
+    /// function at its call sites instead of calling it. A [`Binding`] has an index into this list.
+    inline_functions: Vec<InlineFunction<'a>>,
+
+    /// The classes in our module declared with `@inline`. We substitute the constructor of an
+    /// inline class wherever it is constructed.
+    inline_classes: HashSet<Identifier>,
+
+    /// The inline functions we are currently substituting the body of. The innermost function is
+    /// last. We never substitute an inline function inside its own body.
+    inlining: Vec<usize>,
+
+    /// Whether the inline function body we are substituting references a declaration which is
+    /// shadowed at our call site or returns early. If so then we call the function instead.
+    inline_captured: bool,
+
+    /// Whether we are compiling the body of an inline function we are substituting and not the
+    /// body of a function nested inside of it. A `return` here would return from our call site.
+    inline_body: bool,
+
+    /// The JavaScript identifiers of the module declarations referenced by the outlined function
+    /// we are compiling. The chunk of our outlined function imports them from our module. If we
+    /// are not compiling an outlined function then we don’t have a list.
+    outline_references: Option<Vec<js::Identifier>>,
+
+    /// The document our module was parsed from. We use it to find the line and column of the
+    /// errors we throw.
+    document: &'a Document,
+
+    /// The name of the Brite file our module was compiled from which we print in the errors
+    /// we throw. We assume it has the same name as our JavaScript module.
+    file: String,
+}
+
+/// A JavaScript module with the implementation of an outlined function. A chunk is loaded
+/// asynchronously with a dynamic `import()` the first time our outlined function is called. Write
+/// a chunk next to the module it was compiled from with a file name of `{name}.js`.
+pub struct Chunk {
+    /// The name of our chunk. Our module’s name followed by the name of our function.
+    pub name: String,
+    /// The JavaScript module of our chunk.
+    pub program: js::Program,
 }
 
-impl Compiler {
-    /// Creates a new
-    pub fn new() -> Self {
+impl<'a> Compiler<'a> {
+    /// Creates a new compiler for a module parsed from the provided document.
+    pub fn new(document: &'a Document) -> Self {
         Compiler {
             bindings: BindingMap::new(),
             bindings_js: BindingMap::new(),
+            loops: Vec::new(),
+            inline_functions: Vec::new(),
+            inline_classes: HashSet::new(),
+            inlining: Vec::new(),
+            inline_captured: false,
+            inline_body: false,
+            outline_references: None,
+            document,
+            file: String::new(),
         }
     }
 
-    /// Compiles a Brite module into a JavaScript module. Code compiled into JavaScript should have
-    /// the same behavior as code compiled into another language, like LLVM.
-    pub fn compile_module(mut self, module: &Module) -> js::Program {
-        js::Program::new(
-            module
-                .declarations
-                .iter()
-                .map(|declaration| self.compile_declaration(declaration))
-                .collect(),
-        )
+    /// Compiles the AVT of a Brite module, which our checker returns, into a JavaScript module.
+    /// Code compiled into JavaScript should have the same behavior as code compiled into another
+    /// language, like LLVM.
+    ///
+    /// The name of our module is the file name of our JavaScript module without an extension. We
+    /// also return a chunk for every outlined function in our module. Chunks import from our
+    /// module by its name.
+    ///
+    /// Programs with errors still run in development so wherever our checker inserted an error
+    /// node we throw its error instead.
+    pub fn compile_module(mut self, name: &str, module: &'a Module) -> (js::Program, Vec<Chunk>) {
+        self.file = format!("{}.ite", name);
+
+        let mut js_statements = Vec::new();
+
+        // Compile our imports before any other declaration so that every declaration may reference
+        // the names we import. JavaScript hoists imports to the top of a module anyway.
+        for declaration in &module.declarations {
+            if let Declaration::Import(import) = declaration {
+                js_statements.extend(self.compile_import_declaration(import));
+            }
+        }
+
+        // Declare all of our functions and classes before we compile any declaration so that a
+        // function may reference the functions and classes declared after it.
+        //
+        // We also remember the name every exported declaration is exported with so that our
+        // chunks may import our declarations by that name.
+        let mut js_functions = Vec::new();
+        let mut js_classes = Vec::new();
+        let mut js_exported = HashMap::new();
+        for declaration in &module.declarations {
+            if let Declaration::Class(class) = declaration {
+                let id = self.scope_declare(&class.name);
+                if class.inline {
+                    self.inline_classes.insert(class.name.clone());
+                }
+                if class.export {
+                    js_exported.insert(id.clone(), exported_identifier(&class.name));
+                }
+                js_classes.push(id);
+            }
+            if let Declaration::Function(function) = declaration {
+                // We can’t substitute the body of an async function at a call site since it might
+                // await. So we always call an async function.
+                let inline = if function.inline && !function.function.async_ {
+                    self.inline_functions.push(InlineFunction {
+                        function: &function.function,
+                        used: false,
+                    });
+                    Some(self.inline_functions.len() - 1)
+                } else {
+                    None
+                };
+                let id = self.scope_declare_binding(&function.name, inline);
+                if function.export {
+                    js_exported.insert(id.clone(), exported_identifier(&function.name));
+                }
+                js_functions.push((id, inline));
+            }
+        }
+
+        let mut js_exports = Vec::new();
+        let mut js_declarations = Vec::new();
+        let mut js_chunks = Vec::new();
+        let mut js_chunk_imports = Vec::new();
+        let mut js_functions = js_functions.into_iter();
+        let mut js_classes = js_classes.into_iter();
+        for declaration in &module.declarations {
+            match declaration {
+                Declaration::Function(function) => {
+                    let (id, inline) = js_functions.next().unwrap();
+                    let js_statement = if function.outline {
+                        let js_chunk = self.compile_function_chunk(
+                            name,
+                            &js_exported,
+                            &mut js_chunk_imports,
+                            id.clone(),
+                            function,
+                        );
+                        js_chunks.push(js_chunk);
+                        self.compile_outlined_function_declaration(
+                            name,
+                            &mut js_exports,
+                            id,
+                            function,
+                        )
+                    } else {
+                        self.compile_function_declaration(&mut js_exports, id, function)
+                    };
+                    // Other modules may call an exported function so we always keep it.
+                    let inline = if function.export { None } else { inline };
+                    js_declarations.push((inline, None, js_statement));
+                }
+                Declaration::Class(class) => {
+                    let id = js_classes.next().unwrap();
+                    let js_statement = self.compile_class_declaration(&mut js_exports, id, class);
+                    js_declarations.push((None, Some(class), js_statement));
+                }
+                _ => {
+                    if let Some(js_statement) = self.compile_declaration(declaration) {
+                        js_declarations.push((None, None, js_statement));
+                    }
+                }
+            }
+        }
+
+        // JavaScript classes are not hoisted so a class must come after the class it extends. We
+        // hold back a class which extends a class declared later in our module until we have
+        // added the class it extends.
+        let module_classes: HashSet<_> = module
+            .declarations
+            .iter()
+            .filter_map(|declaration| match declaration {
+                Declaration::Class(class) => Some(&class.name),
+                _ => None,
+            })
+            .collect();
+        let mut added_classes = HashSet::new();
+        let mut waiting_classes = Vec::new();
+
+        for (inline, class, js_statement) in js_declarations {
+            // Leave out the inline functions which we substituted at every call site and which are
+            // not otherwise referenced.
+            if !inline.map_or(true, |index| self.inline_functions[index].used) {
+                continue;
+            }
+            let class = match class {
+                Some(class) => class,
+                None => {
+                    js_statements.push(js_statement);
+                    continue;
+                }
+            };
+            match &class.extends {
+                Some(extends)
+                    if module_classes.contains(extends) && !added_classes.contains(extends) =>
+                {
+                    waiting_classes.push((extends, &class.name, js_statement));
+                }
+                _ => {
+                    let mut ready_classes = vec![(&class.name, js_statement)];
+                    while let Some((name, js_statement)) = ready_classes.pop() {
+                        js_statements.push(js_statement);
+                        added_classes.insert(name);
+                        let (ready, waiting): (Vec<_>, Vec<_>) = waiting_classes
+                            .into_iter()
+                            .partition(|(extends, _, _)| *extends == name);
+                        waiting_classes = waiting;
+                        ready_classes.extend(
+                            ready
+                                .into_iter()
+                                .rev()
+                                .map(|(_, name, js_statement)| (name, js_statement)),
+                        );
+                    }
+                }
+            }
+        }
+
+        // The checker never lets classes extend each other in a cycle so we should have added
+        // every class by now. Add any class we are still holding back anyway instead of losing it.
+        for (_, _, js_statement) in waiting_classes {
+            js_statements.push(js_statement);
+        }
+
+        // Export the declarations our chunks import from our module which we don’t already
+        // export. See `chunk_identifier`.
+        for id in js_chunk_imports {
+            let exported = chunk_identifier(&id);
+            js_exports.push(js::ExportSpecifier::new(id, exported));
+        }
+
+        // Export all the declarations which we could not export where they were declared.
+        if !js_exports.is_empty() {
+            js_statements.push(js::Statement::export_specifiers(js_exports));
+        }
+
+        (js::Program::new(js_statements), js_chunks)
     }
 
-    fn compile_declaration(&mut self, declaration: &Declaration) -> js::Statement {
-        match declaration {
-            // Compile a function declaration. Always use a JavaScript function declaration instead
-            // of an arrow function expression. Some JavaScript developers like to only use arrow
-            // functions for their top-level functions, but function declarations better match the
-            // aesthetics of the Brite language.
-            Declaration::Function(function) => {
-                let id = self.scope_declare(&function.name.identifier);
-                let (params, body) = self.compile_function(&function.function);
-                let body = match body {
-                    js::ArrowFunctionBody::Block(block) => block,
-                    js::ArrowFunctionBody::Expression(expression) => {
-                        js::BlockStatement::new(vec![js::Statement::return_(*expression)])
+    /// Compiles a function declaration with the JavaScript identifier we declared for it in
+    /// [`Compiler::compile_module`]. Always use a JavaScript function declaration instead of an
+    /// arrow function expression. Some JavaScript developers like to only use arrow functions for
+    /// their top-level functions, but function declarations better match the aesthetics of the
+    /// Brite language.
+    fn compile_function_declaration(
+        &mut self,
+        js_exports: &mut Vec<js::ExportSpecifier>,
+        id: js::Identifier,
+        function: &FunctionDeclaration,
+    ) -> js::Statement {
+        let (params, body) = self.compile_function(&function.function);
+        let body = function_body(body);
+        let js_function =
+            js::Statement::function_declaration(function.function.async_, id.clone(), params, body)
+                .with_range(function.range);
+        export_declaration(js_exports, function.export, &function.name, id, js_function)
+    }
+
+    /// Compiles the stub of an outlined function declaration which we use in place of our function.
+    /// Our stub loads the chunk with our function’s implementation and then calls our function.
+    /// JavaScript only loads a module once so we only load our chunk the first time our stub
+    /// is called.
+    fn compile_outlined_function_declaration(
+        &mut self,
+        name: &str,
+        js_exports: &mut Vec<js::ExportSpecifier>,
+        id: js::Identifier,
+        function: &FunctionDeclaration,
+    ) -> js::Statement {
+        // Our stub passes every argument along to our function. If a parameter was not passed
+        // then our function will use its default value.
+        let (params, body) = self.scope_nest_js(|compiler| {
+            compiler.scope_nest(|compiler| {
+                let (params, arguments): (Vec<_>, Vec<_>) = function
+                    .function
+                    .parameters
+                    .iter()
+                    .map(|parameter| {
+                        let id = match &parameter.kind {
+                            PatternKind::Binding(identifier) => compiler.scope_declare(identifier),
+                            _ => compiler.scope_declare_js("arg"),
+                        };
+                        (
+                            js::Pattern::identifier(id.clone()),
+                            js::Expression::identifier(id),
+                        )
+                    })
+                    .unzip();
+                let chunk = compiler.scope_declare_js("chunk");
+                let load = js::Expression::call(
+                    js::Expression::member(
+                        js::Expression::import(format!("./{}.{}.js", name, id.as_str())),
+                        js::Identifier::new_unchecked("then".to_string()),
+                    ),
+                    vec![js::Expression::arrow_function(
+                        false,
+                        vec![js::Pattern::identifier(chunk.clone())],
+                        js::ArrowFunctionBody::Expression(Box::new(js::Expression::call(
+                            js::Expression::member(js::Expression::identifier(chunk), id.clone()),
+                            arguments,
+                        ))),
+                    )],
+                );
+                (
+                    params,
+                    js::BlockStatement::new(vec![js::Statement::return_(load)]),
+                )
+            })
+        });
+        // Our stub already returns a promise so it doesn’t need to be async.
+        let js_function = js::Statement::function_declaration(false, id.clone(), params, body)
+            .with_range(function.range);
+        export_declaration(js_exports, function.export, &function.name, id, js_function)
+    }
+
+    /// Compiles the implementation of an outlined function declaration into its own chunk. Our
+    /// chunk imports the declarations our function references from our module. We import an
+    /// exported declaration by the name it is exported with. Otherwise we add its identifier to
+    /// `js_chunk_imports` so that our module may export it for our chunk.
+    fn compile_function_chunk(
+        &mut self,
+        name: &str,
+        js_exported: &HashMap<js::Identifier, js::Identifier>,
+        js_chunk_imports: &mut Vec<js::Identifier>,
+        id: js::Identifier,
+        function: &FunctionDeclaration,
+    ) -> Chunk {
+        let outline_references = self.outline_references.replace(Vec::new());
+        let (params, body) = self.compile_function(&function.function);
+        let references = mem::replace(&mut self.outline_references, outline_references).unwrap();
+
+        // Our function is declared in our chunk so we don’t import it.
+        let specifiers: Vec<_> = references
+            .into_iter()
+            .filter(|reference| *reference != id)
+            .map(|reference| match js_exported.get(&reference) {
+                Some(exported) => js::ImportSpecifier::new(exported.clone(), reference),
+                None => {
+                    if !js_chunk_imports.contains(&reference) {
+                        js_chunk_imports.push(reference.clone());
                     }
-                };
-                js::Statement::function_declaration(id, params, body)
+                    js::ImportSpecifier::new(chunk_identifier(&reference), reference)
+                }
+            })
+            .collect();
+        let mut js_statements = Vec::with_capacity(2);
+        if !specifiers.is_empty() {
+            js_statements.push(js::Statement::import_declaration(
+                specifiers,
+                format!("./{}.js", name),
+            ));
+        }
+        let chunk_name = format!("{}.{}", name, id.as_str());
+        let js_function = js::Statement::function_declaration(
+            function.function.async_,
+            id,
+            params,
+            function_body(body),
+        )
+        .with_range(function.range);
+        js_statements.push(js::Statement::export_declaration(js_function));
+        Chunk {
+            name: chunk_name,
+            program: js::Program::new(js_statements),
+        }
+    }
+
+    /// Compiles a class declaration with the JavaScript identifier we declared for it in
+    /// [`Compiler::compile_module`] into a JavaScript class declaration.
+    ///
+    /// Our constructor accepts an object with the value of every field, including the fields of
+    /// the class we extend, and sets the fields our class declares. A method with a `this`
+    /// parameter is called on an instance of our class. Every other method is static. Base methods
+    /// don’t have an implementation so we leave them out.
+    ///
+    /// We substitute the constructor of an inline class wherever it is constructed so an inline
+    /// class usually doesn’t need a constructor. Unless another module constructs our class or
+    /// our class is a base class whose constructor is called by the classes which extend it.
+    fn compile_class_declaration(
+        &mut self,
+        js_exports: &mut Vec<js::ExportSpecifier>,
+        id: js::Identifier,
+        class: &ClassDeclaration,
+    ) -> js::Statement {
+        let super_class = class
+            .extends
+            .as_ref()
+            .map(|extends| match self.scope_resolve(extends) {
+                Some(js_identifier) => js_identifier.clone(),
+                None => unimplemented!(),
+            });
+        let mut js_body = Vec::new();
+
+        // If we don’t declare any fields then JavaScript’s default constructor will pass our
+        // fields along to the class we extend.
+        let fields = &class.fields;
+        let inline = class.inline && !class.export && !class.base;
+        if !fields.is_empty() && !inline {
+            let (params, body) = self.scope_nest_js(|compiler| {
+                let js_fields = compiler.scope_declare_js("fields");
+                let mut js_statements = Vec::with_capacity(fields.len() + 1);
+                if super_class.is_some() {
+                    js_statements.push(js::Statement::expression(js::Expression::call(
+                        js::Expression::super_(),
+                        vec![js::Expression::identifier(js_fields.clone())],
+                    )));
+                }
+                for field in fields {
+                    let property = js::Identifier::new_unchecked(field.name.as_str().to_string());
+                    js_statements.push(js::Statement::expression(js::Expression::assignment(
+                        js::Pattern::member(js::Expression::this(), property.clone()),
+                        js::Expression::member(
+                            js::Expression::identifier(js_fields.clone()),
+                            property,
+                        ),
+                    )));
+                }
+                (
+                    vec![js::Pattern::identifier(js_fields)],
+                    js::BlockStatement::new(js_statements),
+                )
+            });
+            js_body.push(js::MethodDefinition::constructor(params, body));
+        }
+
+        for method in &class.methods {
+            if let Some(function) = &method.function {
+                let (params, body) = self.compile_function(function);
+                js_body.push(js::MethodDefinition::method(
+                    method.static_,
+                    function.async_,
+                    js::Identifier::new_unchecked(method.name.as_str().to_string()),
+                    params,
+                    function_body(body),
+                ));
             }
+        }
+
+        let js_class = js::Statement::class_declaration(id.clone(), super_class, js_body)
+            .with_range(class.range);
+        export_declaration(js_exports, class.export, &class.name, id, js_class)
+    }
+
+    fn compile_declaration(&mut self, declaration: &Declaration) -> Option<js::Statement> {
+        match declaration {
+            // We compile function declarations in `Compiler::compile_function_declaration`.
+            Declaration::Function(_) => unreachable!(),
+
+            // We compile class declarations in `Compiler::compile_class_declaration`.
+            Declaration::Class(_) => unreachable!(),
+
+            // Types don’t exist at runtime so there’s nothing to compile for a type alias.
+            Declaration::TypeAlias(_) => None,
+
+            // We already compiled our imports in `Compiler::compile_module`.
+            Declaration::Import(_) => None,
+
+            // We don’t compile a declaration we could not parse. Throwing its error when our module
+            // is evaluated would stop the rest of our program from running. Instead we throw
+            // wherever the names it would have declared are referenced.
+            Declaration::Error(_) => None,
+        }
+    }
 
-            Declaration::Class(_) => unimplemented!(),
+    /// Compiles an import declaration into a JavaScript import declaration. We keep the path of the
+    /// module we import from exactly as the programmer wrote it.
+    ///
+    /// Types don’t exist at runtime so we only import values. If we only import types then we
+    /// don’t compile our import declaration at all.
+    ///
+    /// Module paths don’t have a file extension, but ES modules must be imported with their full
+    /// file name. So we add `.js` to the path just like we do when importing our own chunks.
+    fn compile_import_declaration(&mut self, import: &ImportDeclaration) -> Option<js::Statement> {
+        let specifiers: Vec<_> = import
+            .values
+            .iter()
+            .map(|name| {
+                let imported = js::Identifier::new_unchecked(name.as_str().to_string());
+                let local = self.scope_declare(name);
+                js::ImportSpecifier::new(imported, local)
+            })
+            .collect();
+        if specifiers.is_empty() {
+            return None;
         }
+        Some(js::Statement::import_declaration(
+            specifiers,
+            format!("{}.js", import.path),
+        ))
     }
 
     fn compile_function(
         &mut self,
         function: &Function,
     ) -> (Vec<js::Pattern>, js::ArrowFunctionBody) {
-        self.scope_nest_js(|compiler| {
+        let loops = mem::take(&mut self.loops);
+        let inline_body = mem::replace(&mut self.inline_body, false);
+        let result = self.scope_nest_js(|compiler| {
             compiler.scope_nest(|compiler| compiler.compile_function_without_nest(function))
-        })
+        });
+        self.loops = loops;
+        self.inline_body = inline_body;
+        result
     }
 
     /// Compiles a function without introducing any levels of nesting. Call
@@ -72,15 +536,39 @@ impl Compiler {
         &mut self,
         function: &Function,
     ) -> (Vec<js::Pattern>, js::ArrowFunctionBody) {
-        // Compile our function’s parameters.
+        // Compile our function’s parameters. A parameter with a default value compiles to a
+        // JavaScript assignment pattern. We compile the default before we compile the parameter’s
+        // pattern so that the default may only reference the parameters before it.
+        //
+        // The `this` parameter of a method is the instance our method was called on which is
+        // JavaScript’s `this` so we don’t compile a parameter for it.
         let params = function
             .parameters
             .iter()
-            .map(|parameter| self.compile_pattern(&parameter.pattern))
+            .zip(&function.defaults)
+            .filter_map(|(parameter, default)| match (&parameter.kind, default) {
+                (PatternKind::This, _) => None,
+                (_, None) => Some(self.compile_pattern(parameter)),
+                (_, Some(default)) => {
+                    // TODO: A default which needs statements, like
+                    // `port: Int = do { foo(); 80 }`.
+                    let mut js_statements = Vec::new();
+                    let js_default = self.compile_expression(&mut js_statements, default);
+                    if !js_statements.is_empty() {
+                        unimplemented!()
+                    }
+                    let js_pattern = self.compile_pattern(parameter);
+                    Some(js::Pattern::assignment(js_pattern, js_default))
+                }
+            })
             .collect();
 
-        // Compile our block with a fresh array of JavaScript statements.
+        // Compile our block with a fresh array of JavaScript statements. If we could not parse the
+        // pattern of a parameter then our function throws that error before running its body.
         let mut js_statements = Vec::with_capacity(function.body.statements.len());
+        if let Some(error) = function.parameters.iter().find_map(pattern_error) {
+            js_statements.push(self.compile_error(error));
+        }
         let return_expression = self.compile_block_without_nest(&mut js_statements, &function.body);
 
         // If compiling the block did not add any statements, then let’s use a simple arrow
@@ -131,8 +619,40 @@ impl Compiler {
         js::Expression::undefined_literal()
     }
 
+    /// Compiles a statement, adding some JavaScript statements as we compile. Every JavaScript
+    /// statement we add maps back to our Brite statement in source maps unless it maps somewhere
+    /// more specific already.
     fn compile_statement(&mut self, js_statements: &mut Vec<js::Statement>, statement: &Statement) {
+        let start = js_statements.len();
+        self.compile_statement_without_range(js_statements, statement);
+        for js_statement in &mut js_statements[start..] {
+            js_statement.default_range(statement.range);
+        }
+    }
+
+    fn compile_statement_without_range(
+        &mut self,
+        js_statements: &mut Vec<js::Statement>,
+        statement: &Statement,
+    ) {
         match &statement.kind {
+            // If our checker replaced our expression with an error node then we can throw our
+            // error with a JavaScript statement.
+            StatementKind::Expression(Expression {
+                kind: ExpressionKind::Error(error),
+                ..
+            }) => js_statements.push(self.compile_error(&error.error)),
+
+            // We don’t need the value of a conditional expression statement so we don’t assign it
+            // to a variable.
+            StatementKind::Expression(Expression {
+                kind: ExpressionKind::Conditional(conditional),
+                ..
+            }) => {
+                let js_test = self.compile_expression(js_statements, &conditional.test);
+                self.compile_conditional(js_statements, js_test, conditional, false);
+            }
+
             StatementKind::Expression(expression) => {
                 let js_expression = self.compile_expression(js_statements, expression);
 
@@ -151,16 +671,162 @@ impl Compiler {
                 }
             }
 
+            // A binding statement with a hole pattern binds nothing so we only need to evaluate
+            // our value.
+            StatementKind::Binding(BindingStatement {
+                pattern:
+                    Pattern {
+                        kind: PatternKind::Hole,
+                        ..
+                    },
+                value,
+                ..
+            }) => {
+                let js_expression = self.compile_expression(js_statements, value);
+                if !js_expression.is_undefined_literal() {
+                    js_statements.push(js::Statement::expression(js_expression));
+                }
+            }
+
+            // Only bindings which are assigned to after they are declared need to be a JavaScript
+            // `let`. Every other binding becomes a JavaScript constant.
             StatementKind::Binding(binding) => {
-                let js_statement = js::Statement::variable_declaration(
-                    js::VariableDeclarationKind::Const,
-                    self.compile_pattern(&binding.pattern),
-                    self.compile_expression(js_statements, &binding.value),
-                );
+                let kind = if binding.reassigned {
+                    js::VariableDeclarationKind::Let
+                } else {
+                    js::VariableDeclarationKind::Const
+                };
+                // Compile our value before our pattern so that `let x = x` references the `x` we
+                // are shadowing.
+                let js_value = self.compile_expression(js_statements, &binding.value);
+                let js_pattern = self.compile_pattern(&binding.pattern);
+                // If our pattern has an error then we evaluate our value and throw our error
+                // instead of binding it.
+                if let Some(error) = pattern_error(&binding.pattern) {
+                    if !js_value.is_undefined_literal() {
+                        js_statements.push(js::Statement::expression(js_value));
+                    }
+                    js_statements.push(self.compile_error(error));
+                } else {
+                    let js_statement =
+                        js::Statement::variable_declaration(kind, js_pattern, js_value);
+                    js_statements.push(js_statement);
+                }
+            }
+
+            // The checker makes sure we only assign to variables so we assign to the JavaScript
+            // identifier of our variable. If our variable was bound by a pattern with an error
+            // then we never declared it, but we also threw before we could get here. So we only
+            // evaluate our value.
+            //
+            // JavaScript has no compound assignment which wraps around like a 32-bit integer. So
+            // we compile an integer compound assignment like `x += y` to `x = x + y | 0`.
+            //
+            // TODO: `"${x}${do { x = 2; "" }}"` reads `x` after the assignment since we hoist the
+            // statements of a block before the expression which contains it.
+            StatementKind::Assignment(assignment) => {
+                let js_target = self.scope_resolve(&assignment.target).cloned();
+                let js_value = self.compile_expression(js_statements, &assignment.value);
+                let js_expression = match (js_target, &assignment.operator) {
+                    (None, _) => js_value,
+                    (Some(js_target), None) => {
+                        js::Expression::assignment(js::Pattern::identifier(js_target), js_value)
+                    }
+                    (Some(js_target), Some(operator)) if assignment.integer => {
+                        js::Expression::assignment(
+                            js::Pattern::identifier(js_target.clone()),
+                            compile_arithmetic(
+                                &operator.infix_operator(),
+                                true,
+                                js::Expression::identifier(js_target),
+                                js_value,
+                            ),
+                        )
+                    }
+                    (Some(js_target), Some(operator)) => js::Expression::compound_assignment(
+                        match operator {
+                            AssignmentOperator::Add => js::AssignmentOperator::Add,
+                            AssignmentOperator::Subtract => js::AssignmentOperator::Subtract,
+                            AssignmentOperator::Multiply => js::AssignmentOperator::Multiply,
+                            AssignmentOperator::Divide => js::AssignmentOperator::Divide,
+                            AssignmentOperator::Remainder => js::AssignmentOperator::Remainder,
+                        },
+                        js::Pattern::identifier(js_target),
+                        js_value,
+                    ),
+                };
+                if !js_expression.is_undefined_literal() {
+                    js_statements.push(js::Statement::expression(js_expression));
+                }
+            }
+
+            // If we break with a value then we assign that value to the result variable of our
+            // loop. We only need a JavaScript label when we aren’t breaking the innermost loop.
+            //
+            // The checker replaces a break outside of the loop it references with an
+            // error statement.
+            StatementKind::Break(break_) => {
+                let index = match self.find_loop(break_.label.as_ref()) {
+                    Some(index) => index,
+                    None => unreachable!(),
+                };
+                if let Some(argument) = &break_.argument {
+                    let js_argument = self.compile_expression(js_statements, argument);
+                    let result = match &self.loops[index].result {
+                        Some(result) => result.clone(),
+                        None => {
+                            let result = self.scope_declare_js("result");
+                            self.loops[index].result = Some(result.clone());
+                            result
+                        }
+                    };
+                    js_statements.push(js::Statement::expression(js::Expression::assignment(
+                        js::Pattern::identifier(result),
+                        js_argument,
+                    )));
+                }
+                js_statements.push(js::Statement::break_(self.loop_js_label(index)));
+            }
+
+            StatementKind::Continue(continue_) => {
+                let index = match self.find_loop(continue_.label.as_ref()) {
+                    Some(index) => index,
+                    None => unreachable!(),
+                };
+                js_statements.push(js::Statement::continue_(self.loop_js_label(index)));
+            }
+
+            // We can’t substitute the body of an inline function which returns early since our
+            // `return` would return from our call site instead.
+            StatementKind::Return(argument) => {
+                if self.inline_body {
+                    self.inline_captured = true;
+                }
+                let js_statement = match argument {
+                    Some(argument) => {
+                        let js_argument = self.compile_expression(js_statements, argument);
+                        if js_argument.is_undefined_literal() {
+                            js::Statement::return_without_argument()
+                        } else {
+                            js::Statement::return_(js_argument)
+                        }
+                    }
+                    None => js::Statement::return_without_argument(),
+                };
                 js_statements.push(js_statement);
             }
 
-            StatementKind::Return(_) => unimplemented!(),
+            // Evaluate the expressions of our error statement, like the value of an assignment we
+            // could not make, and then throw our error.
+            StatementKind::Error(error) => {
+                for expression in &error.expressions {
+                    let js_expression = self.compile_expression(js_statements, expression);
+                    if !js_expression.is_undefined_literal() {
+                        js_statements.push(js::Statement::expression(js_expression));
+                    }
+                }
+                js_statements.push(self.compile_error(&error.error));
+            }
         }
     }
 
@@ -172,43 +838,249 @@ impl Compiler {
         js_statements: &mut Vec<js::Statement>,
         expression: &Expression,
     ) -> js::Expression {
-        match &expression.kind {
+        let js_expression = match &expression.kind {
             // A Brite boolean is a JavaScript boolean...
             ExpressionKind::Constant(Constant::Boolean(value)) => {
                 js::Expression::boolean_literal(*value)
             }
 
+            // Brite `null` is JavaScript `null`...
+            ExpressionKind::Constant(Constant::Null) => js::Expression::null_literal(),
+
             // A Brite float is 64 bits which is the same as a JavaScript float which is also
             // 64 bits...
             ExpressionKind::Constant(Constant::Float(value)) => {
                 js::Expression::numeric_literal(*value)
             }
 
-            ExpressionKind::Constant(Constant::Integer(_, _)) => unimplemented!(),
+            // A Brite integer is a JavaScript number with the same value. JavaScript numbers may
+            // be written in binary and hexadecimal too, but we always write them in decimal. An
+            // integer too large for a JavaScript number becomes `Infinity`...
+            ExpressionKind::Constant(Constant::Integer(_, value)) => {
+                js::Expression::numeric_literal(value.to_f64().unwrap_or(f64::INFINITY))
+            }
 
-            // Resolve the JavaScript identifier we are using to represent the referenced Brite
-            // variable. If we can’t resolve a variable then we have an internal error! Unresolved
-            // variables should be handled by the checker!
-            ExpressionKind::Reference(identifier) => {
-                js::Expression::identifier(match self.scope_resolve(identifier) {
-                    Some(js_identifier) => js_identifier.clone(),
-                    None => unimplemented!(),
-                })
+            // A Brite string is a JavaScript string. Both are immutable...
+            ExpressionKind::Constant(Constant::String(value)) => {
+                js::Expression::string_literal(value.clone())
             }
 
-            ExpressionKind::This => unimplemented!(),
+            // Resolve the JavaScript identifier we are using to represent the referenced Brite
+            // variable. The checker replaces unresolved variables with an error node. Except for
+            // the variables bound by a pattern with an error which we never declare. We throw the
+            // error of that pattern before our reference is evaluated so we use `undefined`.
+            ExpressionKind::Reference(identifier) => match self.scope_resolve(identifier) {
+                Some(js_identifier) => js::Expression::identifier(js_identifier.clone()),
+                None => js::Expression::undefined_literal(),
+            },
+
+            // Brite’s `this` is the instance a method was called on, just like JavaScript’s `this`.
+            // We compile Brite functions inside of methods to arrow functions which don’t have
+            // their own `this`.
+            ExpressionKind::This => js::Expression::this(),
 
             // Compile a Brite function expression to a JavaScript arrow function.
             ExpressionKind::Function(function) => {
                 let (params, body) = self.compile_function(function);
-                js::Expression::arrow_function(params, body)
+                js::Expression::arrow_function(function.async_, params, body)
+            }
+
+            // A Brite function call is a JavaScript function call.
+            ExpressionKind::Call(call) => {
+                self.compile_call(js_statements, &call.callee, &call.arguments)
+            }
+
+            // A Brite template is a JavaScript template literal. The checker makes sure that we
+            // only interpolate values which JavaScript prints the same way we do.
+            ExpressionKind::Template(template) => {
+                let mut quasis = Vec::with_capacity(template.spans.len() + 1);
+                let mut expressions = Vec::with_capacity(template.spans.len());
+                quasis.push(template.head.clone());
+                for (expression, text) in &template.spans {
+                    expressions.push(self.compile_expression(js_statements, expression));
+                    quasis.push(text.clone());
+                }
+                js::Expression::template_literal(quasis, expressions)
             }
 
-            ExpressionKind::Call(_) => unimplemented!(),
-            ExpressionKind::Construct(_) => unimplemented!(),
-            ExpressionKind::Member(_) => unimplemented!(),
-            ExpressionKind::Prefix(_) => unimplemented!(),
-            ExpressionKind::Infix(_) => unimplemented!(),
+            // Construct an instance of a class by passing our fields to its constructor in an
+            // object. If we don’t have any fields then we don’t pass an object.
+            //
+            // We substitute the constructor of an inline class. Instead of calling it we create an
+            // object with our class’s prototype and a property for every field. So
+            // `Point { x: 1, y: 2 }` compiles to `{ __proto__: Point.prototype, x: 1, y: 2 }`.
+            //
+            // If we can’t construct our class then our checker replaced its name with an error
+            // node. We still evaluate our fields before throwing our error.
+            ExpressionKind::Construct(ConstructExpression { class, fields })
+                if matches!(class.kind, ExpressionKind::Error(_)) =>
+            {
+                for field in fields {
+                    let js_value = self.compile_expression(js_statements, &field.value);
+                    if !js_value.is_undefined_literal() {
+                        js_statements.push(js::Statement::expression(js_value));
+                    }
+                }
+                self.compile_expression(js_statements, class)
+            }
+            ExpressionKind::Construct(construct) => {
+                let inline = match &construct.class.kind {
+                    ExpressionKind::Reference(identifier) => {
+                        self.inline_classes.contains(identifier)
+                    }
+                    _ => false,
+                };
+                let js_class = self.compile_expression(js_statements, &construct.class);
+                let js_fields = construct.fields.iter().map(|field| {
+                    js::ObjectProperty::new(
+                        js::Identifier::new_unchecked(field.name.as_str().to_string()),
+                        self.compile_expression(js_statements, &field.value),
+                    )
+                });
+                if inline {
+                    let js_prototype = js::ObjectProperty::new(
+                        js::Identifier::new_unchecked("__proto__".to_string()),
+                        js::Expression::member(
+                            js_class,
+                            js::Identifier::new_unchecked("prototype".to_string()),
+                        ),
+                    );
+                    js::Expression::object(iter::once(js_prototype).chain(js_fields).collect())
+                } else {
+                    let mut js_arguments = Vec::with_capacity(1);
+                    if !construct.fields.is_empty() {
+                        js_arguments.push(js::Expression::object(js_fields.collect()));
+                    }
+                    js::Expression::new(js_class, js_arguments)
+                }
+            }
+
+            // A Brite member is a JavaScript property with the same name. Calling a method compiles
+            // to a JavaScript call of our member so JavaScript calls it with our object as `this`.
+            ExpressionKind::Member(member) => js::Expression::member(
+                self.compile_expression(js_statements, &member.object),
+                js::Identifier::new_unchecked(member.property.as_str().to_string()),
+            ),
+
+            // A Brite list is a JavaScript array. We never mutate the array...
+            ExpressionKind::List(elements) => js::Expression::array(
+                elements
+                    .iter()
+                    .map(|element| self.compile_expression(js_statements, element))
+                    .collect(),
+            ),
+
+            // Index a JavaScript array with a computed member expression.
+            ExpressionKind::Index(index) => js::Expression::computed_member(
+                self.compile_expression(js_statements, &index.object),
+                self.compile_expression(js_statements, &index.index),
+            ),
+
+            ExpressionKind::Prefix(prefix) => match &prefix.operator {
+                PrefixOperator::Not => js::Expression::unary(
+                    js::UnaryOperator::Not,
+                    self.compile_expression(js_statements, &prefix.operand),
+                ),
+
+                // Negating the smallest 32-bit integer overflows so like integer arithmetic we
+                // wrap the result around with `| 0`.
+                PrefixOperator::Negative => {
+                    let js_expression = js::Expression::unary(
+                        js::UnaryOperator::Negative,
+                        self.compile_expression(js_statements, &prefix.operand),
+                    );
+                    if prefix.integer {
+                        js::Expression::binary(
+                            js::BinaryOperator::BitwiseOr,
+                            js_expression,
+                            js::Expression::numeric_literal(0.0),
+                        )
+                    } else {
+                        js_expression
+                    }
+                }
+
+                // The checker makes sure our operand is already a number so `+` does nothing.
+                PrefixOperator::Positive => self.compile_expression(js_statements, &prefix.operand),
+
+                // JavaScript’s `~` converts its operand to a 32-bit integer and returns a 32-bit
+                // integer which is exactly what we want for a Brite integer.
+                PrefixOperator::BitwiseNot => js::Expression::unary(
+                    js::UnaryOperator::BitwiseNot,
+                    self.compile_expression(js_statements, &prefix.operand),
+                ),
+            },
+
+            // A Brite await is a JavaScript await. The checker makes sure that we only await
+            // inside of an async function.
+            ExpressionKind::Await(operand) => {
+                js::Expression::await_(self.compile_expression(js_statements, operand))
+            }
+
+            // Relational operators compile to the same JavaScript operators. The checker makes
+            // sure that we only compare numbers.
+            ExpressionKind::Infix(infix) => match &infix.operator {
+                InfixOperator::LessThan
+                | InfixOperator::LessThanOrEqual
+                | InfixOperator::GreaterThan
+                | InfixOperator::GreaterThanOrEqual => js::Expression::binary(
+                    compile_relational_operator(&infix.operator),
+                    self.compile_expression(js_statements, &infix.left),
+                    self.compile_expression(js_statements, &infix.right),
+                ),
+
+                // Arithmetic operators compile to the same JavaScript operators. Except that
+                // arithmetic on integers must wrap around like a 32-bit integer.
+                InfixOperator::Add
+                | InfixOperator::Subtract
+                | InfixOperator::Multiply
+                | InfixOperator::Divide
+                | InfixOperator::Remainder
+                | InfixOperator::Exponent => compile_arithmetic(
+                    &infix.operator,
+                    infix.integer,
+                    self.compile_expression(js_statements, &infix.left),
+                    self.compile_expression(js_statements, &infix.right),
+                ),
+
+                // Brite equality is JavaScript strict equality. The checker makes sure both our
+                // operands have the same type so we never need JavaScript’s coercions.
+                InfixOperator::Equals | InfixOperator::NotEquals => js::Expression::binary(
+                    match &infix.operator {
+                        InfixOperator::Equals => js::BinaryOperator::Equals,
+                        _ => js::BinaryOperator::NotEquals,
+                    },
+                    self.compile_expression(js_statements, &infix.left),
+                    self.compile_expression(js_statements, &infix.right),
+                ),
+
+                // JavaScript bitwise operators convert their operands to 32-bit integers and
+                // return a 32-bit integer. Except for `>>>` which returns an unsigned 32-bit
+                // integer. So we convert the result of `>>>` back to a signed 32-bit integer with
+                // `| 0`. The bits stay the same.
+                InfixOperator::BitwiseAnd
+                | InfixOperator::BitwiseOr
+                | InfixOperator::BitwiseXor
+                | InfixOperator::LeftShift
+                | InfixOperator::RightShift => js::Expression::binary(
+                    compile_bitwise_operator(&infix.operator),
+                    self.compile_expression(js_statements, &infix.left),
+                    self.compile_expression(js_statements, &infix.right),
+                ),
+                InfixOperator::UnsignedRightShift => js::Expression::binary(
+                    js::BinaryOperator::BitwiseOr,
+                    js::Expression::binary(
+                        js::BinaryOperator::UnsignedRightShift,
+                        self.compile_expression(js_statements, &infix.left),
+                        self.compile_expression(js_statements, &infix.right),
+                    ),
+                    js::Expression::numeric_literal(0.0),
+                ),
+            },
+
+            ExpressionKind::Comparison(comparison) => {
+                self.compile_comparison(js_statements, comparison)
+            }
 
             // Compile both operands of a logical expression and create a JavaScript
             // logical expression.
@@ -226,28 +1098,721 @@ impl Compiler {
                 self.compile_expression(js_statements, &logical.right),
             ),
 
-            ExpressionKind::Conditional(_) => unimplemented!(),
+            ExpressionKind::Conditional(conditional) => {
+                let js_test = self.compile_expression(js_statements, &conditional.test);
+                self.compile_conditional(js_statements, js_test, conditional, true)
+            }
+
+            ExpressionKind::Case(case) => self.compile_case(js_statements, case),
+
+            ExpressionKind::While(while_) => {
+                self.compile_while(js_statements, while_);
+                js::Expression::undefined_literal()
+            }
+
+            ExpressionKind::Loop(loop_) => self.compile_loop(js_statements, loop_),
 
             // Add a level of Brite nesting and compile our block...
             ExpressionKind::Block(block) => self
                 .scope_nest(|compiler| compiler.compile_block_without_nest(js_statements, block)),
 
-            ExpressionKind::Wrapped(wrapped) => {
-                self.compile_expression(js_statements, &wrapped.expression)
+            // Throw the error our checker replaced our expression with. We never evaluate the
+            // expression it replaced.
+            ExpressionKind::Error(error) => self.compile_error_expression(&error.error),
+        };
+        // Map our JavaScript expression back to our Brite expression in source maps.
+        js_expression.with_range(expression.range)
+    }
+
+    /// Compiles a conditional expression to a JavaScript `if` statement. We have already compiled
+    /// its test. Like case expressions we assign the value of the branch we take to a variable
+    /// unless we don’t need our value.
+    fn compile_conditional(
+        &mut self,
+        js_statements: &mut Vec<js::Statement>,
+        js_test: js::Expression,
+        conditional: &ConditionalExpression,
+        value: bool,
+    ) -> js::Expression {
+        // Compile every branch with its own level of Brite nesting. An alternate conditional
+        // becomes an `else if` unless its test needs statements. Then it goes in an `else` block
+        // with its own `if` statement.
+        let mut branches = Vec::new();
+        let mut js_test = js_test;
+        let mut conditional = conditional;
+        loop {
+            let (branch_statements, js_body) = self.scope_nest(|compiler| {
+                let mut branch_statements = Vec::new();
+                let js_body = compiler
+                    .compile_block_without_nest(&mut branch_statements, &conditional.consequent);
+                (branch_statements, js_body)
+            });
+            branches.push((Some(js_test), branch_statements, js_body));
+            let alternate = match &conditional.alternate {
+                Some(alternate) => alternate,
+                None => break,
+            };
+            let mut branch_statements = Vec::new();
+            let js_body = match &alternate.kind {
+                ExpressionKind::Conditional(alternate) => {
+                    let js_alternate_test =
+                        self.compile_expression(&mut branch_statements, &alternate.test);
+                    if branch_statements.is_empty() {
+                        js_test = js_alternate_test;
+                        conditional = alternate;
+                        continue;
+                    }
+                    self.scope_nest(|compiler| {
+                        compiler.compile_conditional(
+                            &mut branch_statements,
+                            js_alternate_test,
+                            alternate,
+                            value,
+                        )
+                    })
+                }
+                _ => self.scope_nest(|compiler| {
+                    compiler.compile_expression(&mut branch_statements, alternate)
+                }),
+            };
+            branches.push((None, branch_statements, js_body));
+            break;
+        }
+
+        // Build our `if` statement starting from the last branch. Every branch with a value
+        // assigns it to our result variable. If no branch has a value then we don’t need one.
+        let result = if !value
+            || branches
+                .iter()
+                .all(|(_, _, js_body)| js_body.is_undefined_literal())
+        {
+            None
+        } else {
+            Some(self.scope_declare_js("result"))
+        };
+        let mut js_statement = None;
+        for (js_test, mut branch_statements, js_body) in branches.into_iter().rev() {
+            if !js_body.is_undefined_literal() {
+                branch_statements.push(js::Statement::expression(match &result {
+                    Some(result) => {
+                        js::Expression::assignment(js::Pattern::identifier(result.clone()), js_body)
+                    }
+                    None => js_body,
+                }));
+            }
+            js_statement = match js_test {
+                None if branch_statements.is_empty() => None,
+                None => Some(js::Statement::block(js::BlockStatement::new(
+                    branch_statements,
+                ))),
+                Some(js_test) => Some(js::Statement::if_(
+                    js_test,
+                    js::BlockStatement::new(branch_statements),
+                    js_statement,
+                )),
+            };
+        }
+        if let Some(result) = &result {
+            js_statements.push(js::Statement::variable_declaration_without_init(
+                js::VariableDeclarationKind::Let,
+                js::Pattern::identifier(result.clone()),
+            ));
+        }
+        js_statements.extend(js_statement);
+        match result {
+            Some(result) => js::Expression::identifier(result),
+            None => js::Expression::undefined_literal(),
+        }
+    }
+
+    /// Compiles a case expression to a JavaScript `if` statement which tests the pattern of each
+    /// arm in order. Class patterns are tested with `instanceof`. Since JavaScript `if` statements
+    /// don’t return a value we assign the value of the arm we take to a variable.
+    ///
+    /// The checker makes sure that our case expression is exhaustive, so if none of our patterns
+    /// match then we have Undefined Behavior.
+    fn compile_case(
+        &mut self,
+        js_statements: &mut Vec<js::Statement>,
+        case: &CaseExpression,
+    ) -> js::Expression {
+        // Our test expression might be referenced many times so unless it is already a variable we
+        // assign it to one. That way we only evaluate our test expression once.
+        let variable = match &case.test.kind {
+            ExpressionKind::Reference(identifier) => self.scope_resolve(identifier).cloned(),
+            _ => None,
+        };
+        let test = match variable {
+            Some(js_identifier) => js_identifier,
+            None => {
+                let js_expression = self.compile_expression(js_statements, &case.test);
+                let js_identifier = self.scope_declare_js("test");
+                js_statements.push(js::Statement::variable_declaration(
+                    js::VariableDeclarationKind::Const,
+                    js::Pattern::identifier(js_identifier.clone()),
+                    js_expression,
+                ));
+                js_identifier
+            }
+        };
+
+        // Compile every arm with its own level of Brite nesting. If an arm matches everything
+        // then we don’t bother compiling the arms after it since they will never execute.
+        let mut arms = Vec::with_capacity(case.arms.len());
+        for arm in &case.arms {
+            let (js_test, arm_statements, js_body) = self.scope_nest(|compiler| {
+                let mut arm_statements = Vec::new();
+                let js_test =
+                    compiler.compile_case_pattern(&mut arm_statements, &test, &arm.pattern);
+                if let Some(error) = pattern_error(&arm.pattern) {
+                    arm_statements.push(compiler.compile_error(error));
+                }
+                let js_body = compiler.compile_expression(&mut arm_statements, &arm.body);
+                (js_test, arm_statements, js_body)
+            });
+            let matches_everything = js_test.is_none();
+            arms.push((js_test, arm_statements, js_body));
+            if matches_everything {
+                break;
+            }
+        }
+
+        // If our first arm matches everything then we don’t need an `if` statement. We add our
+        // arm’s statements to the current scope and use our arm’s body directly.
+        if let Some((None, _, _)) = arms.first() {
+            let (_, arm_statements, js_body) = arms.pop().unwrap();
+            js_statements.extend(arm_statements);
+            return js_body;
+        }
+
+        // A case expression with no arms will never return.
+        if arms.is_empty() {
+            return js::Expression::undefined_literal();
+        }
+
+        // Build our `if` statement starting from the last arm. Every arm assigns the value of its
+        // body to our result variable.
+        let result = self.scope_declare_js("result");
+        let mut js_statement = None;
+        for (js_test, mut arm_statements, js_body) in arms.into_iter().rev() {
+            arm_statements.push(js::Statement::expression(js::Expression::assignment(
+                js::Pattern::identifier(result.clone()),
+                js_body,
+            )));
+            let block = js::BlockStatement::new(arm_statements);
+            js_statement = Some(match js_test {
+                None => js::Statement::block(block),
+                Some(js_test) => js::Statement::if_(js_test, block, js_statement),
+            });
+        }
+        js_statements.push(js::Statement::variable_declaration_without_init(
+            js::VariableDeclarationKind::Let,
+            js::Pattern::identifier(result.clone()),
+        ));
+        js_statements.extend(js_statement);
+        js::Expression::identifier(result)
+    }
+
+    /// Compiles a function call. The checker passes a piped argument as our first argument.
+    ///
+    /// We compile our callee before our arguments which means a piped argument is evaluated after
+    /// the function it is piped into. The same as if the programmer had written the call.
+    ///
+    /// If the checker could not pass one of our arguments to a parameter then we don’t call our
+    /// callee. We still evaluate our callee and our arguments in order before throwing the error
+    /// of the first argument we could not pass.
+    fn compile_call(
+        &mut self,
+        js_statements: &mut Vec<js::Statement>,
+        callee: &Expression,
+        arguments: &[CallArgument],
+    ) -> js::Expression {
+        let error = arguments
+            .iter()
+            .find_map(|argument| argument.parameter.as_ref().err());
+        if let Some(error) = error {
+            for expression in iter::once(callee).chain(arguments.iter().map(|a| &a.value)) {
+                let js_expression = self.compile_expression(js_statements, expression);
+                // Constants and references have nothing to evaluate.
+                match &expression.kind {
+                    ExpressionKind::Constant(_) | ExpressionKind::Reference(_) => {}
+                    _ => js_statements.push(js::Statement::expression(js_expression)),
+                }
+            }
+            return self.compile_error_expression(error);
+        }
+
+        // If we are calling an inline function then try to substitute its body instead.
+        if let ExpressionKind::Reference(identifier) = &callee.kind {
+            let inline = self
+                .bindings
+                .get(identifier)
+                .and_then(|binding| binding.inline);
+            if let Some(index) = inline {
+                if !self.inlining.contains(&index) {
+                    if let Some(js_expression) =
+                        self.compile_inline_call(js_statements, index, arguments)
+                    {
+                        return js_expression;
+                    }
+                }
+            }
+        }
+
+        // We pass `undefined` for the parameters we skip over so that JavaScript uses their
+        // default values.
+        let js_callee = self.compile_expression(js_statements, callee);
+        let js_arguments = self
+            .compile_arguments(js_statements, arguments)
+            .into_iter()
+            .map(|js_argument| js_argument.unwrap_or_else(js::Expression::undefined_literal))
+            .collect();
+
+        js::Expression::call(js_callee, js_arguments)
+    }
+
+    /// Compiles the arguments of a call and returns them in the order of the parameters they are
+    /// passed to. A parameter we skip over with named arguments has no argument.
+    ///
+    /// JavaScript only has positional arguments so we pass every named argument in the position
+    /// of the parameter the checker resolved it to. If our arguments were not written in
+    /// parameter order then we bind every argument to a temporary variable first. That way our
+    /// arguments are still evaluated in the order they were written.
+    fn compile_arguments(
+        &mut self,
+        js_statements: &mut Vec<js::Statement>,
+        arguments: &[CallArgument],
+    ) -> Vec<Option<js::Expression>> {
+        let arguments: Vec<_> = arguments
+            .iter()
+            .filter_map(|argument| {
+                argument
+                    .parameter
+                    .as_ref()
+                    .ok()
+                    .map(|i| (*i, &argument.value))
+            })
+            .collect();
+        let temporary = !arguments.windows(2).all(|pair| pair[0].0 < pair[1].0);
+
+        let mut js_arguments = Vec::with_capacity(arguments.len());
+        for (i, value) in arguments {
+            let js_argument = self.compile_argument(js_statements, value, temporary);
+            if js_arguments.len() <= i {
+                js_arguments.resize_with(i + 1, || None);
             }
+            js_arguments[i] = Some(js_argument);
         }
+        js_arguments
     }
 
+    /// Compiles an argument. If we want a temporary then we bind our argument to a temporary
+    /// variable and return a reference to it. Constants have nothing to evaluate so we never bind
+    /// them to a temporary.
+    fn compile_argument(
+        &mut self,
+        js_statements: &mut Vec<js::Statement>,
+        argument: &Expression,
+        temporary: bool,
+    ) -> js::Expression {
+        let js_argument = self.compile_expression(js_statements, argument);
+        match &argument.kind {
+            ExpressionKind::Constant(_) => js_argument,
+            _ if !temporary => js_argument,
+            _ => {
+                let js_identifier = self.scope_declare_js("tmp");
+                js_statements.push(js::Statement::variable_declaration(
+                    js::VariableDeclarationKind::Const,
+                    js::Pattern::identifier(js_identifier.clone()),
+                    js_argument,
+                ));
+                js::Expression::identifier(js_identifier)
+            }
+        }
+    }
+
+    /// Compiles a call to an inline function by substituting the body of our function at our call
+    /// site. We bind each argument to its parameter with a JavaScript constant and then compile our
+    /// function’s body into the statements of our call site. A parameter without an argument is
+    /// bound to its default value.
+    ///
+    /// Our function’s body references declarations in the scope of our module. If our call site
+    /// shadows one of those declarations then we can’t substitute our function’s body. In that case
+    /// we undo everything and return `None` so that our caller compiles a normal call.
+    ///
+    /// NOTE: We evaluate default values in parameter order. So a default value might be evaluated
+    /// before an argument for a later parameter.
+    fn compile_inline_call(
+        &mut self,
+        js_statements: &mut Vec<js::Statement>,
+        index: usize,
+        arguments: &[CallArgument],
+    ) -> Option<js::Expression> {
+        let function = self.inline_functions[index].function;
+        let bindings_js = self.bindings_js.clone();
+        let inline_functions = self.inline_functions.clone();
+        let outline_references = self.outline_references.clone();
+        let mut js_inline_statements = Vec::new();
+
+        // Compile our arguments in the scope of our call site.
+        let mut js_arguments = self.compile_arguments(&mut js_inline_statements, arguments);
+        if js_arguments.len() < function.parameters.len() {
+            js_arguments.resize_with(function.parameters.len(), || None);
+        }
+
+        // Compile our function’s body in the scope of our module. Like a function, our body can’t
+        // see the Brite variables or the loops of our call site.
+        let nested = self.bindings.manual_take_nested();
+        let loops = mem::take(&mut self.loops);
+        let captured = mem::replace(&mut self.inline_captured, false);
+        let inline_body = mem::replace(&mut self.inline_body, true);
+        self.inlining.push(index);
+        let js_expression = self.scope_nest(|compiler| {
+            let parameters = function.parameters.iter().zip(&function.defaults);
+            for ((parameter, default), js_argument) in parameters.zip(js_arguments) {
+                let js_value = match (js_argument, default) {
+                    (Some(js_argument), _) => js_argument,
+                    (None, Some(default)) => {
+                        compiler.compile_expression(&mut js_inline_statements, default)
+                    }
+                    (None, None) => js::Expression::undefined_literal(),
+                };
+                let js_pattern = compiler.compile_pattern(parameter);
+                js_inline_statements.push(js::Statement::variable_declaration(
+                    js::VariableDeclarationKind::Const,
+                    js_pattern,
+                    js_value,
+                ));
+                if let Some(error) = pattern_error(parameter) {
+                    js_inline_statements.push(compiler.compile_error(error));
+                }
+            }
+            compiler.compile_block_without_nest(&mut js_inline_statements, &function.body)
+        });
+        self.inlining.pop();
+        self.inline_body = inline_body;
+        self.loops = loops;
+        self.bindings.manual_restore(nested);
+
+        if mem::replace(&mut self.inline_captured, captured) {
+            self.bindings_js = bindings_js;
+            self.inline_functions = inline_functions;
+            self.outline_references = outline_references;
+            return None;
+        }
+        js_statements.extend(js_inline_statements);
+        Some(js_expression)
+    }
+
+    /// Compiles a comparison chain to JavaScript comparisons combined with `&&`. So `a < b < c`
+    /// compiles to `a < b && b < c`.
+    ///
+    /// Every operand in the middle of our chain is used by two comparisons but must only be
+    /// evaluated once. So unless a middle operand is already a variable we assign it to a
+    /// temporary variable in the first comparison which uses it and reference the temporary
+    /// variable in the second. That way we evaluate operands in order and the `&&` still skips
+    /// the rest of the chain once a comparison fails.
+    fn compile_comparison(
+        &mut self,
+        js_statements: &mut Vec<js::Statement>,
+        comparison: &ComparisonExpression,
+    ) -> js::Expression {
+        let mut js_left = Some(self.compile_expression(js_statements, &comparison.left));
+        let mut js_chain = None;
+        let last = comparison.comparisons.len() - 1;
+        for (i, Comparison { operator, right }) in comparison.comparisons.iter().enumerate() {
+            let js_right = self.compile_expression(js_statements, right);
+            let (js_right, js_next_left) = if i == last {
+                (js_right, None)
+            } else if let ExpressionKind::Reference(_) = &right.kind {
+                (
+                    js_right,
+                    Some(self.compile_expression(js_statements, right)),
+                )
+            } else {
+                let js_identifier = self.scope_declare_js("tmp");
+                js_statements.push(js::Statement::variable_declaration_without_init(
+                    js::VariableDeclarationKind::Let,
+                    js::Pattern::identifier(js_identifier.clone()),
+                ));
+                (
+                    js::Expression::assignment(
+                        js::Pattern::identifier(js_identifier.clone()),
+                        js_right,
+                    ),
+                    Some(js::Expression::identifier(js_identifier)),
+                )
+            };
+            let js_comparison = js::Expression::binary(
+                compile_relational_operator(operator),
+                js_left.take().unwrap(),
+                js_right,
+            );
+            js_chain = Some(match js_chain {
+                None => js_comparison,
+                Some(js_chain) => {
+                    js::Expression::logical(js::LogicalOperator::And, js_chain, js_comparison)
+                }
+            });
+            js_left = js_next_left;
+        }
+        js_chain.unwrap()
+    }
+
+    /// Compiles a while expression to a JavaScript while statement. If our test needs to add some
+    /// statements then we add them to the start of our loop body and break when our test is false.
+    fn compile_while(&mut self, js_statements: &mut Vec<js::Statement>, while_: &WhileExpression) {
+        let mut test_statements = Vec::new();
+        let js_test = self.compile_expression(&mut test_statements, &while_.test);
+        let (body_statements, entry) = self.compile_loop_body(&while_.label, &while_.body);
+        let (js_test, body_statements) = if test_statements.is_empty() {
+            (js_test, body_statements)
+        } else {
+            test_statements.push(js::Statement::if_(
+                js::Expression::unary(js::UnaryOperator::Not, js_test),
+                js::BlockStatement::new(vec![js::Statement::break_(None)]),
+                None,
+            ));
+            test_statements.extend(body_statements);
+            (js::Expression::boolean_literal(true), test_statements)
+        };
+        js_statements.push(entry.label(js::Statement::while_(
+            js_test,
+            js::BlockStatement::new(body_statements),
+        )));
+    }
+
+    /// Compiles a loop expression to a JavaScript `while (true)` statement. If we break out of our
+    /// loop with a value then we assign it to a variable and return that variable.
+    fn compile_loop(
+        &mut self,
+        js_statements: &mut Vec<js::Statement>,
+        loop_: &LoopExpression,
+    ) -> js::Expression {
+        let (body_statements, entry) = self.compile_loop_body(&loop_.label, &loop_.body);
+        let result = entry.result.clone();
+        if let Some(result) = &result {
+            js_statements.push(js::Statement::variable_declaration_without_init(
+                js::VariableDeclarationKind::Let,
+                js::Pattern::identifier(result.clone()),
+            ));
+        }
+        js_statements.push(entry.label(js::Statement::while_(
+            js::Expression::boolean_literal(true),
+            js::BlockStatement::new(body_statements),
+        )));
+        match result {
+            Some(result) => js::Expression::identifier(result),
+            None => js::Expression::undefined_literal(),
+        }
+    }
+
+    /// Compiles the body of a loop with a new level of Brite nesting. Returns the statements of our
+    /// body along with our loop after we are done compiling it.
+    fn compile_loop_body(
+        &mut self,
+        label: &Option<Identifier>,
+        body: &Block,
+    ) -> (Vec<js::Statement>, Loop) {
+        // JavaScript does not allow a label to be reused inside the statement it labels so we
+        // dedupe our label against the labels of the loops we are inside of.
+        let js_label = label.as_ref().map(|label| {
+            let name = label.as_str();
+            let mut dedupe = 1;
+            let mut js_label = js::Identifier::new_unchecked(name.to_string());
+            while self
+                .loops
+                .iter()
+                .any(|entry| entry.js_label.as_ref() == Some(&js_label))
+            {
+                dedupe += 1;
+                js_label = js::Identifier::new_unchecked(format!("{}{}", name, dedupe));
+            }
+            js_label
+        });
+        self.loops.push(Loop {
+            label: label.clone(),
+            js_label,
+            js_label_used: false,
+            result: None,
+        });
+        let mut body_statements = Vec::new();
+        self.scope_nest(|compiler| {
+            let js_expression = compiler.compile_block_without_nest(&mut body_statements, body);
+            if !js_expression.is_undefined_literal() {
+                body_statements.push(js::Statement::expression(js_expression));
+            }
+        });
+        (body_statements, self.loops.pop().unwrap())
+    }
+
+    /// Finds the index of the loop a break or continue statement with this label references. If
+    /// there is no label then we reference the innermost loop.
+    fn find_loop(&self, label: Option<&Identifier>) -> Option<usize> {
+        match label {
+            None => self.loops.len().checked_sub(1),
+            Some(label) => self
+                .loops
+                .iter()
+                .rposition(|entry| entry.label.as_ref() == Some(label)),
+        }
+    }
+
+    /// Gets the JavaScript label we need to break or continue a loop. We don’t need a label for the
+    /// innermost loop. Otherwise we remember that the label was used so that we print it.
+    fn loop_js_label(&mut self, index: usize) -> Option<js::Identifier> {
+        if index == self.loops.len() - 1 {
+            None
+        } else {
+            self.loops[index].js_label_used = true;
+            self.loops[index].js_label.clone()
+        }
+    }
+
+    /// Compiles the pattern of an arm in a case expression. Adds statements which bind the names in
+    /// our pattern and returns the JavaScript expression which tests if our pattern matches. If our
+    /// pattern matches everything then we return `None`.
+    fn compile_case_pattern(
+        &mut self,
+        js_statements: &mut Vec<js::Statement>,
+        test: &js::Identifier,
+        pattern: &Pattern,
+    ) -> Option<js::Expression> {
+        let mut js_tests = Vec::new();
+        self.compile_case_subpattern(js_statements, &mut js_tests, test, &mut Vec::new(), pattern);
+        js_tests
+            .into_iter()
+            .fold(None, |js_test, right| match js_test {
+                None => Some(right),
+                Some(left) => Some(js::Expression::logical(
+                    js::LogicalOperator::And,
+                    left,
+                    right,
+                )),
+            })
+    }
+
+    /// Compiles a pattern nested somewhere in the pattern of an arm in a case expression. The path
+    /// is the list of properties we access on our test to get the value matched by this pattern.
+    /// Adds the JavaScript expressions which test if our pattern matches to `js_tests`.
+    fn compile_case_subpattern(
+        &mut self,
+        js_statements: &mut Vec<js::Statement>,
+        js_tests: &mut Vec<js::Expression>,
+        test: &js::Identifier,
+        path: &mut Vec<js::Identifier>,
+        pattern: &Pattern,
+    ) {
+        match &pattern.kind {
+            PatternKind::Binding(identifier) => {
+                js_statements.push(js::Statement::variable_declaration(
+                    js::VariableDeclarationKind::Const,
+                    js::Pattern::identifier(self.scope_declare(identifier)),
+                    case_value(test, path),
+                ));
+            }
+
+            PatternKind::Hole => {}
+
+            // The checker only allows `this` as the first parameter of a method.
+            PatternKind::This => unreachable!(),
+
+            // Test if our value is an instance of our class and then match all our fields against
+            // the values of the corresponding properties.
+            PatternKind::Class(class) => {
+                let js_class = match self.scope_resolve(&class.class) {
+                    Some(js_identifier) => js_identifier.clone(),
+                    None => unimplemented!(),
+                };
+                js_tests.push(js::Expression::binary(
+                    js::BinaryOperator::InstanceOf,
+                    case_value(test, path),
+                    js::Expression::identifier(js_class),
+                ));
+                for field in &class.fields {
+                    path.push(js::Identifier::new_unchecked(
+                        field.name.as_str().to_string(),
+                    ));
+                    self.compile_case_subpattern(js_statements, js_tests, test, path, &field.value);
+                    path.pop();
+                }
+            }
+
+            // A pattern with an error matches everything. The arm it belongs to throws our error
+            // when it is taken.
+            PatternKind::Error(_) => {}
+        }
+    }
+
+    /// Compiles a pattern which always matches, like the pattern of a binding statement, into a
+    /// JavaScript pattern. Class patterns become object patterns since the checker has already
+    /// made sure our value is an instance of the class.
     fn compile_pattern(&mut self, pattern: &Pattern) -> js::Pattern {
         match &pattern.kind {
             PatternKind::Binding(identifier) => {
                 js::Pattern::identifier(self.scope_declare(identifier))
             }
-            PatternKind::Hole => unimplemented!(),
-            PatternKind::This => unimplemented!(),
+
+            // JavaScript has no hole pattern so we bind our value to a variable which we never use.
+            PatternKind::Hole => js::Pattern::identifier(self.scope_declare_js("_")),
+
+            // The checker only allows `this` as the first parameter of a method which
+            // `Compiler::compile_function` leaves out.
+            PatternKind::This => unreachable!(),
+
+            // Fields with a hole pattern bind nothing so we leave them out of our object pattern.
+            PatternKind::Class(class) => js::Pattern::object(
+                class
+                    .fields
+                    .iter()
+                    .filter_map(|field| {
+                        let value = match &field.value.kind {
+                            PatternKind::Hole => return None,
+                            _ => self.compile_pattern(&field.value),
+                        };
+                        let key = js::Identifier::new_unchecked(field.name.as_str().to_string());
+                        Some(js::ObjectPatternProperty::new(key, value))
+                    })
+                    .collect(),
+            ),
+
+            // We throw the error of a pattern with an error where we bind it, so we bind our value
+            // to a variable which we never use.
+            PatternKind::Error(_) => js::Pattern::identifier(self.scope_declare_js("_")),
         }
     }
 
+    /// Compiles an error into a JavaScript statement which throws it. Our message includes where
+    /// our error is so that the programmer can find it from the JavaScript error.
+    fn compile_error(&self, error: &Diagnostic) -> js::Statement {
+        let message = format!(
+            "{} at {}:{}",
+            error.simple_message(),
+            self.file,
+            error.range.start().display(self.document)
+        );
+        js::Statement::throw(js::Expression::new(
+            js::Expression::identifier(js::Identifier::new_unchecked("Error".to_string())),
+            vec![js::Expression::string_literal(message)],
+        ))
+        .with_range(error.range)
+    }
+
+    /// Compiles an error into a JavaScript expression which throws it. JavaScript may only throw
+    /// in a statement so we throw in an arrow function which we call immediately.
+    fn compile_error_expression(&self, error: &Diagnostic) -> js::Expression {
+        js::Expression::call(
+            js::Expression::arrow_function(
+                false,
+                Vec::new(),
+                js::ArrowFunctionBody::Block(js::BlockStatement::new(vec![
+                    self.compile_error(error)
+                ])),
+            ),
+            Vec::new(),
+        )
+    }
+
     /// Introduces a new level of nesting for Brite bindings.
     fn scope_nest<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
         self.bindings.manual_nest();
@@ -267,6 +1832,16 @@ impl Compiler {
     /// Declares a new Brite variable in our current scope and returns the JavaScript identifier we
     /// will use to reference that variable.
     fn scope_declare(&mut self, identifier: &Identifier) -> js::Identifier {
+        self.scope_declare_binding(identifier, None)
+    }
+
+    /// Declares a new Brite variable like [`Compiler::scope_declare`]. If our variable is an inline
+    /// function then we have the index of that function.
+    fn scope_declare_binding(
+        &mut self,
+        identifier: &Identifier,
+        inline: Option<usize>,
+    ) -> js::Identifier {
         // Get the number of bindings shallowly declared in this scope with the same name.
         let mut dedupe = match self.bindings.get_shallow(identifier) {
             None => 1,
@@ -297,7 +1872,13 @@ impl Compiler {
 
         // If a JavaScript identifier with this name already exists then try adding numbers
         // to the end until we find an identifier does not yet exist.
-        while self.bindings_js.contains_shallow(&js_identifier) {
+        //
+        // The variables of an inline function body we are substituting live in the JavaScript
+        // scope of our call site. They must not shadow any JavaScript variable our call site
+        // might reference so we avoid every JavaScript variable in scope.
+        while self.bindings_js.contains_shallow(&js_identifier)
+            || (!self.inlining.is_empty() && self.bindings_js.contains(&js_identifier))
+        {
             dedupe += 1;
             js_identifier =
                 js::Identifier::new_unchecked(format!("{}{}", identifier.as_str(), dedupe));
@@ -310,6 +1891,7 @@ impl Compiler {
             Binding {
                 dedupe,
                 identifier: js_identifier.clone(),
+                inline,
             },
         );
 
@@ -320,9 +1902,44 @@ impl Compiler {
         js_identifier
     }
 
+    /// Declares a new JavaScript variable which does not belong to any Brite variable. We make sure
+    /// our variable does not shadow any JavaScript variable in scope so that we don’t change what
+    /// the variables of our Brite program reference.
+    fn scope_declare_js(&mut self, name: &str) -> js::Identifier {
+        let mut dedupe = 1;
+        let mut js_identifier = js::Identifier::new_unchecked(name.to_string());
+        while self.bindings_js.contains(&js_identifier) {
+            dedupe += 1;
+            js_identifier = js::Identifier::new_unchecked(format!("{}{}", name, dedupe));
+        }
+        self.bindings_js.insert(js_identifier.clone(), ());
+        js_identifier
+    }
+
     /// Resolve the JavaScript identifier we use to reference a Brite identifier in the
     /// current scope.
-    fn scope_resolve(&self, identifier: &Identifier) -> Option<&js::Identifier> {
+    ///
+    /// If we reference an inline function without calling it then we need to keep the declaration
+    /// of our inline function. If we are substituting an inline function body and our JavaScript
+    /// identifier is shadowed at our call site then we can’t substitute the body.
+    fn scope_resolve(&mut self, identifier: &Identifier) -> Option<&js::Identifier> {
+        let binding = self.bindings.get(identifier)?;
+        if let Some(index) = binding.inline {
+            self.inline_functions[index].used = true;
+        }
+        if !self.inlining.is_empty()
+            && !self.bindings.contains_nested(identifier)
+            && self.bindings_js.contains_nested(&binding.identifier)
+        {
+            self.inline_captured = true;
+        }
+        if let Some(references) = &mut self.outline_references {
+            if !self.bindings.contains_nested(identifier)
+                && !references.contains(&binding.identifier)
+            {
+                references.push(binding.identifier.clone());
+            }
+        }
         self.bindings
             .get(identifier)
             .map(|binding| &binding.identifier)
@@ -332,4 +1949,170 @@ impl Compiler {
 struct Binding {
     dedupe: usize,
     identifier: js::Identifier,
+    /// If our binding is an inline function then this is an index into
+    /// `Compiler::inline_functions`.
+    inline: Option<usize>,
+}
+
+/// A function declared with `@inline`.
+#[derive(Clone, Copy)]
+struct InlineFunction<'a> {
+    /// The function we substitute at our call sites.
+    function: &'a Function,
+    /// Whether we referenced our function somewhere we could not substitute it. If not then we
+    /// leave out our function’s declaration.
+    used: bool,
+}
+
+/// Compiles a Brite relational operator to the JavaScript operator with the same meaning.
+fn compile_relational_operator(operator: &InfixOperator) -> js::BinaryOperator {
+    match operator {
+        InfixOperator::LessThan => js::BinaryOperator::LessThan,
+        InfixOperator::LessThanOrEqual => js::BinaryOperator::LessThanOrEqual,
+        InfixOperator::GreaterThan => js::BinaryOperator::GreaterThan,
+        InfixOperator::GreaterThanOrEqual => js::BinaryOperator::GreaterThanOrEqual,
+        _ => unreachable!(),
+    }
+}
+
+/// Compiles a Brite arithmetic operator to the JavaScript operator with the same meaning. If we
+/// have two integers then we convert our result back to a 32-bit integer with `| 0` so that it
+/// wraps around. Multiplying two large integers might lose precision before we convert our result
+/// so we use `Math.imul()` instead, which multiplies like a 32-bit integer.
+fn compile_arithmetic(
+    operator: &InfixOperator,
+    integer: bool,
+    js_left: js::Expression,
+    js_right: js::Expression,
+) -> js::Expression {
+    let operator = match operator {
+        InfixOperator::Add => js::BinaryOperator::Add,
+        InfixOperator::Subtract => js::BinaryOperator::Subtract,
+        InfixOperator::Multiply if integer => {
+            return js::Expression::call(
+                js::Expression::member(
+                    js::Expression::identifier(js::Identifier::new_unchecked("Math".to_string())),
+                    js::Identifier::new_unchecked("imul".to_string()),
+                ),
+                vec![js_left, js_right],
+            );
+        }
+        InfixOperator::Multiply => js::BinaryOperator::Multiply,
+        InfixOperator::Divide => js::BinaryOperator::Divide,
+        InfixOperator::Remainder => js::BinaryOperator::Remainder,
+        InfixOperator::Exponent => js::BinaryOperator::Exponent,
+        _ => unreachable!(),
+    };
+    let js_expression = js::Expression::binary(operator, js_left, js_right);
+    if integer {
+        js::Expression::binary(
+            js::BinaryOperator::BitwiseOr,
+            js_expression,
+            js::Expression::numeric_literal(0.0),
+        )
+    } else {
+        js_expression
+    }
+}
+
+/// Compiles a Brite bitwise operator to the JavaScript operator with the same meaning.
+fn compile_bitwise_operator(operator: &InfixOperator) -> js::BinaryOperator {
+    match operator {
+        InfixOperator::BitwiseAnd => js::BinaryOperator::BitwiseAnd,
+        InfixOperator::BitwiseOr => js::BinaryOperator::BitwiseOr,
+        InfixOperator::BitwiseXor => js::BinaryOperator::BitwiseXor,
+        InfixOperator::LeftShift => js::BinaryOperator::LeftShift,
+        InfixOperator::RightShift => js::BinaryOperator::RightShift,
+        _ => unreachable!(),
+    }
+}
+
+/// Exports a JavaScript declaration for a Brite declaration if our Brite declaration is exported.
+/// If our JavaScript declaration has the same name as our Brite declaration then we export it where
+/// it is declared. Otherwise we need to rename it with an export specifier.
+fn export_declaration(
+    js_exports: &mut Vec<js::ExportSpecifier>,
+    export: bool,
+    name: &Identifier,
+    id: js::Identifier,
+    js_declaration: js::Statement,
+) -> js::Statement {
+    if !export {
+        return js_declaration;
+    }
+    let exported = exported_identifier(name);
+    if id == exported {
+        js::Statement::export_declaration(js_declaration)
+    } else {
+        js_exports.push(js::ExportSpecifier::new(id, exported));
+        js_declaration
+    }
+}
+
+/// Gets the name a declaration is exported from our JavaScript module with. The same as its
+/// Brite name.
+fn exported_identifier(name: &Identifier) -> js::Identifier {
+    js::Identifier::new_unchecked(name.as_str().to_string())
+}
+
+/// Gets the name our module exports a declaration with only so that our chunks may import it. A
+/// Brite identifier may not contain `$` so our name never clashes with the name of a
+/// declaration exported by the programmer.
+fn chunk_identifier(id: &js::Identifier) -> js::Identifier {
+    js::Identifier::new_unchecked(format!("${}", id.as_str()))
+}
+
+/// Gets the block body of a JavaScript function declaration from the body of an arrow function.
+fn function_body(body: js::ArrowFunctionBody) -> js::BlockStatement {
+    match body {
+        js::ArrowFunctionBody::Block(block) => block,
+        js::ArrowFunctionBody::Expression(expression) => {
+            js::BlockStatement::new(vec![js::Statement::return_(*expression)])
+        }
+    }
+}
+
+/// Gets the value matched by a pattern in a case expression by accessing each property in our path
+/// on our test.
+fn case_value(test: &js::Identifier, path: &[js::Identifier]) -> js::Expression {
+    path.iter().fold(
+        js::Expression::identifier(test.clone()),
+        |object, property| js::Expression::member(object, property.clone()),
+    )
+}
+
+/// Finds the error of the first part of a pattern which has an error.
+fn pattern_error(pattern: &Pattern) -> Option<&DiagnosticRef> {
+    match &pattern.kind {
+        PatternKind::Binding(_) | PatternKind::Hole | PatternKind::This => None,
+        PatternKind::Class(class) => class
+            .fields
+            .iter()
+            .find_map(|field| pattern_error(&field.value)),
+        PatternKind::Error(error) => Some(error),
+    }
+}
+
+/// A loop we are currently compiling the body of.
+struct Loop {
+    /// The Brite label of our loop, if it has one.
+    label: Option<Identifier>,
+    /// The JavaScript label of our loop, if it has one.
+    js_label: Option<js::Identifier>,
+    /// Whether or not some break or continue statement uses our JavaScript label. If not then we
+    /// don’t print the label.
+    js_label_used: bool,
+    /// The variable we assign the values we break out of our loop with to. We create it when we
+    /// compile the first break with a value.
+    result: Option<js::Identifier>,
+}
+
+impl Loop {
+    /// Adds our JavaScript label, if we have one and it is used, to our compiled loop statement.
+    fn label(self, js_loop: js::Statement) -> js::Statement {
+        match self.js_label {
+            Some(js_label) if self.js_label_used => js::Statement::labeled(js_label, js_loop),
+            _ => js_loop,
+        }
+    }
 }
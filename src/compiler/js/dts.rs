@@ -0,0 +1,305 @@
+//! Writes a TypeScript declaration file (`.d.ts`) for a checked Brite module so that TypeScript
+//! code may use the JavaScript we compile with types. Write the declaration file next to the
+//! module’s JavaScript with a file name of `{name}.d.ts`.
+//!
+//! We declare everything with the same names our compiler gives it in JavaScript. So if a name is a
+//! JavaScript reserved word we declare it with an underscore at the end and rename it when we
+//! export it just like our compiler does.
+//!
+//! We import the classes our module imports from other modules so that our declarations may
+//! reference them. A type may still reference a class our module never imports. For example, the
+//! class of a type alias we import. TypeScript will not find that class.
+
+use super::js::RESERVED_WORDS;
+use crate::checker::avt::*;
+use crate::parser::Identifier;
+use std::io;
+
+/// Writes a TypeScript declaration for every exported function, every exported type alias, and
+/// every class in our module. Classes which are not exported are still declared since the types of
+/// our exported declarations may reference them.
+pub fn write_type_declarations<W: io::Write>(w: &mut W, module: &Module) -> io::Result<()> {
+    let mut first = true;
+    for declaration in &module.declarations {
+        if let Declaration::Import(import) = declaration {
+            if !import.classes.is_empty() {
+                write_import(w, import)?;
+                first = false;
+            }
+        }
+    }
+
+    let mut exports = Vec::new();
+    let mut exported = false;
+    for declaration in &module.declarations {
+        let (export, name) = match declaration {
+            Declaration::Function(function) if function.export => (true, &function.name),
+            Declaration::Class(class) => (class.export, &class.name),
+            Declaration::TypeAlias(type_alias) if type_alias.export => (true, &type_alias.name),
+            _ => continue,
+        };
+        if !first {
+            writeln!(w)?;
+        }
+        first = false;
+
+        // If our declaration has the same name in JavaScript then export it where it is declared.
+        // Otherwise we need to rename it with an export specifier.
+        let declared = declared_name(name);
+        if export && declared == name.as_str() {
+            write!(w, "export ")?;
+            exported = true;
+        } else if export {
+            exports.push((declared, name));
+        }
+        match declaration {
+            Declaration::Function(function) => {
+                write!(w, "declare function {}", declared_name(&function.name))?;
+                write_function_signature(w, &function.type_, ": ")?;
+                writeln!(w, ";")?;
+            }
+            Declaration::Class(class) => write_class(w, class)?,
+            Declaration::TypeAlias(type_alias) => {
+                write!(w, "type {} = ", declared_name(&type_alias.name))?;
+                write_type(w, &type_alias.type_)?;
+                writeln!(w, ";")?;
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    // If we don’t write `export` anywhere then TypeScript would think that our declarations are
+    // global. An empty export makes sure our declaration file is a module.
+    if !exports.is_empty() || !exported {
+        if !first {
+            writeln!(w)?;
+        }
+        write!(w, "export {{")?;
+        for (i, (declared, name)) in exports.iter().enumerate() {
+            if i != 0 {
+                write!(w, ",")?;
+            }
+            write!(w, " {} as {}", declared, name.as_str())?;
+        }
+        if !exports.is_empty() {
+            write!(w, " ")?;
+        }
+        writeln!(w, "}};")?;
+    }
+    Ok(())
+}
+
+/// Writes an import of the classes our module imports from another module. TypeScript finds the
+/// declaration file of the module from the path of its JavaScript.
+fn write_import<W: io::Write>(w: &mut W, import: &ImportDeclaration) -> io::Result<()> {
+    write!(w, "import {{")?;
+    for (i, class) in import.classes.iter().enumerate() {
+        if i != 0 {
+            write!(w, ",")?;
+        }
+        let declared = declared_name(class);
+        if declared == class.as_str() {
+            write!(w, " {}", declared)?;
+        } else {
+            write!(w, " {} as {}", class.as_str(), declared)?;
+        }
+    }
+    writeln!(w, " }} from \"{}.js\";", import.path)
+}
+
+/// Writes a class declaration. Our constructor accepts an object with the value of every field,
+/// including the fields of the classes we extend, just like the constructor our compiler writes. A
+/// base class is abstract and its base methods are abstract.
+fn write_class<W: io::Write>(w: &mut W, class: &ClassDeclaration) -> io::Result<()> {
+    write!(w, "declare ")?;
+    if class.base {
+        write!(w, "abstract ")?;
+    }
+    write!(w, "class {}", declared_name(&class.name))?;
+    write_type_parameters(w, &class.type_parameters)?;
+    if let Some(extends) = &class.extends {
+        write!(w, " extends {}", declared_name(extends))?;
+    }
+    if class.fields.is_empty() && class.methods.is_empty() {
+        return writeln!(w, " {{}}");
+    }
+    writeln!(w, " {{")?;
+    for field in &class.fields {
+        write!(w, "  {}: ", field.name.as_str())?;
+        write_type(w, &field.type_)?;
+        writeln!(w, ";")?;
+    }
+    if !class.fields.is_empty() {
+        write!(w, "  constructor(fields: {{")?;
+        let fields = class.inherited_fields.iter().chain(&class.fields);
+        for (i, field) in fields.enumerate() {
+            if i != 0 {
+                write!(w, ";")?;
+            }
+            write!(w, " {}: ", field.name.as_str())?;
+            write_type(w, &field.type_)?;
+        }
+        writeln!(w, " }});")?;
+    }
+    for method in &class.methods {
+        write!(w, "  ")?;
+        if method.static_ {
+            write!(w, "static ")?;
+        }
+        if method.base {
+            write!(w, "abstract ")?;
+        }
+        write!(w, "{}", method.name.as_str())?;
+        match &method.type_ {
+            Some(Type::Ok {
+                kind: TypeKind::Function(function),
+                ..
+            }) => {
+                write_function_signature(w, function, ": ")?;
+                writeln!(w, ";")?;
+            }
+            // We only know the type of a method if its signature is fully annotated and has no
+            // errors. Otherwise we accept and return anything and say so in a comment.
+            _ => writeln!(
+                w,
+                "(...args: any[]): any; // The type of this method is unknown."
+            )?,
+        }
+    }
+    writeln!(w, "}}")
+}
+
+/// Writes the type parameters, parameters, and return type of a function. The separator comes
+/// between our parameters and our return type. It is `: ` in a declaration and ` => ` in a type.
+fn write_function_signature<W: io::Write>(
+    w: &mut W,
+    function: &FunctionType,
+    separator: &str,
+) -> io::Result<()> {
+    write_type_parameters(w, &function.type_parameters)?;
+    write!(w, "(")?;
+    for (i, parameter) in function.parameters.iter().enumerate() {
+        if i != 0 {
+            write!(w, ", ")?;
+        }
+        match &function.parameter_names[i] {
+            Some(name) => write!(w, "{}", declared_name(name))?,
+            None => write!(w, "arg{}", i)?,
+        }
+        // TypeScript does not allow a required parameter after an optional parameter so a
+        // parameter with a default is only optional if all the parameters after it are as well.
+        // Otherwise the caller may pass `undefined` to get the default.
+        let default = function.parameter_defaults[i];
+        if default
+            && function.parameter_defaults[i..]
+                .iter()
+                .all(|default| *default)
+        {
+            write!(w, "?: ")?;
+            write_type(w, parameter)?;
+        } else if default {
+            write!(w, ": ")?;
+            write_union_member(w, parameter)?;
+            write!(w, " | undefined")?;
+        } else {
+            write!(w, ": ")?;
+            write_type(w, parameter)?;
+        }
+    }
+    write!(w, "){}", separator)?;
+    write_type(w, &function.return_)
+}
+
+/// Writes the type parameters of a generic function or class. Writes nothing if there are
+/// no type parameters.
+fn write_type_parameters<W: io::Write>(
+    w: &mut W,
+    type_parameters: &[TypeParameter],
+) -> io::Result<()> {
+    if type_parameters.is_empty() {
+        return Ok(());
+    }
+    write!(w, "<")?;
+    for (i, type_parameter) in type_parameters.iter().enumerate() {
+        if i != 0 {
+            write!(w, ", ")?;
+        }
+        write!(w, "{}", declared_name(&type_parameter.name))?;
+    }
+    write!(w, ">")
+}
+
+/// Writes the TypeScript type for a Brite type. All of our number types are JavaScript numbers.
+/// An error type may be anything at runtime so it is `any`.
+fn write_type<W: io::Write>(w: &mut W, type_: &Type) -> io::Result<()> {
+    let kind = match type_ {
+        Type::Ok { kind, .. } => kind,
+        Type::Error { .. } => return write!(w, "any"),
+    };
+    match kind {
+        TypeKind::Never => write!(w, "never"),
+        TypeKind::Void => write!(w, "void"),
+        TypeKind::Boolean => write!(w, "boolean"),
+        TypeKind::Number | TypeKind::Integer | TypeKind::Float => write!(w, "number"),
+        TypeKind::String => write!(w, "string"),
+        TypeKind::Null => write!(w, "null"),
+        TypeKind::Optional(type_) => {
+            write_union_member(w, type_)?;
+            write!(w, " | null")
+        }
+        TypeKind::List(element) => {
+            write!(w, "ReadonlyArray<")?;
+            write_type(w, element)?;
+            write!(w, ">")
+        }
+        TypeKind::Promise(value) => {
+            write!(w, "Promise<")?;
+            write_type(w, value)?;
+            write!(w, ">")
+        }
+        TypeKind::Function(function) => write_function_signature(w, function, " => "),
+        TypeKind::Parameter(type_parameter) => {
+            write!(w, "{}", declared_name(&type_parameter.name))
+        }
+        TypeKind::Class(class) => {
+            write!(w, "{}", declared_name(&class.name))?;
+            if !class.arguments.is_empty() {
+                write!(w, "<")?;
+                for (i, argument) in class.arguments.iter().enumerate() {
+                    if i != 0 {
+                        write!(w, ", ")?;
+                    }
+                    write_type(w, argument)?;
+                }
+                write!(w, ">")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Writes a type which is a member of a union. A function type needs parentheses or else the rest
+/// of our union would be its return type.
+fn write_union_member<W: io::Write>(w: &mut W, type_: &Type) -> io::Result<()> {
+    if let Type::Ok {
+        kind: TypeKind::Function(_),
+        ..
+    } = type_
+    {
+        write!(w, "(")?;
+        write_type(w, type_)?;
+        write!(w, ")")
+    } else {
+        write_type(w, type_)
+    }
+}
+
+/// Gets the name our compiler declares a Brite identifier with in JavaScript. If our identifier is
+/// a reserved word then we add an underscore to the end.
+fn declared_name(identifier: &Identifier) -> String {
+    if RESERVED_WORDS.contains(identifier.as_str()) {
+        format!("{}_", identifier.as_str())
+    } else {
+        identifier.as_str().to_string()
+    }
+}
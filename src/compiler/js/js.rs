@@ -2,8 +2,10 @@
 //!
 //! https://github.com/babel/babel/blob/master/packages/babel-parser/ast/spec.md
 
+use super::source_map::{Mapping, SourceMap, SourceMapOptions};
+use crate::parser::{Document, Range};
 use std::collections::HashSet;
-use std::io;
+use std::io::{self, Write};
 
 /// A valid JavaScript identifier.
 #[derive(Clone, Eq, Hash, PartialEq)]
@@ -19,6 +21,10 @@ impl Identifier {
     pub fn new_unchecked(string: String) -> Identifier {
         Identifier(string)
     }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 }
 
 /// A complete JavaScript program source tree.
@@ -33,13 +39,23 @@ impl Program {
     }
 }
 
-pub struct Statement(StatementKind);
+pub struct Statement(StatementKind, Option<Range>);
 
 enum StatementKind {
     Expression(Expression),
-    Return(Expression),
+    Block(BlockStatement),
+    Return(Option<Expression>),
+    Throw(Expression),
+    If(Box<IfStatement>),
+    Labeled(Box<LabeledStatement>),
+    Break(Option<Identifier>),
+    Continue(Option<Identifier>),
+    While(Box<WhileStatement>),
     FunctionDeclaration(FunctionDeclaration),
+    ClassDeclaration(Box<ClassDeclaration>),
     VariableDeclaration(VariableDeclaration),
+    ImportDeclaration(ImportDeclaration),
+    ExportNamedDeclaration(Box<ExportNamedDeclaration>),
 }
 
 pub struct BlockStatement {
@@ -52,16 +68,52 @@ impl BlockStatement {
     }
 }
 
+struct IfStatement {
+    test: Expression,
+    consequent: BlockStatement,
+    alternate: Option<Statement>,
+}
+
+struct LabeledStatement {
+    label: Identifier,
+    body: Statement,
+}
+
+struct WhileStatement {
+    test: Expression,
+    body: BlockStatement,
+}
+
 struct FunctionDeclaration {
+    async_: bool,
+    id: Identifier,
+    params: Vec<Pattern>,
+    body: BlockStatement,
+}
+
+struct ClassDeclaration {
     id: Identifier,
+    super_class: Option<Identifier>,
+    body: Vec<MethodDefinition>,
+}
+
+pub struct MethodDefinition {
+    kind: MethodDefinitionKind,
+    static_: bool,
+    async_: bool,
     params: Vec<Pattern>,
     body: BlockStatement,
 }
 
+enum MethodDefinitionKind {
+    Constructor,
+    Method(Identifier),
+}
+
 struct VariableDeclaration {
     kind: VariableDeclarationKind,
     id: Pattern,
-    init: Expression,
+    init: Option<Expression>,
 }
 
 pub enum VariableDeclarationKind {
@@ -70,18 +122,83 @@ pub enum VariableDeclarationKind {
     Const,
 }
 
-pub struct Expression(ExpressionKind);
+struct ImportDeclaration {
+    specifiers: Vec<ImportSpecifier>,
+    source: String,
+}
+
+pub struct ImportSpecifier {
+    imported: Identifier,
+    local: Identifier,
+}
+
+impl ImportSpecifier {
+    pub fn new(imported: Identifier, local: Identifier) -> Self {
+        ImportSpecifier { imported, local }
+    }
+}
+
+struct ExportNamedDeclaration {
+    declaration: Option<Statement>,
+    specifiers: Vec<ExportSpecifier>,
+}
+
+pub struct ExportSpecifier {
+    local: Identifier,
+    exported: Identifier,
+}
+
+impl ExportSpecifier {
+    pub fn new(local: Identifier, exported: Identifier) -> Self {
+        ExportSpecifier { local, exported }
+    }
+}
+
+pub struct Expression(ExpressionKind, Option<Range>);
 
 enum ExpressionKind {
     Identifier(Identifier),
+    This,
+    Super,
     UndefinedLiteral, // NOTE: Technically, `undefined` is not a keyword. We treat it like one anyway.
+    NullLiteral,
     BooleanLiteral(bool),
     NumericLiteral(f64),
+    StringLiteral(String),
+    TemplateLiteral(TemplateLiteral),
+    Array(Vec<Expression>),
+    Object(Vec<ObjectProperty>),
     ArrowFunction(ArrowFunctionExpression),
+    Call(Box<CallExpression>),
+    New(Box<CallExpression>),
+    Import(String), // NOTE: A dynamic `import()` of the module at some path.
+    Await(Box<Expression>),
+    Member(Box<MemberExpression>),
+    ComputedMember(Box<ComputedMemberExpression>),
+    Unary(Box<UnaryExpression>),
+    Binary(Box<BinaryExpression>),
     Logical(Box<LogicalExpression>),
+    Assignment(Box<AssignmentExpression>),
+}
+
+struct TemplateLiteral {
+    quasis: Vec<String>,
+    expressions: Vec<Expression>,
+}
+
+pub struct ObjectProperty {
+    key: Identifier,
+    value: Expression,
+}
+
+impl ObjectProperty {
+    pub fn new(key: Identifier, value: Expression) -> Self {
+        ObjectProperty { key, value }
+    }
 }
 
 struct ArrowFunctionExpression {
+    async_: bool,
     params: Vec<Pattern>,
     body: ArrowFunctionBody,
 }
@@ -91,6 +208,61 @@ pub enum ArrowFunctionBody {
     Expression(Box<Expression>), // NOTE: We need to wrap in a `Box` to prevent an infinite type.
 }
 
+struct CallExpression {
+    callee: Expression,
+    arguments: Vec<Expression>,
+}
+
+struct MemberExpression {
+    object: Expression,
+    property: Identifier,
+}
+
+struct ComputedMemberExpression {
+    object: Expression,
+    property: Expression,
+}
+
+struct UnaryExpression {
+    operator: UnaryOperator,
+    argument: Expression,
+}
+
+pub enum UnaryOperator {
+    Not,
+    Negative,
+    Positive,
+    BitwiseNot,
+}
+
+struct BinaryExpression {
+    operator: BinaryOperator,
+    left: Expression,
+    right: Expression,
+}
+
+pub enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Exponent,
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    Equals,
+    NotEquals,
+    InstanceOf,
+    LeftShift,
+    RightShift,
+    UnsignedRightShift,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+}
+
 struct LogicalExpression {
     operator: LogicalOperator,
     left: Expression,
@@ -102,76 +274,327 @@ pub enum LogicalOperator {
     And,
 }
 
+struct AssignmentExpression {
+    operator: Option<AssignmentOperator>,
+    left: Pattern,
+    right: Expression,
+}
+
+pub enum AssignmentOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+}
+
 pub struct Pattern(PatternKind);
 
 enum PatternKind {
     Identifier(Identifier),
+    Member(Box<MemberExpression>),
+    Object(Vec<ObjectPatternProperty>),
+    Assignment(Box<AssignmentPattern>),
+}
 
-    // TODO: Actually have another pattern kind...
-    #[allow(dead_code)]
-    Unimplemented,
+pub struct ObjectPatternProperty {
+    key: Identifier,
+    value: Pattern,
+}
+
+struct AssignmentPattern {
+    left: Pattern,
+    right: Expression,
 }
 
 impl Statement {
+    fn from_kind(kind: StatementKind) -> Self {
+        Statement(kind, None)
+    }
+
+    /// Sets the range of the Brite code our statement was compiled from. When we write a source
+    /// map our statement maps to the start of this range.
+    pub fn with_range(mut self, range: Range) -> Self {
+        self.1 = Some(range);
+        self
+    }
+
+    /// Sets the range of our statement like [`Statement::with_range`] unless we already have
+    /// a range.
+    pub fn default_range(&mut self, range: Range) {
+        if self.1.is_none() {
+            self.1 = Some(range);
+        }
+    }
+
     pub fn expression(expression: Expression) -> Self {
-        Statement(StatementKind::Expression(expression))
+        Statement::from_kind(StatementKind::Expression(expression))
+    }
+
+    pub fn block(block: BlockStatement) -> Self {
+        Statement::from_kind(StatementKind::Block(block))
     }
 
     pub fn return_(argument: Expression) -> Self {
-        Statement(StatementKind::Return(argument))
+        Statement::from_kind(StatementKind::Return(Some(argument)))
+    }
+
+    pub fn return_without_argument() -> Self {
+        Statement::from_kind(StatementKind::Return(None))
+    }
+
+    pub fn throw(argument: Expression) -> Self {
+        Statement::from_kind(StatementKind::Throw(argument))
+    }
+
+    pub fn if_(test: Expression, consequent: BlockStatement, alternate: Option<Statement>) -> Self {
+        Statement::from_kind(StatementKind::If(Box::new(IfStatement {
+            test,
+            consequent,
+            alternate,
+        })))
+    }
+
+    pub fn labeled(label: Identifier, body: Statement) -> Self {
+        Statement::from_kind(StatementKind::Labeled(Box::new(LabeledStatement {
+            label,
+            body,
+        })))
+    }
+
+    pub fn break_(label: Option<Identifier>) -> Self {
+        Statement::from_kind(StatementKind::Break(label))
+    }
+
+    pub fn continue_(label: Option<Identifier>) -> Self {
+        Statement::from_kind(StatementKind::Continue(label))
+    }
+
+    pub fn while_(test: Expression, body: BlockStatement) -> Self {
+        Statement::from_kind(StatementKind::While(Box::new(WhileStatement {
+            test,
+            body,
+        })))
     }
 
     pub fn function_declaration(
+        async_: bool,
         id: Identifier,
         params: Vec<Pattern>,
         body: BlockStatement,
     ) -> Self {
-        Statement(StatementKind::FunctionDeclaration(FunctionDeclaration {
+        Statement::from_kind(StatementKind::FunctionDeclaration(FunctionDeclaration {
+            async_,
             id,
             params,
             body,
         }))
     }
 
+    pub fn class_declaration(
+        id: Identifier,
+        super_class: Option<Identifier>,
+        body: Vec<MethodDefinition>,
+    ) -> Self {
+        Statement::from_kind(StatementKind::ClassDeclaration(Box::new(
+            ClassDeclaration {
+                id,
+                super_class,
+                body,
+            },
+        )))
+    }
+
     pub fn variable_declaration(
         kind: VariableDeclarationKind,
         id: Pattern,
         init: Expression,
     ) -> Self {
-        Statement(StatementKind::VariableDeclaration(VariableDeclaration {
+        Statement::from_kind(StatementKind::VariableDeclaration(VariableDeclaration {
+            kind,
+            id,
+            init: Some(init),
+        }))
+    }
+
+    pub fn variable_declaration_without_init(kind: VariableDeclarationKind, id: Pattern) -> Self {
+        Statement::from_kind(StatementKind::VariableDeclaration(VariableDeclaration {
             kind,
             id,
-            init,
+            init: None,
         }))
     }
+
+    pub fn import_declaration(specifiers: Vec<ImportSpecifier>, source: String) -> Self {
+        Statement::from_kind(StatementKind::ImportDeclaration(ImportDeclaration {
+            specifiers,
+            source,
+        }))
+    }
+
+    /// Exports a declaration statement. Should only be called with a function or
+    /// variable declaration.
+    pub fn export_declaration(declaration: Statement) -> Self {
+        Statement::from_kind(StatementKind::ExportNamedDeclaration(Box::new(
+            ExportNamedDeclaration {
+                declaration: Some(declaration),
+                specifiers: Vec::new(),
+            },
+        )))
+    }
+
+    pub fn export_specifiers(specifiers: Vec<ExportSpecifier>) -> Self {
+        Statement::from_kind(StatementKind::ExportNamedDeclaration(Box::new(
+            ExportNamedDeclaration {
+                declaration: None,
+                specifiers,
+            },
+        )))
+    }
 }
 
 impl Expression {
+    fn from_kind(kind: ExpressionKind) -> Self {
+        Expression(kind, None)
+    }
+
+    /// Sets the range of the Brite code our expression was compiled from. When we write a source
+    /// map our expression maps to the start of this range.
+    pub fn with_range(mut self, range: Range) -> Self {
+        self.1 = Some(range);
+        self
+    }
+
     pub fn identifier(identifier: Identifier) -> Self {
-        Expression(ExpressionKind::Identifier(identifier))
+        Expression::from_kind(ExpressionKind::Identifier(identifier))
+    }
+
+    pub fn this() -> Self {
+        Expression::from_kind(ExpressionKind::This)
+    }
+
+    pub fn super_() -> Self {
+        Expression::from_kind(ExpressionKind::Super)
     }
 
     pub fn undefined_literal() -> Self {
-        Expression(ExpressionKind::UndefinedLiteral)
+        Expression::from_kind(ExpressionKind::UndefinedLiteral)
+    }
+
+    pub fn null_literal() -> Self {
+        Expression::from_kind(ExpressionKind::NullLiteral)
     }
 
     pub fn boolean_literal(value: bool) -> Self {
-        Expression(ExpressionKind::BooleanLiteral(value))
+        Expression::from_kind(ExpressionKind::BooleanLiteral(value))
     }
 
     pub fn numeric_literal(value: f64) -> Self {
-        Expression(ExpressionKind::NumericLiteral(value))
+        Expression::from_kind(ExpressionKind::NumericLiteral(value))
     }
 
-    pub fn arrow_function(params: Vec<Pattern>, body: ArrowFunctionBody) -> Self {
-        Expression(ExpressionKind::ArrowFunction(ArrowFunctionExpression {
+    pub fn string_literal(value: String) -> Self {
+        Expression::from_kind(ExpressionKind::StringLiteral(value))
+    }
+
+    /// Creates a template literal. There must be exactly one more quasi than there
+    /// are expressions.
+    pub fn template_literal(quasis: Vec<String>, expressions: Vec<Expression>) -> Self {
+        assert_eq!(quasis.len(), expressions.len() + 1);
+        Expression::from_kind(ExpressionKind::TemplateLiteral(TemplateLiteral {
+            quasis,
+            expressions,
+        }))
+    }
+
+    pub fn array(elements: Vec<Expression>) -> Self {
+        Expression::from_kind(ExpressionKind::Array(elements))
+    }
+
+    pub fn object(properties: Vec<ObjectProperty>) -> Self {
+        Expression::from_kind(ExpressionKind::Object(properties))
+    }
+
+    pub fn arrow_function(async_: bool, params: Vec<Pattern>, body: ArrowFunctionBody) -> Self {
+        Expression::from_kind(ExpressionKind::ArrowFunction(ArrowFunctionExpression {
+            async_,
             params,
             body,
         }))
     }
 
+    pub fn call(callee: Expression, arguments: Vec<Expression>) -> Self {
+        Expression::from_kind(ExpressionKind::Call(Box::new(CallExpression {
+            callee,
+            arguments,
+        })))
+    }
+
+    pub fn new(callee: Expression, arguments: Vec<Expression>) -> Self {
+        Expression::from_kind(ExpressionKind::New(Box::new(CallExpression {
+            callee,
+            arguments,
+        })))
+    }
+
+    pub fn import(source: String) -> Self {
+        Expression::from_kind(ExpressionKind::Import(source))
+    }
+
+    pub fn await_(argument: Expression) -> Self {
+        Expression::from_kind(ExpressionKind::Await(Box::new(argument)))
+    }
+
+    pub fn member(object: Expression, property: Identifier) -> Self {
+        Expression::from_kind(ExpressionKind::Member(Box::new(MemberExpression {
+            object,
+            property,
+        })))
+    }
+
+    pub fn computed_member(object: Expression, property: Expression) -> Self {
+        Expression::from_kind(ExpressionKind::ComputedMember(Box::new(
+            ComputedMemberExpression { object, property },
+        )))
+    }
+
+    pub fn unary(operator: UnaryOperator, argument: Expression) -> Self {
+        Expression::from_kind(ExpressionKind::Unary(Box::new(UnaryExpression {
+            operator,
+            argument,
+        })))
+    }
+
+    pub fn binary(operator: BinaryOperator, left: Expression, right: Expression) -> Self {
+        Expression::from_kind(ExpressionKind::Binary(Box::new(BinaryExpression {
+            operator,
+            left,
+            right,
+        })))
+    }
+
+    pub fn assignment(left: Pattern, right: Expression) -> Self {
+        Expression::from_kind(ExpressionKind::Assignment(Box::new(AssignmentExpression {
+            operator: None,
+            left,
+            right,
+        })))
+    }
+
+    pub fn compound_assignment(
+        operator: AssignmentOperator,
+        left: Pattern,
+        right: Expression,
+    ) -> Self {
+        Expression::from_kind(ExpressionKind::Assignment(Box::new(AssignmentExpression {
+            operator: Some(operator),
+            left,
+            right,
+        })))
+    }
+
     pub fn logical(operator: LogicalOperator, left: Expression, right: Expression) -> Self {
-        Expression(ExpressionKind::Logical(Box::new(LogicalExpression {
+        Expression::from_kind(ExpressionKind::Logical(Box::new(LogicalExpression {
             operator,
             left,
             right,
@@ -190,6 +613,58 @@ impl Pattern {
     pub fn identifier(identifier: Identifier) -> Self {
         Pattern(PatternKind::Identifier(identifier))
     }
+
+    pub fn member(object: Expression, property: Identifier) -> Self {
+        Pattern(PatternKind::Member(Box::new(MemberExpression {
+            object,
+            property,
+        })))
+    }
+
+    pub fn object(properties: Vec<ObjectPatternProperty>) -> Self {
+        Pattern(PatternKind::Object(properties))
+    }
+
+    pub fn assignment(left: Pattern, right: Expression) -> Self {
+        Pattern(PatternKind::Assignment(Box::new(AssignmentPattern {
+            left,
+            right,
+        })))
+    }
+}
+
+impl ObjectPatternProperty {
+    pub fn new(key: Identifier, value: Pattern) -> Self {
+        ObjectPatternProperty { key, value }
+    }
+}
+
+impl MethodDefinition {
+    pub fn constructor(params: Vec<Pattern>, body: BlockStatement) -> Self {
+        MethodDefinition {
+            kind: MethodDefinitionKind::Constructor,
+            static_: false,
+            async_: false,
+            params,
+            body,
+        }
+    }
+
+    pub fn method(
+        static_: bool,
+        async_: bool,
+        key: Identifier,
+        params: Vec<Pattern>,
+        body: BlockStatement,
+    ) -> Self {
+        MethodDefinition {
+            kind: MethodDefinitionKind::Method(key),
+            static_,
+            async_,
+            params,
+            body,
+        }
+    }
 }
 
 /// Precedence levels in the [JavaScript expression][1] syntax. We don’t need all the precedence
@@ -206,53 +681,128 @@ enum Precedence {
     // Conditional,
     LogicalOr,
     LogicalAnd,
-    // BitwiseOr,
-    // BitwiseXor,
-    // BitwiseAnd,
-    // Equality,
-    // Relational,
-    // Shift,
-    // Additive,
-    // Multiplicative,
-    // Exponential,
-    // Unary,
-    // Update,
-    // Call,
-    // Member,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseAnd,
+    Equality,
+    Relational,
+    Shift,
+    Additive,
+    Multiplicative,
+    Exponential,
+    Unary,
+    Update,
+    Call,
+    Member,
     Primary,
     // Bottom,
 }
 
+/// Options for how we write a JavaScript program. The default options write pretty code with
+/// two-space indentation and a semicolon after every statement.
+pub struct JsWriterOptions {
+    /// Should we write our program with as few characters as possible? Minified code has no
+    /// whitespace other than the spaces JavaScript needs between keywords and no more parentheses
+    /// than the precedence of our expressions needs. Our indentation and maximum line width are
+    /// ignored when we minify.
+    pub minify: bool,
+    /// The string we write once for every level of indentation.
+    pub indentation: String,
+    /// When do we write a semicolon at the end of a statement?
+    pub semicolons: Semicolons,
+    /// The width, in UTF-16 code units, we try to keep our lines under. When a list of arguments,
+    /// parameters, elements, or properties does not fit in our line then we write every item on a
+    /// line of its own. A line may still be longer than our maximum width if we can’t break it.
+    pub max_line_width: usize,
+}
+
+/// When we write a semicolon at the end of a statement.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Semicolons {
+    /// Write a semicolon at the end of every statement.
+    Always,
+    /// Only write a semicolon where JavaScript’s automatic semicolon insertion would not insert
+    /// one for us. In pretty code that is before a statement which could continue the line before
+    /// it. In minified code that is between statements on the same line.
+    AsNeeded,
+}
+
+impl Default for JsWriterOptions {
+    fn default() -> Self {
+        JsWriterOptions {
+            minify: false,
+            indentation: "  ".to_string(),
+            semicolons: Semicolons::Always,
+            max_line_width: 80,
+        }
+    }
+}
+
 impl Identifier {
-    fn write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+    fn write(&self, w: &mut Writer) -> io::Result<()> {
         write!(w, "{}", &self.0)
     }
 }
 
 impl Program {
     pub fn write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
-        Statement::write_many(&self.body, w, 0)
+        self.write_with_options(w, &JsWriterOptions::default())
     }
-}
 
-impl Statement {
-    fn write_many<W: io::Write>(
-        statements: &Vec<Statement>,
+    /// Writes our program like [`Program::write`] but in the style described by our options.
+    pub fn write_with_options<W: io::Write>(
+        &self,
         w: &mut W,
-        i: usize,
+        options: &JsWriterOptions,
     ) -> io::Result<()> {
+        Statement::write_many(&self.body, &mut Writer::new(w, options), 0)
+    }
+
+    /// Writes our program like [`Program::write_with_options`] and returns a source map which
+    /// links the code we wrote back to the Brite document our program was compiled from.
+    pub fn write_with_source_map<W: io::Write>(
+        &self,
+        w: &mut W,
+        options: &JsWriterOptions,
+        document: &Document,
+        source_map_options: SourceMapOptions,
+    ) -> io::Result<SourceMap> {
+        let mut w = Writer::new(w, options);
+        Statement::write_many(&self.body, &mut w, 0)?;
+        Ok(SourceMap::new(document, source_map_options, &w.mappings))
+    }
+}
+
+impl Statement {
+    fn write_many(statements: &Vec<Statement>, w: &mut Writer, i: usize) -> io::Result<()> {
         for k in 0..statements.len() {
             let statement = &statements[k];
 
             if k > 0 {
-                let newline = match &statements[k - 1].0 {
-                    StatementKind::Expression(_) => false,
-                    StatementKind::Return(_) => false,
-                    StatementKind::FunctionDeclaration(_) => true,
-                    StatementKind::VariableDeclaration(_) => false,
+                let newline = match (&statements[k - 1].0, &statement.0) {
+                    // Keep all of our imports together and put a newline after the last one.
+                    (StatementKind::ImportDeclaration(_), StatementKind::ImportDeclaration(_)) => {
+                        false
+                    }
+                    (StatementKind::ImportDeclaration(_), _) => true,
+                    (StatementKind::Expression(_), _) => false,
+                    (StatementKind::Block(_), _) => false,
+                    (StatementKind::Return(_), _) => false,
+                    (StatementKind::Throw(_), _) => false,
+                    (StatementKind::If(_), _) => false,
+                    (StatementKind::Labeled(_), _) => false,
+                    (StatementKind::Break(_), _) => false,
+                    (StatementKind::Continue(_), _) => false,
+                    (StatementKind::While(_), _) => false,
+                    (StatementKind::FunctionDeclaration(_), _) => true,
+                    (StatementKind::ClassDeclaration(_), _) => true,
+                    (StatementKind::VariableDeclaration(_), _) => false,
+                    (StatementKind::ExportNamedDeclaration(export), _) => {
+                        export.declaration.is_some()
+                    }
                 };
                 if newline {
-                    write!(w, "\n")?;
+                    w.newline()?;
                 }
             }
 
@@ -261,55 +811,247 @@ impl Statement {
         Ok(())
     }
 
-    fn write<W: io::Write>(&self, w: &mut W, i: usize) -> io::Result<()> {
+    fn write(&self, w: &mut Writer, i: usize) -> io::Result<()> {
         write_indentation(w, i)?;
+        self.write_without_indentation(w, i)
+    }
 
+    /// Writes a statement without indentation before it so that we may write a labeled statement
+    /// on the same line as its label.
+    fn write_without_indentation(&self, w: &mut Writer, i: usize) -> io::Result<()> {
+        w.map(self.1);
         match &self.0 {
             StatementKind::Expression(expression) => {
+                // JavaScript would parse a statement starting with `{` as a block so we wrap our
+                // expression in parentheses if it starts with an object.
+                let object = expression.starts_with_object(Precedence::Top);
+
+                // Without a semicolon at the end of the line before us JavaScript would continue
+                // that line with our expression if it starts with one of these characters.
+                if !w.options.minify
+                    && w.options.semicolons == Semicolons::AsNeeded
+                    && (object || expression.continues_line(Precedence::Top))
+                {
+                    write!(w, ";")?;
+                }
+                if object {
+                    write!(w, "(")?;
+                }
                 expression.write(w, i, Precedence::Top)?;
-                write!(w, ";\n")
+                if object {
+                    write!(w, ")")?;
+                }
+                w.end_statement()
+            }
+            StatementKind::Block(block) => {
+                block.write(w, i)?;
+                w.newline()
+            }
+            StatementKind::Return(Some(argument)) => {
+                w.keyword("return")?;
+                argument.write(w, i, Precedence::Top)?;
+                w.end_statement()
+            }
+            StatementKind::Return(None) => {
+                write!(w, "return")?;
+                w.end_statement()
             }
-            StatementKind::Return(argument) => {
-                write!(w, "return ")?;
+            StatementKind::Throw(argument) => {
+                w.keyword("throw")?;
                 argument.write(w, i, Precedence::Top)?;
-                write!(w, ";\n")
+                w.end_statement()
+            }
+            StatementKind::If(if_statement) => {
+                if_statement.write(w, i)?;
+                w.newline()
+            }
+            StatementKind::Labeled(labeled_statement) => {
+                labeled_statement.label.write(w)?;
+                write!(w, ":")?;
+                w.space()?;
+                labeled_statement.body.write_without_indentation(w, i)
+            }
+            StatementKind::Break(label) => {
+                write!(w, "break")?;
+                if let Some(label) = label {
+                    write!(w, " ")?;
+                    label.write(w)?;
+                }
+                w.end_statement()
+            }
+            StatementKind::Continue(label) => {
+                write!(w, "continue")?;
+                if let Some(label) = label {
+                    write!(w, " ")?;
+                    label.write(w)?;
+                }
+                w.end_statement()
+            }
+            StatementKind::While(while_statement) => {
+                write!(w, "while")?;
+                w.space()?;
+                write!(w, "(")?;
+                while_statement.test.write(w, i, Precedence::Top)?;
+                write!(w, ")")?;
+                w.space()?;
+                while_statement.body.write(w, i)?;
+                w.newline()
             }
             StatementKind::FunctionDeclaration(function_declaration) => {
-                write!(w, "function ")?;
+                if function_declaration.async_ {
+                    w.keyword("async")?;
+                }
+                w.keyword("function")?;
                 function_declaration.id.write(w)?;
-                write!(w, "(")?;
-                for i in 0..function_declaration.params.len() {
-                    if i != 0 {
-                        write!(w, ", ")?;
+                write_list(
+                    w,
+                    i,
+                    ("(", ")"),
+                    false,
+                    &function_declaration.params,
+                    &|param, w, i| param.write(w, i),
+                )?;
+                w.space()?;
+                function_declaration.body.write(w, i)?;
+                w.newline()
+            }
+            StatementKind::ClassDeclaration(class_declaration) => {
+                w.keyword("class")?;
+                class_declaration.id.write(w)?;
+                if let Some(super_class) = &class_declaration.super_class {
+                    write!(w, " ")?;
+                    w.keyword("extends")?;
+                    super_class.write(w)?;
+                }
+                w.space()?;
+                if class_declaration.body.is_empty() {
+                    write!(w, "{{}}")?;
+                    w.newline()
+                } else {
+                    write!(w, "{{")?;
+                    w.newline()?;
+                    for (j, method) in class_declaration.body.iter().enumerate() {
+                        if j != 0 {
+                            w.newline()?;
+                        }
+                        method.write(w, i + 1)?;
                     }
-                    function_declaration.params[i].write(w)?;
+                    write_indentation(w, i)?;
+                    write!(w, "}}")?;
+                    w.newline()
                 }
-                write!(w, ") ")?;
-                function_declaration.body.write(w, i)?;
-                write!(w, "\n")
             }
             StatementKind::VariableDeclaration(variable_declaration) => {
                 match &variable_declaration.kind {
-                    VariableDeclarationKind::Var => write!(w, "var")?,
-                    VariableDeclarationKind::Let => write!(w, "let")?,
-                    VariableDeclarationKind::Const => write!(w, "const")?,
+                    VariableDeclarationKind::Var => w.keyword("var")?,
+                    VariableDeclarationKind::Let => w.keyword("let")?,
+                    VariableDeclarationKind::Const => w.keyword("const")?,
                 }
-                write!(w, " ")?;
-                variable_declaration.id.write(w)?;
-                write!(w, " = ")?;
-                variable_declaration.init.write(w, i, Precedence::Top)?;
-                write!(w, ";\n")
+                variable_declaration.id.write(w, i)?;
+                if let Some(init) = &variable_declaration.init {
+                    w.space()?;
+                    write!(w, "=")?;
+                    w.space()?;
+                    init.write(w, i, Precedence::Top)?;
+                }
+                w.end_statement()
+            }
+            StatementKind::ImportDeclaration(import_declaration) => {
+                w.keyword("import")?;
+                write_list(
+                    w,
+                    i,
+                    ("{", "}"),
+                    true,
+                    &import_declaration.specifiers,
+                    &|specifier, w, _| {
+                        specifier.imported.write(w)?;
+                        if specifier.local != specifier.imported {
+                            write!(w, " as ")?;
+                            specifier.local.write(w)?;
+                        }
+                        Ok(())
+                    },
+                )?;
+                w.space()?;
+                w.keyword("from")?;
+                write!(w, "\"")?;
+                write_escaped(w, &import_declaration.source, '"')?;
+                write!(w, "\"")?;
+                w.end_statement()
+            }
+            StatementKind::ExportNamedDeclaration(export_declaration) => {
+                w.keyword("export")?;
+                if let Some(declaration) = &export_declaration.declaration {
+                    return declaration.write_without_indentation(w, i);
+                }
+                write_list(
+                    w,
+                    i,
+                    ("{", "}"),
+                    true,
+                    &export_declaration.specifiers,
+                    &|specifier, w, _| {
+                        specifier.local.write(w)?;
+                        if specifier.exported != specifier.local {
+                            write!(w, " as ")?;
+                            specifier.exported.write(w)?;
+                        }
+                        Ok(())
+                    },
+                )?;
+                w.end_statement()
+            }
+        }
+    }
+}
+
+impl IfStatement {
+    /// Writes an if statement without indentation before it or a newline after it so that we may
+    /// write `else if` chains on a single line.
+    fn write(&self, w: &mut Writer, i: usize) -> io::Result<()> {
+        write!(w, "if")?;
+        w.space()?;
+        write!(w, "(")?;
+        self.test.write(w, i, Precedence::Top)?;
+        write!(w, ")")?;
+        w.space()?;
+        self.consequent.write(w, i)?;
+        match &self.alternate {
+            None => {}
+            Some(Statement(StatementKind::If(alternate), _)) => {
+                w.space()?;
+                w.keyword("else")?;
+                alternate.write(w, i)?;
+            }
+            Some(Statement(StatementKind::Block(alternate), _)) => {
+                w.space()?;
+                write!(w, "else")?;
+                w.space()?;
+                alternate.write(w, i)?;
+            }
+            Some(alternate) => {
+                w.space()?;
+                write!(w, "else")?;
+                w.space()?;
+                write!(w, "{{")?;
+                w.newline()?;
+                alternate.write(w, i + 1)?;
+                write_indentation(w, i)?;
+                write!(w, "}}")?;
             }
         }
+        Ok(())
     }
 }
 
 impl BlockStatement {
-    fn write<W: io::Write>(&self, w: &mut W, i: usize) -> io::Result<()> {
+    fn write(&self, w: &mut Writer, i: usize) -> io::Result<()> {
         if self.body.is_empty() {
             write!(w, "{{}}")
         } else {
-            write!(w, "{{\n")?;
+            write!(w, "{{")?;
+            w.newline()?;
             Statement::write_many(&self.body, w, i + 1)?;
             write_indentation(w, i)?;
             write!(w, "}}")
@@ -320,25 +1062,20 @@ impl BlockStatement {
 impl Expression {
     /// Print an expression at the provided level of indentation. All expressions with a smaller
     /// precedence than `p` will be wrapped in parentheses.
-    fn write<W: io::Write>(&self, w: &mut W, i: usize, p: Precedence) -> io::Result<()> {
-        let precedence = match &self.0 {
-            ExpressionKind::Identifier(_) => Precedence::Primary,
-            ExpressionKind::UndefinedLiteral => Precedence::Primary,
-            ExpressionKind::BooleanLiteral(_) => Precedence::Primary,
-            ExpressionKind::NumericLiteral(_) => Precedence::Primary,
-            ExpressionKind::ArrowFunction(_) => Precedence::Assignment,
-            ExpressionKind::Logical(logical) => match &logical.operator {
-                LogicalOperator::Or => Precedence::LogicalOr,
-                LogicalOperator::And => Precedence::LogicalAnd,
-            },
-        };
+    fn write(&self, w: &mut Writer, i: usize, p: Precedence) -> io::Result<()> {
+        w.map(self.1);
+        let precedence = self.precedence();
         if p > precedence {
             write!(w, "(")?;
         }
         match &self.0 {
             ExpressionKind::Identifier(identifier) => identifier.write(w)?,
 
+            ExpressionKind::This => write!(w, "this")?,
+            ExpressionKind::Super => write!(w, "super")?,
+
             ExpressionKind::UndefinedLiteral => write!(w, "undefined")?,
+            ExpressionKind::NullLiteral => write!(w, "null")?,
 
             ExpressionKind::BooleanLiteral(true) => write!(w, "true")?,
             ExpressionKind::BooleanLiteral(false) => write!(w, "false")?,
@@ -359,7 +1096,59 @@ impl Expression {
                 }
             }
 
+            // Always print strings with double quotes.
+            ExpressionKind::StringLiteral(value) => {
+                write!(w, "\"")?;
+                write_escaped(w, value, '"')?;
+                write!(w, "\"")?;
+            }
+
+            // Print the quasis of a template literal with the expressions interpolated in between.
+            ExpressionKind::TemplateLiteral(template) => {
+                write!(w, "`")?;
+                write_escaped(w, &template.quasis[0], '`')?;
+                for (expression, quasi) in template.expressions.iter().zip(&template.quasis[1..]) {
+                    write!(w, "${{")?;
+                    expression.write(w, i, Precedence::Top)?;
+                    write!(w, "}}")?;
+                    write_escaped(w, quasi, '`')?;
+                }
+                write!(w, "`")?;
+            }
+
+            ExpressionKind::Array(elements) => {
+                write_list(w, i, ("[", "]"), false, elements, &|element, w, i| {
+                    element.write(w, i, Precedence::Assignment)
+                })?;
+            }
+
+            // Like object patterns we use the shorthand syntax when a property’s value is a
+            // variable of the same name.
+            ExpressionKind::Object(properties) => {
+                if properties.is_empty() {
+                    write!(w, "{{}}")?;
+                } else {
+                    write_list(w, i, ("{", "}"), true, properties, &|property, w, i| {
+                        property.key.write(w)?;
+                        match &property.value.0 {
+                            ExpressionKind::Identifier(identifier)
+                                if identifier == &property.key => {}
+                            _ => {
+                                write!(w, ":")?;
+                                w.space()?;
+                                property.value.write(w, i, Precedence::Assignment)?;
+                            }
+                        }
+                        Ok(())
+                    })?;
+                }
+            }
+
             ExpressionKind::ArrowFunction(arrow_function) => {
+                if arrow_function.async_ {
+                    w.keyword("async")?;
+                }
+
                 // Write the arrow function’s parameters. If the arrow function has a single,
                 // identifier, parameter then we don’t need to emit the parentheses.
                 if arrow_function.params.len() == 1 {
@@ -367,69 +1156,640 @@ impl Expression {
                         identifier.write(w)?;
                     } else {
                         write!(w, "(")?;
-                        arrow_function.params[0].write(w)?;
+                        arrow_function.params[0].write(w, i)?;
                         write!(w, ")")?;
                     }
                 } else {
-                    write!(w, "(")?;
-                    for i in 0..arrow_function.params.len() {
-                        if i > 0 {
-                            write!(w, ", ")?;
-                        }
-                        arrow_function.params[i].write(w)?;
-                    }
-                    write!(w, ")")?;
+                    write_list(
+                        w,
+                        i,
+                        ("(", ")"),
+                        false,
+                        &arrow_function.params,
+                        &|param, w, i| param.write(w, i),
+                    )?;
                 }
 
                 // Write the arrow itself!
-                write!(w, " => ")?;
+                w.space()?;
+                write!(w, "=>")?;
+                w.space()?;
 
                 // Write the arrow function’s body...
                 match &arrow_function.body {
                     ArrowFunctionBody::Block(block) => {
                         block.write(w, i)?;
                     }
+                    // JavaScript would parse a body starting with `{` as a block so we wrap our
+                    // body in parentheses if it starts with an object.
                     ArrowFunctionBody::Expression(expression) => {
-                        // TODO: Wrap object expressions.
-                        expression.write(w, i, Precedence::Top)?;
+                        if expression.starts_with_object(Precedence::Top) {
+                            write!(w, "(")?;
+                            expression.write(w, i, Precedence::Top)?;
+                            write!(w, ")")?;
+                        } else {
+                            expression.write(w, i, Precedence::Top)?;
+                        }
                     }
                 }
             }
 
+            ExpressionKind::Call(call) => {
+                call.callee.write(w, i, Precedence::Call)?;
+                write_list(
+                    w,
+                    i,
+                    ("(", ")"),
+                    false,
+                    &call.arguments,
+                    &|argument, w, i| argument.write(w, i, Precedence::Assignment),
+                )?;
+            }
+
+            // Our callee may not be a call since then JavaScript would think the arguments of our
+            // callee are our arguments.
+            ExpressionKind::New(new) => {
+                w.keyword("new")?;
+                new.callee.write(w, i, Precedence::Member)?;
+                write_list(
+                    w,
+                    i,
+                    ("(", ")"),
+                    false,
+                    &new.arguments,
+                    &|argument, w, i| argument.write(w, i, Precedence::Assignment),
+                )?;
+            }
+
+            ExpressionKind::Import(source) => {
+                write!(w, "import(\"")?;
+                write_escaped(w, source, '"')?;
+                write!(w, "\")")?;
+            }
+
+            ExpressionKind::Await(argument) => {
+                w.keyword("await")?;
+                argument.write(w, i, Precedence::Unary)?;
+            }
+
+            // A call may be the object of a member expression without parentheses. We don’t have
+            // `new` expressions where this would be ambiguous.
+            ExpressionKind::Member(member) => {
+                member.object.write(w, i, Precedence::Call)?;
+                write!(w, ".")?;
+                member.property.write(w)?;
+            }
+
+            ExpressionKind::ComputedMember(member) => {
+                member.object.write(w, i, Precedence::Member)?;
+                write!(w, "[")?;
+                member.property.write(w, i, Precedence::Top)?;
+                write!(w, "]")?;
+            }
+
+            ExpressionKind::Unary(unary) => match &unary.operator {
+                UnaryOperator::Not => {
+                    write!(w, "!")?;
+                    unary.argument.write(w, i, Precedence::Unary)?;
+                }
+                UnaryOperator::Negative => {
+                    write!(w, "-")?;
+                    unary.argument.write(w, i, Precedence::Unary)?;
+                }
+                UnaryOperator::Positive => {
+                    write!(w, "+")?;
+                    unary.argument.write(w, i, Precedence::Unary)?;
+                }
+                UnaryOperator::BitwiseNot => {
+                    write!(w, "~")?;
+                    unary.argument.write(w, i, Precedence::Unary)?;
+                }
+            },
+
+            ExpressionKind::Binary(binary) => {
+                let (operator, left, right) = binary.operator.info();
+                binary.left.write(w, i, left)?;
+                // Even minified code needs spaces around a keyword operator.
+                if operator == "instanceof" {
+                    write!(w, " {} ", operator)?;
+                } else {
+                    w.space()?;
+                    write!(w, "{}", operator)?;
+                    w.space()?;
+                }
+                binary.right.write(w, i, right)?;
+            }
+
             ExpressionKind::Logical(logical) => match &logical.operator {
                 LogicalOperator::Or => {
                     logical.left.write(w, i, Precedence::LogicalOr)?;
-                    write!(w, " || ")?;
+                    w.space()?;
+                    write!(w, "||")?;
+                    w.space()?;
                     logical.right.write(w, i, Precedence::LogicalOr)?;
                 }
                 LogicalOperator::And => {
                     logical.left.write(w, i, Precedence::LogicalAnd)?;
-                    write!(w, " && ")?;
+                    w.space()?;
+                    write!(w, "&&")?;
+                    w.space()?;
                     logical.right.write(w, i, Precedence::LogicalAnd)?;
                 }
             },
+
+            ExpressionKind::Assignment(assignment) => {
+                assignment.left.write(w, i)?;
+                w.space()?;
+                match &assignment.operator {
+                    None => write!(w, "=")?,
+                    Some(AssignmentOperator::Add) => write!(w, "+=")?,
+                    Some(AssignmentOperator::Subtract) => write!(w, "-=")?,
+                    Some(AssignmentOperator::Multiply) => write!(w, "*=")?,
+                    Some(AssignmentOperator::Divide) => write!(w, "/=")?,
+                    Some(AssignmentOperator::Remainder) => write!(w, "%=")?,
+                }
+                w.space()?;
+                assignment.right.write(w, i, Precedence::Assignment)?;
+            }
         }
         if p > precedence {
             write!(w, ")")?;
         }
         Ok(())
     }
+
+    /// The precedence of our expression. We wrap our expression in parentheses when it is written
+    /// somewhere which expects a higher precedence.
+    fn precedence(&self) -> Precedence {
+        match &self.0 {
+            ExpressionKind::Identifier(_) => Precedence::Primary,
+            ExpressionKind::This => Precedence::Primary,
+            ExpressionKind::Super => Precedence::Primary,
+            ExpressionKind::UndefinedLiteral => Precedence::Primary,
+            ExpressionKind::NullLiteral => Precedence::Primary,
+            ExpressionKind::BooleanLiteral(_) => Precedence::Primary,
+            ExpressionKind::NumericLiteral(_) => Precedence::Primary,
+            ExpressionKind::StringLiteral(_) => Precedence::Primary,
+            ExpressionKind::TemplateLiteral(_) => Precedence::Primary,
+            ExpressionKind::Array(_) => Precedence::Primary,
+            ExpressionKind::Object(_) => Precedence::Primary,
+            ExpressionKind::ArrowFunction(_) => Precedence::Assignment,
+            ExpressionKind::Call(_) => Precedence::Call,
+            ExpressionKind::New(_) => Precedence::Member,
+            ExpressionKind::Import(_) => Precedence::Call,
+            ExpressionKind::Await(_) => Precedence::Unary,
+            ExpressionKind::Member(_) => Precedence::Member,
+            ExpressionKind::ComputedMember(_) => Precedence::Member,
+            ExpressionKind::Unary(_) => Precedence::Unary,
+            ExpressionKind::Binary(binary) => binary.operator.precedence(),
+            ExpressionKind::Logical(logical) => match &logical.operator {
+                LogicalOperator::Or => Precedence::LogicalOr,
+                LogicalOperator::And => Precedence::LogicalAnd,
+            },
+            ExpressionKind::Assignment(_) => Precedence::Assignment,
+        }
+    }
+
+    /// Does our expression start with `(`, `[`, `` ` ``, `-`, or `+` when written at the provided
+    /// precedence? JavaScript won’t insert a semicolon before a line starting with one of these
+    /// characters since the line could continue the line before it.
+    fn continues_line(&self, p: Precedence) -> bool {
+        if p > self.precedence() {
+            return true;
+        }
+        match &self.0 {
+            ExpressionKind::NumericLiteral(value) => {
+                value.is_infinite() && value.is_sign_negative()
+            }
+            ExpressionKind::TemplateLiteral(_) => true,
+            ExpressionKind::Array(_) => true,
+            ExpressionKind::Unary(unary) => match &unary.operator {
+                UnaryOperator::Negative | UnaryOperator::Positive => true,
+                UnaryOperator::Not | UnaryOperator::BitwiseNot => false,
+            },
+            ExpressionKind::ArrowFunction(arrow_function) => match &arrow_function.params[..] {
+                _ if arrow_function.async_ => false,
+                [Pattern(PatternKind::Identifier(_))] => false,
+                _ => true,
+            },
+            ExpressionKind::Call(call) => call.callee.continues_line(Precedence::Call),
+            ExpressionKind::Member(member) => member.object.continues_line(Precedence::Call),
+            ExpressionKind::ComputedMember(member) => {
+                member.object.continues_line(Precedence::Member)
+            }
+            ExpressionKind::Binary(binary) => binary.left.continues_line(binary.operator.info().1),
+            ExpressionKind::Logical(logical) => match &logical.operator {
+                LogicalOperator::Or => logical.left.continues_line(Precedence::LogicalOr),
+                LogicalOperator::And => logical.left.continues_line(Precedence::LogicalAnd),
+            },
+            ExpressionKind::Assignment(assignment) => match &assignment.left.0 {
+                PatternKind::Member(member) => member.object.continues_line(Precedence::Call),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Does our expression start with an object when written at the provided precedence?
+    fn starts_with_object(&self, p: Precedence) -> bool {
+        if p > self.precedence() {
+            return false;
+        }
+        match &self.0 {
+            ExpressionKind::Object(_) => true,
+            ExpressionKind::Call(call) => call.callee.starts_with_object(Precedence::Call),
+            ExpressionKind::Member(member) => member.object.starts_with_object(Precedence::Call),
+            ExpressionKind::ComputedMember(member) => {
+                member.object.starts_with_object(Precedence::Member)
+            }
+            ExpressionKind::Binary(binary) => {
+                binary.left.starts_with_object(binary.operator.info().1)
+            }
+            ExpressionKind::Logical(logical) => match &logical.operator {
+                LogicalOperator::Or => logical.left.starts_with_object(Precedence::LogicalOr),
+                LogicalOperator::And => logical.left.starts_with_object(Precedence::LogicalAnd),
+            },
+            ExpressionKind::Assignment(assignment) => match &assignment.left.0 {
+                PatternKind::Member(member) => member.object.starts_with_object(Precedence::Call),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl BinaryOperator {
+    /// Returns the code for our operator along with the precedence of its left and right operands.
+    /// Binary operators are left associative so our right operand must have a higher precedence
+    /// than our operator.
+    fn info(&self) -> (&'static str, Precedence, Precedence) {
+        match self {
+            BinaryOperator::Add => ("+", Precedence::Additive, Precedence::Multiplicative),
+            BinaryOperator::Subtract => ("-", Precedence::Additive, Precedence::Multiplicative),
+            BinaryOperator::Multiply => ("*", Precedence::Multiplicative, Precedence::Exponential),
+            BinaryOperator::Divide => ("/", Precedence::Multiplicative, Precedence::Exponential),
+            BinaryOperator::Remainder => ("%", Precedence::Multiplicative, Precedence::Exponential),
+            // Exponentiation is right associative and its left operand may not be a unary
+            // expression. JavaScript rejects `-a ** b` since it is ambiguous.
+            BinaryOperator::Exponent => ("**", Precedence::Update, Precedence::Exponential),
+            BinaryOperator::LessThan => ("<", Precedence::Relational, Precedence::Shift),
+            BinaryOperator::LessThanOrEqual => ("<=", Precedence::Relational, Precedence::Shift),
+            BinaryOperator::GreaterThan => (">", Precedence::Relational, Precedence::Shift),
+            BinaryOperator::GreaterThanOrEqual => (">=", Precedence::Relational, Precedence::Shift),
+            BinaryOperator::InstanceOf => ("instanceof", Precedence::Relational, Precedence::Shift),
+            BinaryOperator::Equals => ("===", Precedence::Equality, Precedence::Relational),
+            BinaryOperator::NotEquals => ("!==", Precedence::Equality, Precedence::Relational),
+            BinaryOperator::LeftShift => ("<<", Precedence::Shift, Precedence::Additive),
+            BinaryOperator::RightShift => (">>", Precedence::Shift, Precedence::Additive),
+            BinaryOperator::UnsignedRightShift => (">>>", Precedence::Shift, Precedence::Additive),
+            BinaryOperator::BitwiseAnd => ("&", Precedence::BitwiseAnd, Precedence::Equality),
+            BinaryOperator::BitwiseOr => ("|", Precedence::BitwiseOr, Precedence::BitwiseXor),
+            BinaryOperator::BitwiseXor => ("^", Precedence::BitwiseXor, Precedence::BitwiseAnd),
+        }
+    }
+
+    /// The precedence of an expression using our operator. The same as the precedence of our left
+    /// operand except for right associative operators.
+    fn precedence(&self) -> Precedence {
+        match self {
+            BinaryOperator::Exponent => Precedence::Exponential,
+            _ => self.info().1,
+        }
+    }
+}
+
+impl MethodDefinition {
+    fn write(&self, w: &mut Writer, i: usize) -> io::Result<()> {
+        write_indentation(w, i)?;
+        if self.static_ {
+            w.keyword("static")?;
+        }
+        if self.async_ {
+            w.keyword("async")?;
+        }
+        match &self.kind {
+            MethodDefinitionKind::Constructor => write!(w, "constructor")?,
+            MethodDefinitionKind::Method(key) => key.write(w)?,
+        }
+        write_list(w, i, ("(", ")"), false, &self.params, &|param, w, i| {
+            param.write(w, i)
+        })?;
+        w.space()?;
+        self.body.write(w, i)?;
+        w.newline()
+    }
 }
 
 impl Pattern {
-    fn write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+    fn write(&self, w: &mut Writer, i: usize) -> io::Result<()> {
         match &self.0 {
             PatternKind::Identifier(identifier) => identifier.write(w)?,
-            PatternKind::Unimplemented => unimplemented!(),
+            PatternKind::Member(member) => {
+                member.object.write(w, i, Precedence::Call)?;
+                write!(w, ".")?;
+                member.property.write(w)?;
+            }
+            PatternKind::Object(properties) => {
+                if properties.is_empty() {
+                    write!(w, "{{}}")?;
+                } else {
+                    write_list(w, i, ("{", "}"), true, properties, &|property, w, i| {
+                        property.key.write(w)?;
+                        // Use the shorthand syntax when we bind a property to a variable of the
+                        // same name.
+                        match &property.value.0 {
+                            PatternKind::Identifier(identifier) if identifier == &property.key => {}
+                            _ => {
+                                write!(w, ":")?;
+                                w.space()?;
+                                property.value.write(w, i)?;
+                            }
+                        }
+                        Ok(())
+                    })?;
+                }
+            }
+            PatternKind::Assignment(assignment) => {
+                assignment.left.write(w, i)?;
+                w.space()?;
+                write!(w, "=")?;
+                w.space()?;
+                assignment.right.write(w, i, Precedence::Assignment)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes a comma separated list of items between an opening and a closing delimiter. If we are
+/// writing pretty code and our list does not fit in our maximum line width then we write every
+/// item on a line of its own. We never break a list with a single item since that item would be
+/// just as wide on a line of its own. Padded lists have spaces inside their delimiters like
+/// `{ a, b }` when written on a single line.
+fn write_list<T>(
+    w: &mut Writer,
+    i: usize,
+    (open, close): (&str, &str),
+    padded: bool,
+    items: &[T],
+    write_item: &dyn Fn(&T, &mut Writer, usize) -> io::Result<()>,
+) -> io::Result<()> {
+    if !w.options.minify
+        && !w.flat
+        && items.len() > 1
+        && !w.fits(|w| write_list(w, i, (open, close), padded, items, write_item))
+    {
+        write!(w, "{}", open)?;
+        for (j, item) in items.iter().enumerate() {
+            w.newline()?;
+            write_indentation(w, i + 1)?;
+            write_item(item, w, i + 1)?;
+            if j + 1 < items.len() {
+                write!(w, ",")?;
+            }
+        }
+        w.newline()?;
+        write_indentation(w, i)?;
+        return write!(w, "{}", close);
+    }
+    write!(w, "{}", open)?;
+    if padded {
+        w.space()?;
+    }
+    for (j, item) in items.iter().enumerate() {
+        if j != 0 {
+            write!(w, ",")?;
+            w.space()?;
+        }
+        write_item(item, w, i)?;
+    }
+    if padded {
+        w.space()?;
+    }
+    write!(w, "{}", close)
+}
+
+/// Writes the text of a string or template literal. We escape the characters which may not be
+/// written directly between the provided quotes. Line and paragraph separators are allowed in
+/// strings since ES2019, but we escape them anyway for older engines.
+fn write_escaped(w: &mut Writer, value: &str, quote: char) -> io::Result<()> {
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => write!(w, "\\\\")?,
+            '\n' => write!(w, "\\n")?,
+            '\r' => write!(w, "\\r")?,
+            '\t' => write!(w, "\\t")?,
+            '\u{2028}' | '\u{2029}' => write!(w, "\\u{:04X}", c as u32)?,
+            c if c == quote => write!(w, "\\{}", c)?,
+            '$' if quote == '`' && chars.peek() == Some(&'{') => write!(w, "\\$")?,
+            c if c.is_control() => write!(w, "\\u{{{:X}}}", c as u32)?,
+            c => write!(w, "{}", c)?,
+        }
+    }
+    Ok(())
+}
+
+/// Writes JavaScript code while keeping track of the line and column we are writing at so that we
+/// can map the code we write back to the Brite code it was compiled from.
+struct Writer<'a> {
+    w: &'a mut dyn io::Write,
+    options: &'a JsWriterOptions,
+    /// The zero-based line we are writing at.
+    line: u32,
+    /// The zero-based column we are writing at in UTF-16 code units.
+    column: u32,
+    /// A mapping for every statement and expression with a range in the order we wrote them.
+    mappings: Vec<Mapping>,
+    /// Have we ended a statement without writing its semicolon yet? When we minify with semicolons
+    /// as needed we don’t need the semicolon of the last statement in a block or program, so we
+    /// wait to see what we write next.
+    pending_semicolon: bool,
+    /// Have we written a keyword without writing the space after it yet? Minified code only needs
+    /// the space when an identifier, a number, or another keyword comes next.
+    pending_space: bool,
+    /// Are we writing every list on a single line? We do so when measuring the width of a list.
+    flat: bool,
+    /// The last byte we wrote. We need a space between two `-` or two `+` operators so that we
+    /// don’t write `--` or `++`. Like `a - -b` which minified code would otherwise write as
+    /// `a--b`.
+    last: u8,
+}
+
+impl<'a> Writer<'a> {
+    fn new(w: &'a mut dyn io::Write, options: &'a JsWriterOptions) -> Self {
+        Writer {
+            w,
+            options,
+            line: 0,
+            column: 0,
+            mappings: Vec::new(),
+            pending_semicolon: false,
+            pending_space: false,
+            flat: false,
+            last: 0,
+        }
+    }
+
+    /// Maps the code we are about to write to the start of the provided range. If we already have
+    /// a mapping at our current position then the new mapping replaces it since the code we are
+    /// about to write is more specific.
+    fn map(&mut self, range: Option<Range>) {
+        if let Some(range) = range {
+            let mapping = Mapping {
+                generated_line: self.line,
+                generated_column: self.column,
+                original: range.start(),
+            };
+            match self.mappings.last_mut() {
+                Some(last)
+                    if last.generated_line == self.line && last.generated_column == self.column =>
+                {
+                    *last = mapping;
+                }
+                _ => self.mappings.push(mapping),
+            }
+        }
+    }
+
+    /// Writes a space which is only there to make our code pretty.
+    fn space(&mut self) -> io::Result<()> {
+        if self.options.minify {
+            Ok(())
+        } else {
+            write!(self, " ")
+        }
+    }
+
+    /// Writes a newline which is only there to make our code pretty.
+    fn newline(&mut self) -> io::Result<()> {
+        if self.options.minify {
+            Ok(())
+        } else {
+            writeln!(self)
+        }
+    }
+
+    /// Writes a keyword and the space after it. When we minify we wait to see if we need the space.
+    fn keyword(&mut self, keyword: &str) -> io::Result<()> {
+        write!(self, "{}", keyword)?;
+        if self.options.minify {
+            self.pending_space = true;
+            Ok(())
+        } else {
+            write!(self, " ")
+        }
+    }
+
+    /// Ends a statement with a semicolon if our options need one and a newline if we are pretty.
+    fn end_statement(&mut self) -> io::Result<()> {
+        match (self.options.minify, self.options.semicolons) {
+            (false, Semicolons::Always) => writeln!(self, ";"),
+            (false, Semicolons::AsNeeded) => writeln!(self),
+            (true, Semicolons::Always) => write!(self, ";"),
+            (true, Semicolons::AsNeeded) => {
+                self.pending_semicolon = true;
+                Ok(())
+            }
+        }
+    }
+
+    /// Does the code written by our function fit between our current column and our maximum line
+    /// width? We write the code flat into a buffer and only measure its first line since
+    /// everything after a newline, like the body of a function, starts at a new column.
+    fn fits(&self, f: impl FnOnce(&mut Writer) -> io::Result<()>) -> bool {
+        let mut buffer = Vec::new();
+        let mut w = Writer {
+            w: &mut buffer,
+            options: self.options,
+            line: 0,
+            column: self.column,
+            mappings: Vec::new(),
+            pending_semicolon: false,
+            pending_space: false,
+            flat: true,
+            last: self.last,
+        };
+        if f(&mut w).is_err() {
+            return false;
         }
+        let line = buffer.split(|byte| *byte == b'\n').next().unwrap_or(&[]);
+        let width = String::from_utf8_lossy(line).encode_utf16().count();
+        self.column as usize + width <= self.options.max_line_width
+    }
+
+    /// Writes code we held back until we knew what comes after it. A mapping at our current
+    /// position is for the code which comes after so we move it.
+    fn write_pending(&mut self, pending: &[u8]) -> io::Result<()> {
+        self.w.write_all(pending)?;
+        if let Some(&last) = pending.last() {
+            self.last = last;
+        }
+        match self.mappings.last_mut() {
+            Some(last)
+                if last.generated_line == self.line && last.generated_column == self.column =>
+            {
+                last.generated_column += pending.len() as u32;
+            }
+            _ => {}
+        }
+        self.column += pending.len() as u32;
         Ok(())
     }
 }
 
-/// Writes some spaces at the specified indentation level.
-fn write_indentation<W: io::Write>(w: &mut W, i: usize) -> io::Result<()> {
+impl<'a> io::Write for Writer<'a> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Write our pending semicolon unless we are closing a block where we don’t need it. Write
+        // our pending space if we would otherwise join a keyword with the word after it.
+        if let Some(&next) = buf.first() {
+            if self.pending_semicolon {
+                self.pending_semicolon = false;
+                if next != b'}' {
+                    self.write_pending(b";")?;
+                }
+            }
+            if self.pending_space {
+                self.pending_space = false;
+                if next.is_ascii_alphanumeric() || next == b'_' || next == b'$' || next >= 0x80 {
+                    self.write_pending(b" ")?;
+                }
+            }
+            if (next == b'-' || next == b'+') && self.last == next {
+                self.write_pending(b" ")?;
+            }
+        }
+        let n = self.w.write(buf)?;
+        if let Some(&last) = buf[..n].last() {
+            self.last = last;
+        }
+        for &byte in &buf[..n] {
+            if byte == b'\n' {
+                self.line += 1;
+                self.column = 0;
+            } else if byte & 0b1100_0000 != 0b1000_0000 {
+                // Every character starts with a byte that is not a continuation byte. A character
+                // of four UTF-8 bytes is two UTF-16 code units.
+                self.column += if byte >= 0b1111_0000 { 2 } else { 1 };
+            }
+        }
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.w.flush()
+    }
+}
+
+/// Writes our indentation string once for every level of indentation. Minified code is
+/// not indented.
+fn write_indentation(w: &mut Writer, i: usize) -> io::Result<()> {
+    if w.options.minify {
+        return Ok(());
+    }
+    let options = w.options;
     for _ in 0..i {
-        write!(w, "  ")?;
+        write!(w, "{}", options.indentation)?;
     }
     Ok(())
 }
@@ -505,6 +1865,7 @@ lazy_static! {
         set.insert("Date");
         set.insert("Math");
         set.insert("Promise");
+        set.insert("Error");
         set.insert("document");
         set.insert("window");
         set.insert("global");
@@ -1,5 +1,9 @@
 mod compiler;
+mod dts;
 mod js;
+mod source_map;
 
 pub use self::compiler::*;
-pub use self::js::Program;
+pub use self::dts::write_type_declarations;
+pub use self::js::{JsWriterOptions, Program, Semicolons};
+pub use self::source_map::{SourceMap, SourceMapOptions};
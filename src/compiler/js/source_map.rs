@@ -0,0 +1,171 @@
+//! Source maps link the JavaScript code we generate back to the Brite code it was compiled from so
+//! that stack traces and debuggers point at Brite code. We generate version 3 source maps as
+//! specified here:
+//!
+//! https://sourcemaps.info/spec.html
+
+use crate::parser::{Document, Position};
+use std::io;
+
+/// Options for the source map we generate alongside a JavaScript program.
+pub struct SourceMapOptions {
+    /// The name of the JavaScript file our program is written to.
+    pub file: String,
+    /// The name of the Brite file our program was compiled from. Relative to the directory of the
+    /// JavaScript file.
+    pub source: String,
+    /// Should we embed the source code of our Brite file in our source map? Tools won’t need to
+    /// find the Brite file to show it when it is embedded, but our source map gets bigger.
+    pub sources_content: bool,
+}
+
+/// A source map for a JavaScript program compiled from a single Brite document. Write it next to
+/// the program with a file name of `{file}.map`.
+pub struct SourceMap {
+    file: String,
+    source: String,
+    source_content: Option<String>,
+    mappings: String,
+}
+
+/// Links the position of some JavaScript code we wrote to the position of the Brite code it was
+/// compiled from. Lines and columns are zero-based. Columns are measured in UTF-16 code units like
+/// JavaScript strings.
+pub(super) struct Mapping {
+    pub(super) generated_line: u32,
+    pub(super) generated_column: u32,
+    pub(super) original: Position,
+}
+
+impl SourceMap {
+    /// Creates a new source map from the mappings we recorded while writing our program. Our
+    /// mappings must be sorted by their generated position.
+    pub(super) fn new(
+        document: &Document,
+        options: SourceMapOptions,
+        mappings: &[Mapping],
+    ) -> Self {
+        // Every line of generated code is separated by `;` and every segment in a line is
+        // separated by `,`. The generated column of a segment is relative to the previous segment
+        // in the same line. Everything else is relative to the previous segment in our map.
+        let mut encoded = String::new();
+        let mut line = 0;
+        let mut previous_generated_column = 0;
+        let mut previous_original_line = 0;
+        let mut previous_original_column = 0;
+        for (k, mapping) in mappings.iter().enumerate() {
+            if mapping.generated_line > line {
+                while line < mapping.generated_line {
+                    encoded.push(';');
+                    line += 1;
+                }
+                previous_generated_column = 0;
+            } else if k > 0 {
+                encoded.push(',');
+            }
+            let original_line = mapping.original.line(document) as i64;
+            let original_column = mapping.original.character(document) as i64;
+            let generated_column = i64::from(mapping.generated_column);
+            encode_vlq(&mut encoded, generated_column - previous_generated_column);
+            // We only have one source so the index of our source is always zero.
+            encode_vlq(&mut encoded, 0);
+            encode_vlq(&mut encoded, original_line - previous_original_line);
+            encode_vlq(&mut encoded, original_column - previous_original_column);
+            previous_generated_column = generated_column;
+            previous_original_line = original_line;
+            previous_original_column = original_column;
+        }
+        SourceMap {
+            file: options.file,
+            source: options.source,
+            source_content: if options.sources_content {
+                Some(document.source().to_string())
+            } else {
+                None
+            },
+            mappings: encoded,
+        }
+    }
+
+    /// Writes our source map as JSON.
+    pub fn write<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        write!(w, "{{\"version\":3,\"file\":")?;
+        write_json_string(w, &self.file)?;
+        write!(w, ",\"sources\":[")?;
+        write_json_string(w, &self.source)?;
+        write!(w, "]")?;
+        if let Some(source_content) = &self.source_content {
+            write!(w, ",\"sourcesContent\":[")?;
+            write_json_string(w, source_content)?;
+            write!(w, "]")?;
+        }
+        write!(w, ",\"names\":[],\"mappings\":")?;
+        write_json_string(w, &self.mappings)?;
+        writeln!(w, "}}")
+    }
+}
+
+/// The characters of the base 64 alphabet used to encode VLQ values.
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes a number as a base 64 variable length quantity. The lowest bit of our first digit is
+/// our sign and every digit has a continuation bit set if more digits follow.
+fn encode_vlq(encoded: &mut String, value: i64) {
+    let mut value = if value < 0 {
+        ((-value) << 1) | 1
+    } else {
+        value << 1
+    };
+    loop {
+        let mut digit = value & 0b11111;
+        value >>= 5;
+        if value > 0 {
+            digit |= 0b100000;
+        }
+        encoded.push(BASE64[digit as usize] as char);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+/// Writes a JSON string in quotes, escaping the characters JSON does not allow in a string.
+fn write_json_string<W: io::Write>(w: &mut W, value: &str) -> io::Result<()> {
+    write!(w, "\"")?;
+    for c in value.chars() {
+        match c {
+            '"' => write!(w, "\\\"")?,
+            '\\' => write!(w, "\\\\")?,
+            '\n' => write!(w, "\\n")?,
+            '\r' => write!(w, "\\r")?,
+            '\t' => write!(w, "\\t")?,
+            c if (c as u32) < 0x20 => write!(w, "\\u{:04x}", c as u32)?,
+            c => write!(w, "{}", c)?,
+        }
+    }
+    write!(w, "\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vlq(value: i64) -> String {
+        let mut encoded = String::new();
+        encode_vlq(&mut encoded, value);
+        encoded
+    }
+
+    #[test]
+    fn vlq_encoding() {
+        assert_eq!(vlq(0), "A");
+        assert_eq!(vlq(1), "C");
+        assert_eq!(vlq(-1), "D");
+        assert_eq!(vlq(15), "e");
+        assert_eq!(vlq(-15), "f");
+        assert_eq!(vlq(16), "gB");
+        assert_eq!(vlq(-16), "hB");
+        assert_eq!(vlq(123), "2H");
+        assert_eq!(vlq(1000), "w+B");
+    }
+}
@@ -68,7 +68,9 @@
 //! - [Grammarly](https://www.grammarly.com) for confirming your grammar is correct.
 //! - [Hemingway Editor](http://www.hemingwayapp.com) for reducing the complexity of your writing.
 
-use crate::language::{Constant, LogicalOperator, PrefixOperator};
+use crate::language::{
+    AssignmentOperator, AttributeTarget, Constant, InfixOperator, LogicalOperator, PrefixOperator,
+};
 use crate::parser::{Document, Glyph, Identifier, IdentifierKeyword, Position, Range, Token};
 use crate::utils::markup::{Markup, MarkupCode};
 use std::fmt::{self, Write};
@@ -87,8 +89,9 @@ use std::rc::Rc;
 pub struct Diagnostic {
     /// The range of our diagnostic.
     pub range: Range,
-    /// A representation of every possible diagnostic message.
-    message: DiagnosticMessage,
+    /// A representation of every possible diagnostic message. Some messages carry large snippets
+    /// so we box our message to keep diagnostics cheap to move around.
+    message: Box<DiagnosticMessage>,
 }
 
 /// The diagnostic message. Includes the severity of the message. Each diagnostic may have some
@@ -106,7 +109,6 @@ enum DiagnosticMessage {
     /// code which is technically correct but might be suboptimal. Warnings will not block
     /// deployment of a program by default. However, it is strongly recommended that warnings
     /// be fixed.
-    #[allow(dead_code)]
     Warning(WarningDiagnosticMessage),
     /// Useful information about a user’s program that does not need to be changed. Unlike a warning
     /// where we are recommending a code change.
@@ -123,6 +125,10 @@ enum ErrorDiagnosticMessage {
     },
     /// The parser ran into the end of the source document unexpectedly.
     UnexpectedEnding { expected: ExpectedSyntax },
+    /// A Unicode escape sequence in a string does not describe a Unicode character.
+    InvalidUnicodeEscape { code: u32 },
+    /// A string interpolation which is not closed before the end of its line.
+    UnclosedInterpolation,
     /// Could not find a declaration for an identifier.
     IdentifierNotFound { identifier: Identifier },
     /// A declaration with this name already exists.
@@ -135,11 +141,92 @@ enum ErrorDiagnosticMessage {
         identifier: Identifier,
         declaration_range: Range,
     },
+    /// Referenced a function before we know its type. We only know the type of a function before
+    /// we check it if all of its parameters and its return type are annotated.
+    FunctionTypeUnknown {
+        identifier: Identifier,
+        declaration_range: Range,
+    },
     /// Tried to extend a declaration which is not a base class.
     CanOnlyExtendBaseClass {
         identifier: Identifier,
         declaration_range: Range,
     },
+    /// Tried to match a declaration which is not a class in a class pattern.
+    CanOnlyMatchClass {
+        identifier: Identifier,
+        declaration_range: Range,
+    },
+    /// Could not find a field on a class.
+    FieldNotFound {
+        identifier: Identifier,
+        class: Identifier,
+        class_range: Range,
+    },
+    /// Tried to construct a declaration which is not a class.
+    CanOnlyConstructClass {
+        identifier: Identifier,
+        declaration_range: Range,
+    },
+    /// Tried to use a class as a value. We may only access the static methods of a class.
+    ClassAsValue {
+        identifier: Identifier,
+        declaration_range: Range,
+    },
+    /// Tried to construct a base class. Only the classes which extend a base class may
+    /// be constructed.
+    CannotConstructBaseClass {
+        identifier: Identifier,
+        class_range: Range,
+    },
+    /// We found a construct expression which does not provide a value for a field of its class.
+    MissingField {
+        operation: OperationSnippet,
+        field: Identifier,
+    },
+    /// A case expression does not have a case for some of the values it might test.
+    MissingCases {
+        test: ExpressionSnippet,
+        missing: VecSnippet<Identifier>,
+    },
+    /// A pattern which might not match was used where every value must match, like a
+    /// binding statement.
+    RefutablePattern { pattern: PatternSnippet },
+    /// A `break` statement which is not inside a loop.
+    BreakOutsideLoop,
+    /// A `continue` statement which is not inside a loop.
+    ContinueOutsideLoop,
+    /// A `return` statement which is not inside a function.
+    ReturnOutsideFunction,
+    /// Could not find a loop with this label around a `break` or `continue` statement.
+    LoopLabelNotFound { label: Identifier },
+    /// Tried to break out of a while loop with a value. Only `loop` expressions have a value.
+    WhileBreakValue,
+    /// An `await` expression which is not inside an `async` function.
+    AwaitOutsideAsync,
+    /// A `this` expression or pattern which is not inside a method with a `this` parameter.
+    ThisOutsideMethod,
+    /// Tried to assign to a name which was not declared with `let mut`.
+    AssignImmutable {
+        identifier: Identifier,
+        declaration_range: Range,
+    },
+    /// Tried to assign to an expression which is not a variable.
+    InvalidAssignmentTarget { target: ExpressionSnippet },
+    /// Could not find the module an import declaration imports from.
+    ModuleNotFound { path: String },
+    /// A module ends up importing itself through the modules it imports.
+    ImportCycleDetected { path: String, cycle: Vec<String> },
+    /// Could not find a declaration in the module we import from.
+    ExportNotFound {
+        identifier: Identifier,
+        path: String,
+    },
+    /// Tried to import a declaration which was not exported.
+    ImportNotExported {
+        identifier: Identifier,
+        path: String,
+    },
     /// We found two types that were incompatible with one another during subtyping.
     IncompatibleTypes {
         operation: OperationSnippet,
@@ -156,6 +243,29 @@ enum ErrorDiagnosticMessage {
         range2: Range,
         len2: usize,
     },
+    /// We found a call which does not provide an argument for a parameter without a default.
+    MissingArgument {
+        operation: OperationSnippet,
+        parameter: Identifier,
+    },
+    /// We found a named argument which is not the name of any parameter.
+    UnknownArgument {
+        operation: OperationSnippet,
+        argument: Identifier,
+    },
+    /// We found a named argument for a parameter which already has an argument.
+    DuplicateArgument {
+        operation: OperationSnippet,
+        argument: Identifier,
+    },
+    /// We found a different number of type arguments than the number of type parameters we need.
+    IncompatibleTypeArgumentLengths {
+        operation: OperationSnippet,
+        range1: Range,
+        len1: usize,
+        range2: Range,
+        len2: usize,
+    },
     /// We found a function parameter that needs a type annotation since we can’t infer one.
     MissingFunctionParameterType { pattern: PatternSnippet },
     /// We found the programmer trying to call a type that is not a function.
@@ -163,10 +273,62 @@ enum ErrorDiagnosticMessage {
         callee_range: Range,
         callee_type: TypeKindSnippet,
     },
+    /// We found the programmer trying to interpolate a type into a string which can’t be printed.
+    CannotPrint {
+        expression_range: Range,
+        expression_type: TypeKindSnippet,
+    },
+    /// We found the programmer trying to access a member of a type which has no members. Like an
+    /// optional type which might be `null`.
+    CannotAccessMember {
+        property: Identifier,
+        object_range: Range,
+        object_type: TypeKindSnippet,
+    },
+    /// We found the programmer trying to index a type which is not a list.
+    CannotIndex {
+        object_range: Range,
+        object_type: TypeKindSnippet,
+    },
+    /// We found the programmer trying to await a type which is not a promise.
+    CannotAwait {
+        operand_range: Range,
+        operand_type: TypeKindSnippet,
+    },
+    /// We found a comparison chain which compares in both directions. Like `a < b > c`.
+    InconsistentComparison {
+        first: InfixOperator,
+        second: InfixOperator,
+    },
+    /// We found an attribute with a name we don’t know of.
+    UnknownAttribute { name: Identifier },
+    /// We found an attribute before a declaration or class member it may not be used with.
+    UnexpectedAttribute {
+        name: Identifier,
+        target: AttributeTarget,
+    },
+    /// We found an attribute with more arguments than it accepts.
+    IncompatibleAttributeArguments {
+        name: Identifier,
+        len: usize,
+        max: usize,
+    },
+    /// We found the same attribute written twice before one declaration or class member.
+    DuplicateAttribute { name: Identifier },
+    /// We found an `@outline` function which does not return a promise. Loading an outlined
+    /// function is asynchronous so it must return a promise.
+    OutlineWithoutPromise {
+        function: Identifier,
+        return_range: Range,
+        return_type: TypeKindSnippet,
+    },
 }
 
 #[derive(Debug)]
-enum WarningDiagnosticMessage {}
+enum WarningDiagnosticMessage {
+    /// Every value a case matches is already matched by the cases before it.
+    RedundantCase { pattern: PatternSnippet },
+}
 
 #[derive(Debug)]
 enum InfoDiagnosticMessage {}
@@ -180,6 +342,8 @@ pub enum UnexpectedSyntax {
     Identifier,
     /// An unexpected number.
     Number,
+    /// An unexpected string.
+    String,
     /// An unexpected character.
     Char(char),
 }
@@ -201,6 +365,12 @@ pub enum ExpectedSyntax {
     BinaryDigit,
     /// Expected a hexadecimal digit.
     HexadecimalDigit,
+    /// Expected the end of a string.
+    StringEnd,
+    /// Expected an escape sequence in a string.
+    EscapeSequence,
+    /// Expected a string.
+    String,
     /// Expected a declaration.
     Declaration,
     /// Expected a class member.
@@ -226,6 +396,36 @@ pub enum OperationSnippet {
     FunctionReturnAnnotation(Option<StatementSnippet>),
     /// Calling a function failed to type check.
     FunctionCall(ExpressionSnippet),
+    /// Piping a value into a function failed to type check.
+    Pipe(ExpressionSnippet, ExpressionSnippet),
+    /// The default value of a function parameter failed to type check.
+    FunctionParameterDefault(PatternSnippet, ExpressionSnippet),
+    /// An argument of an attribute failed to type check.
+    AttributeArgument(Identifier, ExpressionSnippet),
+    /// Referencing a type failed to type check.
+    TypeReference(Identifier),
+    /// Matching a class pattern failed to type check.
+    ClassPattern(Identifier),
+    /// Constructing an instance of a class failed to type check.
+    Construct(Identifier),
+    /// Returning a value from an arm of a case expression failed to type check.
+    CaseArm(ExpressionSnippet),
+    /// The test of a while loop failed to type check.
+    WhileTest(ExpressionSnippet),
+    /// The test of a conditional expression failed to type check.
+    ConditionalTest(ExpressionSnippet),
+    /// Returning a value from the else branch of a conditional expression failed to type check.
+    ConditionalAlternate,
+    /// Putting a value in a list with other values failed to type check.
+    ListElement(ExpressionSnippet),
+    /// Indexing a list failed to type check.
+    ListIndex(ExpressionSnippet, ExpressionSnippet),
+    /// Breaking out of a loop, maybe with a value, failed to type check.
+    LoopBreak(Option<ExpressionSnippet>),
+    /// Returning from a function, maybe with a value, failed to type check.
+    FunctionReturn(Option<ExpressionSnippet>),
+    /// Assigning a new value to a variable failed to type check.
+    Assignment(ExpressionSnippet, ExpressionSnippet),
     /// Some use of an operator failed to type check.
     OperatorExpression(OperatorSnippet),
 }
@@ -239,6 +439,20 @@ pub enum OperatorSnippet {
     And,
     /// `||`
     Or,
+    /// `==`
+    Equals,
+    /// `!=`
+    NotEquals,
+    /// `-` before an operand.
+    Negative,
+    /// `+` before an operand.
+    Positive,
+    /// `~`
+    BitwiseNot,
+    /// Some other infix operator like `<` or `&`.
+    Infix(InfixOperator),
+    /// A compound assignment operator like `+=`.
+    Assignment(AssignmentOperator),
 }
 
 /// A snippet of some type for error message printing.
@@ -256,8 +470,22 @@ pub enum TypeKindSnippet {
     Integer,
     /// The float type.
     Float,
+    /// The string type.
+    String,
+    /// The null type.
+    Null,
+    /// An optional type.
+    Optional(Box<TypeKindSnippet>),
+    /// A list type.
+    List,
+    /// A promise type.
+    Promise,
     /// A function type.
     Function,
+    /// A type parameter of some generic function or class.
+    Parameter(Identifier),
+    /// An instance of some class.
+    Class(Identifier),
 }
 
 /// A snippet of a [`Vec`]. May contain up to 2 items.
@@ -298,6 +526,18 @@ pub enum StatementSnippet {
     Expression(ExpressionSnippet),
     // Some statement that binds a pattern in our current scope.
     Binding(PatternSnippet, ExpressionSnippet),
+    /// Some statement that assigns a new value to a variable.
+    Assignment(
+        ExpressionSnippet,
+        Option<AssignmentOperator>,
+        ExpressionSnippet,
+    ),
+    /// Some statement that exits a loop. We don’t remember the label.
+    Break(Option<ExpressionSnippet>),
+    /// Some statement that continues a loop. We don’t remember the label.
+    Continue,
+    /// Some statement that returns from a function.
+    Return(Option<ExpressionSnippet>),
 }
 
 /// A snippet of some expression for error message printing. We try to keep the snippet small. A
@@ -308,21 +548,58 @@ pub enum ExpressionSnippet {
     Constant(Constant),
     /// A reference to some value in the program.
     Reference(Identifier),
+    /// A reference to the instance a method was called on.
+    This,
     /// A function expression. We only keep some of the parameters in the function
     /// expression snippet.
     Function(VecSnippet<PatternSnippet>),
     /// A call expression. We only remember the callee.
     Call(Box<ExpressionSnippet>),
+    /// A construct expression. We only remember the class.
+    Construct(Identifier),
+    /// A member expression.
+    Member(Box<ExpressionSnippet>, Identifier),
+    /// A list expression.
+    List,
+    /// An index expression.
+    Index(Box<ExpressionSnippet>, Box<ExpressionSnippet>),
+    /// A conditional expression. We only remember the test expression of the first branch.
+    Conditional(Box<ExpressionSnippet>),
+    /// A case expression. We only remember the test expression.
+    Case(Box<ExpressionSnippet>),
+    /// A while expression. We only remember the test expression.
+    While(Box<ExpressionSnippet>),
+    /// A loop expression.
+    Loop,
     /// Some expression using a prefix operator.
     Prefix(PrefixOperator, Box<ExpressionSnippet>),
+    /// An await expression.
+    Await(Box<ExpressionSnippet>),
+    /// Some expression using an infix operator.
+    Infix(
+        Box<ExpressionSnippet>,
+        InfixOperator,
+        Box<ExpressionSnippet>,
+    ),
+    /// A chain of relational comparisons.
+    Comparison(
+        Box<ExpressionSnippet>,
+        Vec<(InfixOperator, ExpressionSnippet)>,
+    ),
     /// Some infix expression using a logical operator.
     Logical(
         Box<ExpressionSnippet>,
         LogicalOperator,
         Box<ExpressionSnippet>,
     ),
+    /// Some pipe expression.
+    Pipe(Box<ExpressionSnippet>, Box<ExpressionSnippet>),
     /// A block expression which contains some statements.
     Block,
+    /// A string with expressions interpolated into it.
+    Template,
+    /// Some expression we could not parse.
+    Error,
 }
 
 /// A snippet of some pattern for error message printing.
@@ -330,17 +607,30 @@ pub enum ExpressionSnippet {
 pub enum PatternSnippet {
     /// A binding for some value in the program.
     Binding(Identifier),
+    /// A pattern which binds nothing.
+    Hole,
+    /// A class pattern. We only remember the class.
+    Class(Identifier),
+    /// Some pattern we could not parse.
+    Error,
 }
 
 impl Diagnostic {
     fn new(range: Range, message: DiagnosticMessage) -> Self {
-        Diagnostic { range, message }
+        Diagnostic {
+            range,
+            message: Box::new(message),
+        }
     }
 
     fn error(range: Range, message: ErrorDiagnosticMessage) -> Self {
         Self::new(range, DiagnosticMessage::Error(message))
     }
 
+    fn warning(range: Range, message: WarningDiagnosticMessage) -> Self {
+        Self::new(range, DiagnosticMessage::Warning(message))
+    }
+
     /// The parser ran into syntax it did not recognize.
     pub fn unexpected_syntax(
         range: Range,
@@ -378,6 +668,17 @@ impl Diagnostic {
         )
     }
 
+    /// A Unicode escape sequence in a string does not describe a Unicode character.
+    pub fn invalid_unicode_escape(range: Range, code: u32) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::InvalidUnicodeEscape { code })
+    }
+
+    /// A string interpolation which is not closed before the end of its line. Our range is the
+    /// `${` which opened the interpolation.
+    pub fn unclosed_interpolation(range: Range) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::UnclosedInterpolation)
+    }
+
     /// Could not find a declaration for an identifier.
     pub fn identifier_not_found(range: Range, identifier: Identifier) -> Self {
         Self::error(
@@ -421,6 +722,24 @@ impl Diagnostic {
         )
     }
 
+    /// Referenced a function before we know its type.
+    ///
+    /// The first range is the range of the reference. The second range is the range of the
+    /// function declaration.
+    pub fn function_type_unknown(
+        range: Range,
+        identifier: Identifier,
+        declaration_range: Range,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::FunctionTypeUnknown {
+                identifier,
+                declaration_range,
+            },
+        )
+    }
+
     /// Tried to extend a declaration which is not a base class.
     ///
     /// The first range is the range of the bad extends name. The second range is the range of the
@@ -439,6 +758,218 @@ impl Diagnostic {
         )
     }
 
+    /// Tried to match a declaration which is not a class in a class pattern.
+    ///
+    /// The first range is the range of the class name in our pattern. The second range is the
+    /// range of the declaration that is not a class.
+    pub fn can_only_match_class(
+        range: Range,
+        identifier: Identifier,
+        declaration_range: Range,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::CanOnlyMatchClass {
+                identifier,
+                declaration_range,
+            },
+        )
+    }
+
+    /// Could not find a field on a class.
+    ///
+    /// The first range is the range of the field name. The second range is the range of the
+    /// class declaration.
+    pub fn field_not_found(
+        range: Range,
+        identifier: Identifier,
+        class: Identifier,
+        class_range: Range,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::FieldNotFound {
+                identifier,
+                class,
+                class_range,
+            },
+        )
+    }
+
+    /// Tried to construct a declaration which is not a class.
+    ///
+    /// The first range is the range of the class name in our construct expression. The second
+    /// range is the range of the declaration that is not a class.
+    pub fn can_only_construct_class(
+        range: Range,
+        identifier: Identifier,
+        declaration_range: Range,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::CanOnlyConstructClass {
+                identifier,
+                declaration_range,
+            },
+        )
+    }
+
+    /// Tried to use a class as a value.
+    ///
+    /// The first range is the range of the class name in our expression. The second range is the
+    /// range of the class declaration.
+    pub fn class_as_value(range: Range, identifier: Identifier, declaration_range: Range) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::ClassAsValue {
+                identifier,
+                declaration_range,
+            },
+        )
+    }
+
+    /// Tried to construct a base class.
+    ///
+    /// The first range is the range of the class name in our construct expression. The second
+    /// range is the range of the base class declaration.
+    pub fn cannot_construct_base_class(
+        range: Range,
+        identifier: Identifier,
+        class_range: Range,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::CannotConstructBaseClass {
+                identifier,
+                class_range,
+            },
+        )
+    }
+
+    /// We found a construct expression which does not provide a value for a field of its class.
+    pub fn missing_field(range: Range, operation: OperationSnippet, field: Identifier) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::MissingField { operation, field },
+        )
+    }
+
+    /// A case expression does not have a case for some of the values it might test. If we are
+    /// missing no classes then we are missing a case which matches everything.
+    pub fn missing_cases(
+        range: Range,
+        test: ExpressionSnippet,
+        missing: VecSnippet<Identifier>,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::MissingCases { test, missing },
+        )
+    }
+
+    /// A pattern which might not match was used where every value must match, like a
+    /// binding statement.
+    pub fn refutable_pattern(range: Range, pattern: PatternSnippet) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::RefutablePattern { pattern })
+    }
+
+    /// A `break` statement which is not inside a loop.
+    pub fn break_outside_loop(range: Range) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::BreakOutsideLoop)
+    }
+
+    /// A `continue` statement which is not inside a loop.
+    pub fn continue_outside_loop(range: Range) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::ContinueOutsideLoop)
+    }
+
+    /// A `return` statement which is not inside a function.
+    pub fn return_outside_function(range: Range) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::ReturnOutsideFunction)
+    }
+
+    /// An `await` expression which is not inside an `async` function.
+    pub fn await_outside_async(range: Range) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::AwaitOutsideAsync)
+    }
+
+    /// A `this` expression or pattern which is not inside a method with a `this` parameter.
+    pub fn this_outside_method(range: Range) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::ThisOutsideMethod)
+    }
+
+    /// Could not find a loop with this label around a `break` or `continue` statement.
+    pub fn loop_label_not_found(range: Range, label: Identifier) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::LoopLabelNotFound { label })
+    }
+
+    /// Tried to break out of a while loop with a value. The range is the range of our value.
+    pub fn while_break_value(range: Range) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::WhileBreakValue)
+    }
+
+    /// Tried to assign to a name which was not declared with `let mut`.
+    ///
+    /// The first range is the range of the name we assign to. The second range is the range of
+    /// the name’s declaration.
+    pub fn assign_immutable(
+        range: Range,
+        identifier: Identifier,
+        declaration_range: Range,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::AssignImmutable {
+                identifier,
+                declaration_range,
+            },
+        )
+    }
+
+    /// Tried to assign to an expression which is not a variable.
+    pub fn invalid_assignment_target(range: Range, target: ExpressionSnippet) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::InvalidAssignmentTarget { target },
+        )
+    }
+
+    /// Could not find the module an import declaration imports from.
+    pub fn module_not_found(range: Range, path: String) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::ModuleNotFound { path })
+    }
+
+    /// A module ends up importing itself through the modules it imports. The range is the range
+    /// of the import path which closes the cycle. The cycle is the path of every module in the
+    /// cycle starting and ending with the module that closes it.
+    pub fn import_cycle_detected(range: Range, path: String, cycle: Vec<String>) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::ImportCycleDetected { path, cycle },
+        )
+    }
+
+    /// Could not find a declaration in the module we import from.
+    pub fn export_not_found(range: Range, identifier: Identifier, path: String) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::ExportNotFound { identifier, path },
+        )
+    }
+
+    /// Tried to import a declaration which was not exported.
+    pub fn import_not_exported(range: Range, identifier: Identifier, path: String) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::ImportNotExported { identifier, path },
+        )
+    }
+
+    /// Every value a case matches is already matched by the cases before it.
+    pub fn redundant_case(range: Range, pattern: PatternSnippet) -> Self {
+        Self::warning(range, WarningDiagnosticMessage::RedundantCase { pattern })
+    }
+
     /// We found two types that were incompatible with one another during subtyping.
     ///
     /// We will report the error at the first range. The second and third ranges will be used as
@@ -480,6 +1011,70 @@ impl Diagnostic {
         )
     }
 
+    /// We found a call which does not provide an argument for a parameter without a default.
+    pub fn missing_argument(
+        range: Range,
+        operation: OperationSnippet,
+        parameter: Identifier,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::MissingArgument {
+                operation,
+                parameter,
+            },
+        )
+    }
+
+    /// We found a named argument which is not the name of any parameter.
+    pub fn unknown_argument(
+        range: Range,
+        operation: OperationSnippet,
+        argument: Identifier,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::UnknownArgument {
+                operation,
+                argument,
+            },
+        )
+    }
+
+    /// We found a named argument for a parameter which already has an argument.
+    pub fn duplicate_argument(
+        range: Range,
+        operation: OperationSnippet,
+        argument: Identifier,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::DuplicateArgument {
+                operation,
+                argument,
+            },
+        )
+    }
+
+    /// We found a different number of type arguments than the number of type parameters we need.
+    pub fn incompatible_type_argument_lengths(
+        range: Range,
+        operation: OperationSnippet,
+        (range1, len1): (Range, usize),
+        (range2, len2): (Range, usize),
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::IncompatibleTypeArgumentLengths {
+                operation,
+                range1,
+                len1,
+                range2,
+                len2,
+            },
+        )
+    }
+
     /// We found a pattern that needs a type annotation since we can’t infer one.
     pub fn missing_function_parameter_type(range: Range, pattern: PatternSnippet) -> Self {
         Self::error(
@@ -498,31 +1093,151 @@ impl Diagnostic {
             },
         )
     }
-}
 
-/// Related information for a diagnostic in case the primary message was not enough. Most
-/// importantly, related information carries a location so we can point to source code which
-/// contributed to an error.
-///
-/// See related information in the [Language Server Protocol (LSP) Specification][1].
-///
-/// See an example of [related information rendered in VSCode][2].
-///
-/// [1]: https://microsoft.github.io/language-server-protocol/specification
-/// [2]: https://code.visualstudio.com/updates/v1_22#_related-information-in-errors-and-warnings
-struct DiagnosticRelatedInformation {
-    range: Range,
+    /// We found the programmer trying to interpolate a type into a string which can’t be printed.
+    pub fn cannot_print(
+        range: Range,
+        expression_range: Range,
+        expression_type: TypeKindSnippet,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::CannotPrint {
+                expression_range,
+                expression_type,
+            },
+        )
+    }
+
+    /// We found the programmer trying to access a member of a type which has no members.
+    pub fn cannot_access_member(
+        range: Range,
+        property: Identifier,
+        object_range: Range,
+        object_type: TypeKindSnippet,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::CannotAccessMember {
+                property,
+                object_range,
+                object_type,
+            },
+        )
+    }
+
+    /// We found the programmer trying to index a type which is not a list.
+    pub fn cannot_index(range: Range, object_range: Range, object_type: TypeKindSnippet) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::CannotIndex {
+                object_range,
+                object_type,
+            },
+        )
+    }
+
+    /// We found the programmer trying to await a type which is not a promise.
+    pub fn cannot_await(range: Range, operand_range: Range, operand_type: TypeKindSnippet) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::CannotAwait {
+                operand_range,
+                operand_type,
+            },
+        )
+    }
+
+    /// We found a comparison chain which compares in both directions.
+    pub fn inconsistent_comparison(
+        range: Range,
+        first: InfixOperator,
+        second: InfixOperator,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::InconsistentComparison { first, second },
+        )
+    }
+
+    /// We found an attribute with a name we don’t know of.
+    pub fn unknown_attribute(range: Range, name: Identifier) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::UnknownAttribute { name })
+    }
+
+    /// We found an attribute before a declaration or class member it may not be used with.
+    pub fn unexpected_attribute(range: Range, name: Identifier, target: AttributeTarget) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::UnexpectedAttribute { name, target },
+        )
+    }
+
+    /// We found an attribute with more arguments than it accepts.
+    pub fn incompatible_attribute_arguments(
+        range: Range,
+        name: Identifier,
+        len: usize,
+        max: usize,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::IncompatibleAttributeArguments { name, len, max },
+        )
+    }
+
+    /// We found the same attribute written twice before one declaration or class member.
+    pub fn duplicate_attribute(range: Range, name: Identifier) -> Self {
+        Self::error(range, ErrorDiagnosticMessage::DuplicateAttribute { name })
+    }
+
+    /// We found an `@outline` function which does not return a promise.
+    pub fn outline_without_promise(
+        range: Range,
+        function: Identifier,
+        return_range: Range,
+        return_type: TypeKindSnippet,
+    ) -> Self {
+        Self::error(
+            range,
+            ErrorDiagnosticMessage::OutlineWithoutPromise {
+                function,
+                return_range,
+                return_type,
+            },
+        )
+    }
+}
+
+/// Related information for a diagnostic in case the primary message was not enough. Most
+/// importantly, related information carries a location so we can point to source code which
+/// contributed to an error.
+///
+/// See related information in the [Language Server Protocol (LSP) Specification][1].
+///
+/// See an example of [related information rendered in VSCode][2].
+///
+/// [1]: https://microsoft.github.io/language-server-protocol/specification
+/// [2]: https://code.visualstudio.com/updates/v1_22#_related-information-in-errors-and-warnings
+struct DiagnosticRelatedInformation {
+    range: Range,
     message: Markup,
 }
 
 impl Diagnostic {
+    /// Creates a human readable diagnostic message as plain text without any formatting. Used where
+    /// we can’t display markup, like in the error thrown by a program which failed to type check.
+    pub fn simple_message(&self) -> String {
+        self.message().0.to_simple_string()
+    }
+
     /// Creates a human readable diagnostic message for a given diagnostic. Also may create some
     /// related information regarding the error. Remember that this generates a new message every
     /// time it is called instead of fetching a pre-generated message.
     fn message(&self) -> (Markup, Vec<DiagnosticRelatedInformation>) {
-        match &self.message {
+        match &*self.message {
             DiagnosticMessage::Error(message) => self.error_message(message).unwrap(),
-            DiagnosticMessage::Warning(_) => unreachable!(),
+            DiagnosticMessage::Warning(message) => self.warning_message(message).unwrap(),
             DiagnosticMessage::Info(_) => unreachable!(),
         }
     }
@@ -590,6 +1305,28 @@ impl Diagnostic {
                 Ok((message, Vec::new()))
             }
 
+            // The programmer wrote a Unicode escape sequence with valid syntax but the code point is
+            // a surrogate or is too large. We print the escape sequence back in uppercase instead of
+            // pointing at the source so that the programmer sees the number we read.
+            ErrorDiagnosticMessage::InvalidUnicodeEscape { code } => {
+                let mut message = Markup::new();
+                write!(message.code(), "\\u{{{:X}}}", code)?;
+                write!(message, " is not a Unicode character.")?;
+                Ok((message, Vec::new()))
+            }
+
+            // Strings may not span multiple lines so neither may an interpolation. We point at the
+            // `${` since the end of the line is not where the programmer needs to look.
+            ErrorDiagnosticMessage::UnclosedInterpolation => {
+                let mut message = Markup::new();
+                write!(message, "We want ")?;
+                write!(message.code(), "}}")?;
+                write!(message, " to close ")?;
+                write!(message.code(), "${{")?;
+                write!(message, " but the line ends.")?;
+                Ok((message, Vec::new()))
+            }
+
             // We tell the user directly that the name they were looking for is missing. “does not
             // exist” is a bit harsh. It might also be untrue from the user’s point of view. The
             // variable could exist in a different scope or with a small mis-spelling. Instead we
@@ -659,6 +1396,31 @@ impl Diagnostic {
                 Ok((message, related_information))
             }
 
+            // Tell the programmer we need annotations to use the function here. Point to the
+            // function declaration so the programmer knows where to add them.
+            ErrorDiagnosticMessage::FunctionTypeUnknown {
+                identifier,
+                declaration_range,
+            } => {
+                let mut message = Markup::new();
+                write!(message, "Can not use ")?;
+                write!(message.code(), "{}", identifier.as_str())?;
+                write!(
+                    message,
+                    " here because we need the types of its parameters and its return type."
+                )?;
+                let mut related_information = Vec::new();
+                {
+                    let mut message = Markup::new();
+                    write!(message.code(), "{}", identifier.as_str())?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *declaration_range,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+
             // Tell the programmer they can’t extend the declaration because it is not a base class.
             // Make sure we point to the declaration in related information so the programmer can
             // see that, indeed, the declaration that is not a base class.
@@ -682,6 +1444,318 @@ impl Diagnostic {
                 Ok((message, related_information))
             }
 
+            // Follows the same format as our error for extending a declaration which is not a
+            // base class.
+            ErrorDiagnosticMessage::CanOnlyMatchClass {
+                identifier,
+                declaration_range,
+            } => {
+                let mut message = Markup::new();
+                write!(message, "Can not match ")?;
+                write!(message.code(), "{}", identifier.as_str())?;
+                write!(message, " because it is not a class.")?;
+                let mut related_information = Vec::new();
+                {
+                    let mut message = Markup::new();
+                    write!(message.code(), "{}", identifier.as_str())?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *declaration_range,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+
+            // Follows the same format as our error for a name we can not find. We point to the
+            // class in related information so the programmer can look at the fields it does have.
+            ErrorDiagnosticMessage::FieldNotFound {
+                identifier,
+                class,
+                class_range,
+            } => {
+                let mut message = Markup::new();
+                write!(message, "Can not find ")?;
+                write!(message.code(), "{}", identifier.as_str())?;
+                write!(message, " in ")?;
+                write!(message.code(), "{}", class.as_str())?;
+                write!(message, ".")?;
+                let mut related_information = Vec::new();
+                {
+                    let mut message = Markup::new();
+                    write!(message.code(), "{}", class.as_str())?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *class_range,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+
+            // Follows the same format as our error for matching a declaration which is not
+            // a class.
+            ErrorDiagnosticMessage::CanOnlyConstructClass {
+                identifier,
+                declaration_range,
+            } => {
+                let mut message = Markup::new();
+                write!(message, "Can not construct ")?;
+                write!(message.code(), "{}", identifier.as_str())?;
+                write!(message, " because it is not a class.")?;
+                let mut related_information = Vec::new();
+                {
+                    let mut message = Markup::new();
+                    write!(message.code(), "{}", identifier.as_str())?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *declaration_range,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+
+            // Tell the programmer what they may do with a class instead. We point to the class in
+            // related information so the programmer can look at its static methods.
+            ErrorDiagnosticMessage::ClassAsValue {
+                identifier,
+                declaration_range,
+            } => {
+                let mut message = Markup::new();
+                write!(message, "Can not use ")?;
+                write!(message.code(), "{}", identifier.as_str())?;
+                write!(
+                    message,
+                    " as a value because it is a class. Try calling one of its "
+                )?;
+                write!(message, "static methods.")?;
+                let mut related_information = Vec::new();
+                {
+                    let mut message = Markup::new();
+                    write!(message.code(), "{}", identifier.as_str())?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *declaration_range,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+
+            // We point to the base class in related information so the programmer can see that it
+            // was declared with `base`.
+            ErrorDiagnosticMessage::CannotConstructBaseClass {
+                identifier,
+                class_range,
+            } => {
+                let mut message = Markup::new();
+                write!(message, "Can not construct ")?;
+                write!(message.code(), "{}", identifier.as_str())?;
+                write!(message, " because it is a base class.")?;
+                let mut related_information = Vec::new();
+                {
+                    let mut message = Markup::new();
+                    write!(message.code(), "{}", identifier.as_str())?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *class_range,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+
+            // Follows the same format as our error for a call which is missing an argument.
+            ErrorDiagnosticMessage::MissingField { operation, field } => {
+                let mut message = Markup::new();
+                operation.print(&mut message)?;
+                write!(message, " because we need a value for ")?;
+                write!(message.code(), "{}", field.as_str())?;
+                write!(message, ".")?;
+                Ok((message, Vec::new()))
+            }
+
+            // We list the first couple of classes the programmer needs to add a case for. If there
+            // are more we trust the programmer to find them after adding the first few. When the
+            // test is not an instance of a base class no list of classes will ever be enough so we
+            // ask for a case which matches everything instead.
+            ErrorDiagnosticMessage::MissingCases { test, missing } => {
+                let mut message = Markup::new();
+                write!(message, "Can not match ")?;
+                test.print(&mut message.code())?;
+                write!(message, " because we need a case for ")?;
+                match missing {
+                    VecSnippet::Vec0 => write!(message.code(), "_")?,
+                    VecSnippet::Vec1(class) => write!(message.code(), "{}", class.as_str())?,
+                    VecSnippet::Vec2(class1, class2) => {
+                        write!(message.code(), "{}", class1.as_str())?;
+                        write!(message, " and ")?;
+                        write!(message.code(), "{}", class2.as_str())?;
+                    }
+                    VecSnippet::VecN(class1, class2) => {
+                        write!(message.code(), "{}", class1.as_str())?;
+                        write!(message, ", ")?;
+                        write!(message.code(), "{}", class2.as_str())?;
+                        write!(message, ", and more")?;
+                    }
+                }
+                write!(message, ".")?;
+                Ok((message, Vec::new()))
+            }
+
+            // Point the programmer to case expressions which are the way to match a value against a
+            // pattern which might fail.
+            ErrorDiagnosticMessage::RefutablePattern { pattern } => {
+                let mut message = Markup::new();
+                write!(message, "Can not use ")?;
+                pattern.print(&mut message.code())?;
+                write!(message, " here because it might not match. Try a ")?;
+                write!(message.code(), "case")?;
+                write!(message, " instead.")?;
+                Ok((message, Vec::new()))
+            }
+
+            ErrorDiagnosticMessage::BreakOutsideLoop => {
+                let mut message = Markup::new();
+                write!(message, "Can not ")?;
+                write!(message.code(), "break")?;
+                write!(message, " outside of a loop.")?;
+                Ok((message, Vec::new()))
+            }
+
+            ErrorDiagnosticMessage::ContinueOutsideLoop => {
+                let mut message = Markup::new();
+                write!(message, "Can not ")?;
+                write!(message.code(), "continue")?;
+                write!(message, " outside of a loop.")?;
+                Ok((message, Vec::new()))
+            }
+
+            ErrorDiagnosticMessage::ReturnOutsideFunction => {
+                let mut message = Markup::new();
+                write!(message, "Can not ")?;
+                write!(message.code(), "return")?;
+                write!(message, " outside of a function.")?;
+                Ok((message, Vec::new()))
+            }
+
+            ErrorDiagnosticMessage::AwaitOutsideAsync => {
+                let mut message = Markup::new();
+                write!(message, "Can not ")?;
+                write!(message.code(), "await")?;
+                write!(message, " outside of an ")?;
+                write!(message.code(), "async")?;
+                write!(message, " function.")?;
+                Ok((message, Vec::new()))
+            }
+
+            ErrorDiagnosticMessage::ThisOutsideMethod => {
+                let mut message = Markup::new();
+                write!(message, "Can not use ")?;
+                write!(message.code(), "this")?;
+                write!(message, " outside of a method.")?;
+                Ok((message, Vec::new()))
+            }
+
+            ErrorDiagnosticMessage::LoopLabelNotFound { label } => {
+                let mut message = Markup::new();
+                write!(message, "Can not find a loop named ")?;
+                write!(message.code(), "{}", label.as_str())?;
+                write!(message, ".")?;
+                Ok((message, Vec::new()))
+            }
+
+            // Programmers coming from other languages might expect `while` to have a value. Tell
+            // them which loop does.
+            ErrorDiagnosticMessage::WhileBreakValue => {
+                let mut message = Markup::new();
+                write!(message, "Can not ")?;
+                write!(message.code(), "break")?;
+                write!(message, " out of ")?;
+                write!(message.code(), "while")?;
+                write!(message, " with a value. Only ")?;
+                write!(message.code(), "loop")?;
+                write!(message, " has a value.")?;
+                Ok((message, Vec::new()))
+            }
+
+            // Tell the programmer how to make their variable mutable and point them to where the
+            // variable was declared.
+            ErrorDiagnosticMessage::AssignImmutable {
+                identifier,
+                declaration_range,
+            } => {
+                let mut message = Markup::new();
+                write!(message, "Can not assign to ")?;
+                write!(message.code(), "{}", identifier.as_str())?;
+                write!(
+                    message,
+                    " because it is not mutable. Try declaring it with "
+                )?;
+                write!(message.code(), "let mut")?;
+                write!(message, ".")?;
+                let mut related_information = Vec::new();
+                {
+                    let mut message = Markup::new();
+                    write!(message.code(), "{}", identifier.as_str())?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *declaration_range,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+
+            ErrorDiagnosticMessage::InvalidAssignmentTarget { target } => {
+                let mut message = Markup::new();
+                write!(message, "Can not assign to ")?;
+                target.print(&mut message.code())?;
+                write!(message, " because it is not a variable.")?;
+                Ok((message, Vec::new()))
+            }
+
+            ErrorDiagnosticMessage::ModuleNotFound { path } => {
+                let mut message = Markup::new();
+                write!(message, "Can not find module ")?;
+                write!(message.code(), "{}", path)?;
+                write!(message, ".")?;
+                Ok((message, Vec::new()))
+            }
+
+            // The modules in an import cycle may live in many files. We don’t point at all of them.
+            // Only at the import which closes the cycle.
+            ErrorDiagnosticMessage::ImportCycleDetected { path, cycle } => {
+                let mut message = Markup::new();
+                write!(message, "Can not import ")?;
+                write!(message.code(), "{}", path)?;
+                write!(
+                    message,
+                    " because the modules import one another in a cycle: "
+                )?;
+                write!(message.code(), "{}", cycle.join(" → "))?;
+                write!(message, ".")?;
+                Ok((message, Vec::new()))
+            }
+
+            ErrorDiagnosticMessage::ExportNotFound { identifier, path } => {
+                let mut message = Markup::new();
+                write!(message, "Can not find ")?;
+                write!(message.code(), "{}", identifier.as_str())?;
+                write!(message, " in ")?;
+                write!(message.code(), "{}", path)?;
+                write!(message, ".")?;
+                Ok((message, Vec::new()))
+            }
+
+            // Tell the programmer how to export their declaration.
+            ErrorDiagnosticMessage::ImportNotExported { identifier, path } => {
+                let mut message = Markup::new();
+                write!(message, "Can not import ")?;
+                write!(message.code(), "{}", identifier.as_str())?;
+                write!(message, " because it is not exported from ")?;
+                write!(message.code(), "{}", path)?;
+                write!(message, ". Try declaring it with ")?;
+                write!(message.code(), "export")?;
+                write!(message, ".")?;
+                Ok((message, Vec::new()))
+            }
+
             // Add a special case for `FunctionReturnAnnotation(None)` since the error message which
             // is generated by default isn’t great.
             ErrorDiagnosticMessage::IncompatibleTypes {
@@ -747,7 +1821,56 @@ impl Diagnostic {
                 let mut related_information = Vec::new();
                 if !self.range.intersects(*range1) {
                     let mut message = Markup::new();
-                    snippet1.print(&mut message, false)?;
+                    snippet1.print(&mut message, false)?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *range1,
+                        message,
+                    });
+                }
+                if !self.range.intersects(*range2) {
+                    let mut message = Markup::new();
+                    snippet2.print(&mut message, false)?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *range2,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+
+            // We tell the programmer we can not perform their operation because we don’t have the
+            // right number of arguments. In related information we then point them to the
+            // function we found and the function we expect.
+            //
+            // - We use the word “argument” over “parameter” since “argument” is more common. We
+            //   should always use “argument” over “parameter” in our error messages to
+            //   be consistent.
+            // - We don’t print out the two types since they would be really big. We trust the
+            //   programmer to look them up in their IDE if necessary. We do point to the two
+            //   functions in related information, though, so that the programmer has easy access
+            //   to them.
+            ErrorDiagnosticMessage::IncompatibleFunctionParameterLengths {
+                operation,
+                range1,
+                len1,
+                range2,
+                len2,
+            } => {
+                let mut message = Markup::new();
+                operation.print(&mut message)?;
+                write!(message, " because we have ")?;
+                argument_len(&mut message, *len1, true)?;
+                if len1 < len2 {
+                    write!(message, " but we need ")?;
+                } else {
+                    write!(message, " but we only need ")?;
+                }
+                argument_len(&mut message, *len2, false)?;
+                write!(message, ".")?;
+                let mut related_information = Vec::new();
+                if !self.range.intersects(*range1) {
+                    let mut message = Markup::new();
+                    argument_len(&mut message, *len1, true)?;
                     related_information.push(DiagnosticRelatedInformation {
                         range: *range1,
                         message,
@@ -755,27 +1878,79 @@ impl Diagnostic {
                 }
                 if !self.range.intersects(*range2) {
                     let mut message = Markup::new();
-                    snippet2.print(&mut message, false)?;
+                    argument_len(&mut message, *len2, true)?;
                     related_information.push(DiagnosticRelatedInformation {
                         range: *range2,
                         message,
                     });
                 }
+
+                fn argument_len(
+                    message: &mut Markup,
+                    len: usize,
+                    unit: bool,
+                ) -> Result<(), fmt::Error> {
+                    if let Some(len) = cardinal(len) {
+                        write!(message, "{}", len)?;
+                    } else {
+                        write!(message, "{}", len)?;
+                    }
+                    if unit {
+                        if len == 1 {
+                            write!(message, " argument")?;
+                        } else {
+                            write!(message, " arguments")?;
+                        }
+                    }
+                    Ok(())
+                }
+
                 Ok((message, related_information))
             }
 
-            // We tell the programmer we can not perform their operation because we don’t have the
-            // right number of arguments. In related information we then point them to the
-            // function we found and the function we expect.
-            //
-            // - We use the word “argument” over “parameter” since “argument” is more common. We
-            //   should always use “argument” over “parameter” in our error messages to
-            //   be consistent.
-            // - We don’t print out the two types since they would be really big. We trust the
-            //   programmer to look them up in their IDE if necessary. We do point to the two
-            //   functions in related information, though, so that the programmer has easy access
-            //   to them.
-            ErrorDiagnosticMessage::IncompatibleFunctionParameterLengths {
+            // Follows the same format as our error for functions with the wrong number of
+            // arguments. We name the argument instead of counting arguments since with named
+            // arguments and defaults the count alone doesn’t tell the programmer what to fix.
+            ErrorDiagnosticMessage::MissingArgument {
+                operation,
+                parameter,
+            } => {
+                let mut message = Markup::new();
+                operation.print(&mut message)?;
+                write!(message, " because we need an argument for ")?;
+                write!(message.code(), "{}", parameter.as_str())?;
+                write!(message, ".")?;
+                Ok((message, Vec::new()))
+            }
+
+            ErrorDiagnosticMessage::UnknownArgument {
+                operation,
+                argument,
+            } => {
+                let mut message = Markup::new();
+                operation.print(&mut message)?;
+                write!(message, " because there is no argument named ")?;
+                write!(message.code(), "{}", argument.as_str())?;
+                write!(message, ".")?;
+                Ok((message, Vec::new()))
+            }
+
+            ErrorDiagnosticMessage::DuplicateArgument {
+                operation,
+                argument,
+            } => {
+                let mut message = Markup::new();
+                operation.print(&mut message)?;
+                write!(message, " because we already have an argument for ")?;
+                write!(message.code(), "{}", argument.as_str())?;
+                write!(message, ".")?;
+                Ok((message, Vec::new()))
+            }
+
+            // Follows the same format as our error for functions with the wrong number of
+            // arguments. The programmer must write type arguments themselves so we don’t try to
+            // explain what a type parameter is.
+            ErrorDiagnosticMessage::IncompatibleTypeArgumentLengths {
                 operation,
                 range1,
                 len1,
@@ -785,18 +1960,18 @@ impl Diagnostic {
                 let mut message = Markup::new();
                 operation.print(&mut message)?;
                 write!(message, " because we have ")?;
-                argument_len(&mut message, *len1, true)?;
+                type_argument_len(&mut message, *len1, true)?;
                 if len1 < len2 {
                     write!(message, " but we need ")?;
                 } else {
                     write!(message, " but we only need ")?;
                 }
-                argument_len(&mut message, *len2, false)?;
+                type_argument_len(&mut message, *len2, false)?;
                 write!(message, ".")?;
                 let mut related_information = Vec::new();
                 if !self.range.intersects(*range1) {
                     let mut message = Markup::new();
-                    argument_len(&mut message, *len1, true)?;
+                    type_argument_len(&mut message, *len1, true)?;
                     related_information.push(DiagnosticRelatedInformation {
                         range: *range1,
                         message,
@@ -804,14 +1979,14 @@ impl Diagnostic {
                 }
                 if !self.range.intersects(*range2) {
                     let mut message = Markup::new();
-                    argument_len(&mut message, *len2, true)?;
+                    type_argument_len(&mut message, *len2, true)?;
                     related_information.push(DiagnosticRelatedInformation {
                         range: *range2,
                         message,
                     });
                 }
 
-                fn argument_len(
+                fn type_argument_len(
                     message: &mut Markup,
                     len: usize,
                     unit: bool,
@@ -823,9 +1998,9 @@ impl Diagnostic {
                     }
                     if unit {
                         if len == 1 {
-                            write!(message, " argument")?;
+                            write!(message, " type argument")?;
                         } else {
-                            write!(message, " arguments")?;
+                            write!(message, " type arguments")?;
                         }
                     }
                     Ok(())
@@ -866,6 +2041,238 @@ impl Diagnostic {
                 }
                 Ok((message, related_information))
             }
+
+            // Follows the same format as our error for calling something that is not a function.
+            // We say “print” instead of something like “convert to a string” since it is
+            // shorter and what the programmer will see is the value printed into their string.
+            ErrorDiagnosticMessage::CannotPrint {
+                expression_range,
+                expression_type,
+            } => {
+                let mut message = Markup::new();
+                write!(message, "Can not print ")?;
+                expression_type.print(&mut message, true)?;
+                write!(message, " in a string.")?;
+                let mut related_information = Vec::new();
+                if !self.range.intersects(*expression_range) {
+                    let mut message = Markup::new();
+                    expression_type.print(&mut message, false)?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *expression_range,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+
+            // Follows the same format as our error for calling something that is not a function.
+            // If our object might be `null` then we say so since the programmer probably forgot to
+            // check for `null` first.
+            ErrorDiagnosticMessage::CannotAccessMember {
+                property,
+                object_range,
+                object_type,
+            } => {
+                let mut message = Markup::new();
+                write!(message, "Can not access ")?;
+                write!(message.code(), "{}", property.as_str())?;
+                write!(message, " on ")?;
+                object_type.print(&mut message, true)?;
+                if let TypeKindSnippet::Optional(_) = object_type {
+                    write!(message, " because it might be ")?;
+                    write!(message.code(), "null")?;
+                }
+                write!(message, ".")?;
+                let mut related_information = Vec::new();
+                if !self.range.intersects(*object_range) {
+                    let mut message = Markup::new();
+                    object_type.print(&mut message, false)?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *object_range,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+
+            // Follows the same format as our error for accessing a member of a type which has
+            // no members.
+            ErrorDiagnosticMessage::CannotIndex {
+                object_range,
+                object_type,
+            } => {
+                let mut message = Markup::new();
+                write!(message, "Can not index ")?;
+                object_type.print(&mut message, true)?;
+                if let TypeKindSnippet::Optional(_) = object_type {
+                    write!(message, " because it might be ")?;
+                    write!(message.code(), "null")?;
+                }
+                write!(message, ".")?;
+                let mut related_information = Vec::new();
+                if !self.range.intersects(*object_range) {
+                    let mut message = Markup::new();
+                    object_type.print(&mut message, false)?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *object_range,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+
+            // Follows the same format as our error for indexing a type which is not a list.
+            ErrorDiagnosticMessage::CannotAwait {
+                operand_range,
+                operand_type,
+            } => {
+                let mut message = Markup::new();
+                write!(message, "Can not ")?;
+                write!(message.code(), "await")?;
+                write!(message, " ")?;
+                operand_type.print(&mut message, true)?;
+                write!(message, " because it is not a ")?;
+                write!(message.code(), "Promise")?;
+                write!(message, ".")?;
+                let mut related_information = Vec::new();
+                if !self.range.intersects(*operand_range) {
+                    let mut message = Markup::new();
+                    operand_type.print(&mut message, false)?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *operand_range,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+
+            // A chain like `a < b > c` doesn’t tell us anything about how `a` relates to `c` so
+            // we suggest the programmer write out both comparisons.
+            ErrorDiagnosticMessage::InconsistentComparison { first, second } => {
+                let mut message = Markup::new();
+                write!(message, "Can not compare with ")?;
+                write!(message.code(), "{}", second.as_str())?;
+                write!(message, " after ")?;
+                write!(message.code(), "{}", first.as_str())?;
+                write!(
+                    message,
+                    " because a comparison chain must go in one direction. Use "
+                )?;
+                write!(message.code(), "&&")?;
+                write!(message, " instead.")?;
+                Ok((message, Vec::new()))
+            }
+
+            // Follows the same format as our error for a name we can not find. We write the `@`
+            // so the programmer knows we are talking about an attribute.
+            ErrorDiagnosticMessage::UnknownAttribute { name } => {
+                let mut message = Markup::new();
+                write!(message, "Can not find ")?;
+                write!(message.code(), "@{}", name.as_str())?;
+                write!(message, ".")?;
+                Ok((message, Vec::new()))
+            }
+
+            ErrorDiagnosticMessage::UnexpectedAttribute { name, target } => {
+                let mut message = Markup::new();
+                write!(message, "Can not use ")?;
+                write!(message.code(), "@{}", name.as_str())?;
+                match target {
+                    AttributeTarget::Function => write!(message, " on a function.")?,
+                    AttributeTarget::Class => write!(message, " on a class.")?,
+                    AttributeTarget::TypeAlias => write!(message, " on a type alias.")?,
+                    AttributeTarget::Field => write!(message, " on a field.")?,
+                    AttributeTarget::Method => write!(message, " on a method.")?,
+                    AttributeTarget::BaseMethod => write!(message, " on a base method.")?,
+                }
+                Ok((message, Vec::new()))
+            }
+
+            // Follows the same format as our error for functions with the wrong number of
+            // arguments. We only report this error when there are too many arguments since every
+            // attribute we know of may be written without any.
+            ErrorDiagnosticMessage::IncompatibleAttributeArguments { name, len, max } => {
+                let mut message = Markup::new();
+                write!(message, "Can not use ")?;
+                write!(message.code(), "@{}", name.as_str())?;
+                write!(message, " because we have ")?;
+                match cardinal(*len) {
+                    Some(len) => write!(message, "{}", len)?,
+                    None => write!(message, "{}", len)?,
+                }
+                if *len == 1 {
+                    write!(message, " argument")?;
+                } else {
+                    write!(message, " arguments")?;
+                }
+                if *max == 0 {
+                    write!(message, " but we need none.")?;
+                } else if let Some(max) = cardinal(*max) {
+                    write!(message, " but we only need {}.", max)?;
+                } else {
+                    write!(message, " but we only need {}.", max)?;
+                }
+                Ok((message, Vec::new()))
+            }
+
+            ErrorDiagnosticMessage::DuplicateAttribute { name } => {
+                let mut message = Markup::new();
+                write!(message, "Can not use ")?;
+                write!(message.code(), "@{}", name.as_str())?;
+                write!(message, " twice.")?;
+                Ok((message, Vec::new()))
+            }
+
+            // Tell the programmer what our function returns instead of a promise and point to
+            // where that type comes from.
+            ErrorDiagnosticMessage::OutlineWithoutPromise {
+                function,
+                return_range,
+                return_type,
+            } => {
+                let mut message = Markup::new();
+                write!(message, "Can not use ")?;
+                write!(message.code(), "@outline")?;
+                write!(message, " on ")?;
+                write!(message.code(), "{}", function.as_str())?;
+                write!(message, " because it returns ")?;
+                return_type.print(&mut message, true)?;
+                write!(message, " and not a ")?;
+                write!(message.code(), "Promise")?;
+                write!(message, ".")?;
+                let mut related_information = Vec::new();
+                if !self.range.intersects(*return_range) {
+                    let mut message = Markup::new();
+                    return_type.print(&mut message, false)?;
+                    related_information.push(DiagnosticRelatedInformation {
+                        range: *return_range,
+                        message,
+                    });
+                }
+                Ok((message, related_information))
+            }
+        }
+    }
+}
+
+impl Diagnostic {
+    fn warning_message(
+        &self,
+        warning_message: &WarningDiagnosticMessage,
+    ) -> Result<(Markup, Vec<DiagnosticRelatedInformation>), fmt::Error> {
+        match warning_message {
+            // The programmer probably expects their case to match something. We tell them it
+            // never will and point them to the cases before it which are to blame.
+            WarningDiagnosticMessage::RedundantCase { pattern } => {
+                let mut message = Markup::new();
+                write!(message, "We will never match ")?;
+                pattern.print(&mut message.code())?;
+                write!(
+                    message,
+                    " because the cases before it match everything it does."
+                )?;
+                Ok((message, Vec::new()))
+            }
         }
     }
 }
@@ -905,6 +2312,7 @@ impl UnexpectedSyntax {
             UnexpectedSyntax::Glyph(glyph) => write!(message.code(), "{}", glyph.as_str()),
             UnexpectedSyntax::Identifier => write!(message, "a variable name"),
             UnexpectedSyntax::Number => write!(message, "a number"),
+            UnexpectedSyntax::String => write!(message, "a string"),
             UnexpectedSyntax::Char(c) => match c {
                 '\n' => write!(message.code(), "\\n"),
                 '\r' => write!(message.code(), "\\r"),
@@ -937,6 +2345,15 @@ impl ExpectedSyntax {
             ExpectedSyntax::BinaryDigit => write!(message, "a binary digit"),
             ExpectedSyntax::HexadecimalDigit => write!(message, "a hexadecimal digit"),
 
+            ExpectedSyntax::StringEnd => write!(message.code(), "\""),
+
+            // If the programmer wrote a backslash in a string then they are probably familiar with
+            // escape sequences so we use that phrase.
+            ExpectedSyntax::EscapeSequence => write!(message, "an escape sequence"),
+
+            // We only expect a string for the path of a module we import from.
+            ExpectedSyntax::String => write!(message, "a module path"),
+
             // While a declaration or class member may be something else other than a function we
             // still say that we expected a function. Functions are the most common declaration and
             // class member. If the programmer was trying to write something other than a function
@@ -991,12 +2408,110 @@ impl OperationSnippet {
                 write!(message, "Can not call ")?;
                 callee.print(&mut message.code())?;
             }
+            OperationSnippet::Pipe(argument, callee) => {
+                write!(message, "Can not pipe ")?;
+                argument.print(&mut message.code())?;
+                write!(message, " into ")?;
+                callee.print(&mut message.code())?;
+            }
+            OperationSnippet::FunctionParameterDefault(pattern, value) => {
+                write!(message, "Can not default ")?;
+                pattern.print(&mut message.code())?;
+                write!(message, " to ")?;
+                value.print(&mut message.code())?;
+            }
+            OperationSnippet::AttributeArgument(name, argument) => {
+                write!(message, "Can not pass ")?;
+                argument.print(&mut message.code())?;
+                write!(message, " to ")?;
+                write!(message.code(), "@{}", name.as_str())?;
+            }
+            OperationSnippet::TypeReference(identifier) => {
+                write!(message, "Can not use ")?;
+                write!(message.code(), "{}", identifier.as_str())?;
+            }
+            OperationSnippet::ClassPattern(identifier) => {
+                write!(message, "Can not match ")?;
+                write!(message.code(), "{}", identifier.as_str())?;
+            }
+            OperationSnippet::Construct(identifier) => {
+                write!(message, "Can not construct ")?;
+                write!(message.code(), "{}", identifier.as_str())?;
+            }
+            OperationSnippet::CaseArm(body) => {
+                write!(message, "Can not return ")?;
+                body.print(&mut message.code())?;
+                write!(message, " from ")?;
+                write!(message.code(), "case")?;
+            }
+            OperationSnippet::WhileTest(test) => {
+                write!(message, "Can not loop while ")?;
+                test.print(&mut message.code())?;
+            }
+            OperationSnippet::ConditionalTest(test) => {
+                write!(message, "Can not test ")?;
+                let mut code = message.code();
+                write!(code, "if ")?;
+                test.print(&mut code)?;
+            }
+            OperationSnippet::ConditionalAlternate => {
+                write!(message, "Can not return from ")?;
+                write!(message.code(), "else")?;
+            }
+            OperationSnippet::ListElement(element) => {
+                write!(message, "Can not put ")?;
+                element.print(&mut message.code())?;
+                write!(message, " in a list")?;
+            }
+            OperationSnippet::ListIndex(object, index) => {
+                write!(message, "Can not index ")?;
+                object.print(&mut message.code())?;
+                write!(message, " with ")?;
+                index.print(&mut message.code())?;
+            }
+            OperationSnippet::LoopBreak(None) => {
+                write!(message, "Can not ")?;
+                write!(message.code(), "break")?;
+                write!(message, " without a value")?;
+            }
+            OperationSnippet::LoopBreak(Some(argument)) => {
+                write!(message, "Can not ")?;
+                write!(message.code(), "break")?;
+                write!(message, " with ")?;
+                argument.print(&mut message.code())?;
+            }
+            OperationSnippet::FunctionReturn(None) => {
+                write!(message, "Can not ")?;
+                write!(message.code(), "return")?;
+                write!(message, " without a value")?;
+            }
+            OperationSnippet::FunctionReturn(Some(argument)) => {
+                write!(message, "Can not return ")?;
+                argument.print(&mut message.code())?;
+            }
+            OperationSnippet::Assignment(target, value) => {
+                write!(message, "Can not assign ")?;
+                value.print(&mut message.code())?;
+                write!(message, " to ")?;
+                target.print(&mut message.code())?;
+            }
             OperationSnippet::OperatorExpression(operator) => {
                 write!(message, "Can not use ")?;
                 match operator {
                     OperatorSnippet::Not => write!(message.code(), "!")?,
                     OperatorSnippet::And => write!(message.code(), "&&")?,
                     OperatorSnippet::Or => write!(message.code(), "||")?,
+                    OperatorSnippet::Equals => write!(message.code(), "==")?,
+                    OperatorSnippet::NotEquals => write!(message.code(), "!=")?,
+                    OperatorSnippet::Negative => write!(message.code(), "-")?,
+                    OperatorSnippet::Positive => write!(message.code(), "+")?,
+                    OperatorSnippet::BitwiseNot => write!(message.code(), "~")?,
+                    OperatorSnippet::Infix(operator) => {
+                        write!(message.code(), "{}", operator.as_str())?
+                    }
+                    OperatorSnippet::Assignment(operator) => {
+                        write!(message.code(), "{}", operator.as_str())?
+                    }
                 }
             }
         };
@@ -1014,6 +2529,25 @@ impl StatementSnippet {
                 write!(message, " = ")?;
                 value.print(message)
             }
+            StatementSnippet::Assignment(target, operator, value) => {
+                target.print(message)?;
+                match operator {
+                    None => write!(message, " = ")?,
+                    Some(operator) => write!(message, " {} ", operator.as_str())?,
+                }
+                value.print(message)
+            }
+            StatementSnippet::Break(None) => write!(message, "break"),
+            StatementSnippet::Break(Some(argument)) => {
+                write!(message, "break ")?;
+                argument.print(message)
+            }
+            StatementSnippet::Continue => write!(message, "continue"),
+            StatementSnippet::Return(None) => write!(message, "return"),
+            StatementSnippet::Return(Some(argument)) => {
+                write!(message, "return ")?;
+                argument.print(message)
+            }
         }
     }
 }
@@ -1023,6 +2557,7 @@ impl ExpressionSnippet {
         match self {
             ExpressionSnippet::Constant(constant) => write!(message, "{}", constant.print()),
             ExpressionSnippet::Reference(identifier) => write!(message, "{}", identifier.as_str()),
+            ExpressionSnippet::This => write!(message, "this"),
             ExpressionSnippet::Function(parameters) => {
                 write!(message, "fun(")?;
                 match parameters {
@@ -1046,14 +2581,60 @@ impl ExpressionSnippet {
                 callee.print(message)?;
                 write!(message, "()")
             }
+            ExpressionSnippet::Construct(class) => write!(message, "{} {{ ... }}", class.as_str()),
+            ExpressionSnippet::Member(object, property) => {
+                object.print(message)?;
+                write!(message, ".{}", property.as_str())
+            }
+            ExpressionSnippet::List => write!(message, "[...]"),
+            ExpressionSnippet::Index(object, index) => {
+                object.print(message)?;
+                write!(message, "[")?;
+                index.print(message)?;
+                write!(message, "]")
+            }
+            ExpressionSnippet::Conditional(test) => {
+                write!(message, "if ")?;
+                test.print(message)?;
+                write!(message, " {{ ... }}")
+            }
+            ExpressionSnippet::Case(test) => {
+                write!(message, "case ")?;
+                test.print(message)?;
+                write!(message, " {{ ... }}")
+            }
+            ExpressionSnippet::While(test) => {
+                write!(message, "while ")?;
+                test.print(message)?;
+                write!(message, " {{ ... }}")
+            }
+            ExpressionSnippet::Loop => write!(message, "loop {{ ... }}"),
             ExpressionSnippet::Prefix(operator, operand) => {
                 match operator {
                     PrefixOperator::Not => write!(message, "!")?,
                     PrefixOperator::Negative => write!(message, "-")?,
                     PrefixOperator::Positive => write!(message, "+")?,
+                    PrefixOperator::BitwiseNot => write!(message, "~")?,
                 }
                 operand.print(message)
             }
+            ExpressionSnippet::Await(operand) => {
+                write!(message, "await ")?;
+                operand.print(message)
+            }
+            ExpressionSnippet::Infix(left, operator, right) => {
+                left.print(message)?;
+                write!(message, " {} ", operator.as_str())?;
+                right.print(message)
+            }
+            ExpressionSnippet::Comparison(left, comparisons) => {
+                left.print(message)?;
+                for (operator, right) in comparisons {
+                    write!(message, " {} ", operator.as_str())?;
+                    right.print(message)?;
+                }
+                Ok(())
+            }
             ExpressionSnippet::Logical(left, operator, right) => {
                 left.print(message)?;
                 match operator {
@@ -1062,7 +2643,14 @@ impl ExpressionSnippet {
                 }
                 right.print(message)
             }
+            ExpressionSnippet::Pipe(left, right) => {
+                left.print(message)?;
+                write!(message, " |> ")?;
+                right.print(message)
+            }
             ExpressionSnippet::Block => write!(message, "do {{ ... }}"),
+            ExpressionSnippet::Template => write!(message, "\"${{...}}\""),
+            ExpressionSnippet::Error => write!(message, "..."),
         }
     }
 }
@@ -1071,6 +2659,11 @@ impl PatternSnippet {
     fn print(&self, message: &mut MarkupCode) -> Result<(), fmt::Error> {
         match self {
             PatternSnippet::Binding(identifier) => write!(message, "{}", identifier.as_str()),
+            PatternSnippet::Hole => write!(message, "_"),
+            PatternSnippet::Class(identifier) => {
+                write!(message, "{} {{ ... }}", identifier.as_str())
+            }
+            PatternSnippet::Error => write!(message, "..."),
         }
     }
 }
@@ -1107,12 +2700,66 @@ impl TypeKindSnippet {
                 }
                 write!(message.code(), "Float")
             }
+            TypeKindSnippet::String => {
+                if article {
+                    write!(message, "a ")?
+                }
+                write!(message.code(), "String")
+            }
+            TypeKindSnippet::Null => write!(message.code(), "null"),
+            TypeKindSnippet::List => {
+                if article {
+                    write!(message, "a ")?;
+                }
+                write!(message.code(), "List")
+            }
+            TypeKindSnippet::Promise => {
+                if article {
+                    write!(message, "a ")?;
+                }
+                write!(message.code(), "Promise")
+            }
+            TypeKindSnippet::Optional(type_) => match type_.name() {
+                Some(name) => write!(message.code(), "{}?", name),
+                None => {
+                    if article {
+                        write!(message, "an ")?
+                    }
+                    write!(message, "optional ")?;
+                    type_.print(message, false)
+                }
+            },
             TypeKindSnippet::Function => {
                 if article {
                     write!(message, "a ")?
                 }
                 write!(message, "function")
             }
+            TypeKindSnippet::Parameter(identifier) | TypeKindSnippet::Class(identifier) => {
+                write!(message.code(), "{}", identifier.as_str())
+            }
+        }
+    }
+
+    /// Gets the name of a type which we may print as code. Not every type has a name. For example,
+    /// we print function types as “function”.
+    fn name(&self) -> Option<&str> {
+        match self {
+            TypeKindSnippet::Never => Some("Never"),
+            TypeKindSnippet::Void => Some("Void"),
+            TypeKindSnippet::Boolean => Some("Bool"),
+            TypeKindSnippet::Number => Some("Num"),
+            TypeKindSnippet::Integer => Some("Int"),
+            TypeKindSnippet::Float => Some("Float"),
+            TypeKindSnippet::String => Some("String"),
+            TypeKindSnippet::List => Some("List"),
+            TypeKindSnippet::Promise => Some("Promise"),
+            TypeKindSnippet::Parameter(identifier) | TypeKindSnippet::Class(identifier) => {
+                Some(identifier.as_str())
+            }
+            TypeKindSnippet::Null | TypeKindSnippet::Optional(_) | TypeKindSnippet::Function => {
+                None
+            }
         }
     }
 }
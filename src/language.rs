@@ -1,9 +1,13 @@
 //! The Abstract Syntax Tree (AST) represents the syntactic source code structure of a
 //! Brite program.
 
+// Our nodes have a `_private` field so that only this module may construct them. Unlike
+// `#[non_exhaustive]` that also stops the rest of our crate from constructing them.
+#![allow(clippy::manual_non_exhaustive)]
+
 use crate::diagnostics::DiagnosticRef;
 use crate::diagnostics::{ExpressionSnippet, PatternSnippet, StatementSnippet, VecSnippet};
-use crate::parser::{Document, Identifier, Range};
+use crate::parser::{Document, Identifier, Range, StringLiteral};
 use crate::utils::lisp::Lisp;
 use crate::utils::vecn::Vec2;
 use num::BigInt;
@@ -31,10 +35,21 @@ pub enum Declaration {
     Function(FunctionDeclaration),
     /// A class is some associated data and functions.
     Class(ClassDeclaration),
+    /// A type alias gives a name to some other type.
+    TypeAlias(TypeAliasDeclaration),
+    /// An import brings the exported declarations of another module into scope.
+    Import(ImportDeclaration),
+    /// A declaration we could not parse because of a syntax error. We skip all the tokens up to the
+    /// next declaration so that one syntax error does not hide the rest of the module.
+    Error(ErrorDeclaration),
 }
 
 /// A function describes some reusable code which may be executed at any time.
 pub struct FunctionDeclaration {
+    /// The attributes written before our function.
+    pub attributes: Vec<Attribute>,
+    /// Is this function exported? Other modules may only import exported declarations.
+    pub export: bool,
     /// The name of a function declaration.
     pub name: Name,
     /// Shared function node.
@@ -49,6 +64,12 @@ pub struct FunctionDeclaration {
 /// - As a `FunctionExpression`.
 #[derive(Debug)]
 pub struct Function {
+    /// Is this an `async` function? An async function returns a promise of the value its body
+    /// evaluates to and may `await` other promises.
+    pub async_: bool,
+    /// The type parameters of a function make it generic. Every time the function is called we
+    /// pick new types for its type parameters.
+    pub type_parameters: Vec<Name>,
     /// The parameters of a function describes what the function accepts as input.
     pub parameters: Vec<FunctionParameter>,
     /// The programmer may optionally write a return type. The return type is inferred if it is
@@ -66,14 +87,24 @@ pub struct FunctionParameter {
     /// The type of our function parameter. Most function parameters must be annotated and may not
     /// be inferred.
     pub annotation: Option<Type>,
+    /// The value we use for our function parameter when a call does not provide an argument for
+    /// it. For example, `port: Int = 80`.
+    pub default: Option<Expression>,
 }
 
 /// A class is some associated data and functions.
 pub struct ClassDeclaration {
+    /// The attributes written before our class.
+    pub attributes: Vec<Attribute>,
+    /// Is this class exported? Other modules may only import exported declarations.
+    pub export: bool,
     /// Is this a base class?
     pub base: bool,
     /// The name of a class.
     pub name: Name,
+    /// The type parameters of a class make it generic. Every reference to a generic class must
+    /// provide a type for each of its type parameters.
+    pub type_parameters: Vec<Name>,
     /// A class may optionally extend a base class.
     pub extends: Option<Name>,
     /// The members of a class.
@@ -92,6 +123,8 @@ pub enum ClassMember {
 
 /// A field declares some data needed by the class.
 pub struct FieldClassMember {
+    /// The attributes written before our class field.
+    pub attributes: Vec<Attribute>,
     /// The name of the class field.
     pub name: Name,
     /// The type of the class field’s data.
@@ -100,6 +133,8 @@ pub struct FieldClassMember {
 
 /// A method declares some function behavior that a class may perform.
 pub struct MethodClassMember {
+    /// The attributes written before our class method.
+    pub attributes: Vec<Attribute>,
     /// The name of the class method.
     pub name: Name,
     /// Shared function node.
@@ -108,22 +143,232 @@ pub struct MethodClassMember {
 
 /// A base method is a function which may be overriden in a class which extends the current one.
 pub struct BaseMethodClassMember {
+    /// The attributes written before our base class method.
+    pub attributes: Vec<Attribute>,
     /// The name of the base class method.
     pub name: Name,
+    /// The type parameters which the base method’s implementation must accept.
+    pub type_parameters: Vec<Name>,
     /// The parameters which the base method’s implementation must accept.
     pub parameters: Vec<FunctionParameter>,
     /// The type which the base method’s implementation must return.
     pub return_type: Type,
 }
 
+/// A type alias gives a name to some other type. The alias is transparent. Anywhere we may use the
+/// alias we may also use the aliased type and the other way around.
+///
+/// ```ite
+/// type Comparator = fun(Int, Int): Bool
+/// ```
+pub struct TypeAliasDeclaration {
+    /// The attributes written before our type alias.
+    pub attributes: Vec<Attribute>,
+    /// Is this type alias exported? Other modules may only import exported declarations.
+    pub export: bool,
+    /// The name of our type alias.
+    pub name: Name,
+    /// The type our type alias gives a name to.
+    pub value: Type,
+}
+
+/// An import brings the exported declarations of another module into scope.
+///
+/// ```ite
+/// import { foo, Bar } from "./other"
+/// ```
+pub struct ImportDeclaration {
+    /// The names of the declarations we import from the other module.
+    pub names: Vec<Name>,
+    /// The range of the string we parsed our module path from.
+    pub path_range: Range,
+    /// The path of the module we import from. The path is relative to the directory of the
+    /// importing module so it must start with `./` or `../`.
+    pub path: String,
+}
+
+impl ImportDeclaration {
+    pub fn new(names: Vec<Name>, path_range: Range, path: String) -> Self {
+        ImportDeclaration {
+            names,
+            path_range,
+            path,
+        }
+    }
+}
+
+/// A declaration we could not parse because of a syntax error.
+pub struct ErrorDeclaration {
+    /// The range of all the source code we skipped over while recovering from the syntax error.
+    pub range: Range,
+    /// The syntax error diagnostic which caused us to create this error declaration.
+    pub error: DiagnosticRef,
+}
+
 impl Declaration {
-    /// Gets the range for our declaration’s name.
-    pub fn name(&self) -> &Name {
+    /// Gets the range for our declaration’s name. Import and error declarations don’t have
+    /// a name.
+    pub fn name(&self) -> Option<&Name> {
+        match self {
+            Declaration::Function(x) => Some(&x.name),
+            Declaration::Class(x) => Some(&x.name),
+            Declaration::TypeAlias(x) => Some(&x.name),
+            Declaration::Import(_) | Declaration::Error(_) => None,
+        }
+    }
+
+    /// Is this declaration exported? Other modules may only import exported declarations.
+    pub fn export(&self) -> bool {
         match self {
-            Declaration::Function(x) => &x.name,
-            Declaration::Class(x) => &x.name,
+            Declaration::Function(x) => x.export,
+            Declaration::Class(x) => x.export,
+            Declaration::TypeAlias(x) => x.export,
+            Declaration::Import(_) | Declaration::Error(_) => false,
         }
     }
+
+    /// Gets the attributes written before our declaration. Import and error declarations don’t
+    /// have attributes.
+    pub fn attributes(&self) -> &[Attribute] {
+        match self {
+            Declaration::Function(x) => &x.attributes,
+            Declaration::Class(x) => &x.attributes,
+            Declaration::TypeAlias(x) => &x.attributes,
+            Declaration::Import(_) | Declaration::Error(_) => &[],
+        }
+    }
+
+    /// Was our declaration written with an attribute of this kind?
+    pub fn has_attribute(&self, kind: AttributeKind) -> bool {
+        has_attribute(self.attributes(), kind)
+    }
+}
+
+impl FunctionDeclaration {
+    /// Was our function declaration written with an attribute of this kind?
+    pub fn has_attribute(&self, kind: AttributeKind) -> bool {
+        has_attribute(&self.attributes, kind)
+    }
+}
+
+impl ClassDeclaration {
+    /// Was our class declaration written with an attribute of this kind?
+    pub fn has_attribute(&self, kind: AttributeKind) -> bool {
+        has_attribute(&self.attributes, kind)
+    }
+}
+
+impl ClassMember {
+    /// Gets the attributes written before our class member.
+    pub fn attributes(&self) -> &[Attribute] {
+        match self {
+            ClassMember::Field(x) => &x.attributes,
+            ClassMember::Method(x) => &x.attributes,
+            ClassMember::BaseMethod(x) => &x.attributes,
+        }
+    }
+
+    /// Was our class member written with an attribute of this kind?
+    pub fn has_attribute(&self, kind: AttributeKind) -> bool {
+        has_attribute(self.attributes(), kind)
+    }
+}
+
+/// An attribute changes how we check or compile the declaration or class member it is
+/// written before.
+///
+/// ```ite
+/// @deprecated("Use `connect` instead.")
+/// fun open() {}
+/// ```
+#[derive(Debug)]
+pub struct Attribute {
+    /// The range of our attribute from the `@` to the end of our arguments.
+    pub range: Range,
+    /// The name of our attribute without the `@`.
+    pub name: Name,
+    /// The arguments of our attribute. An attribute written without parentheses has
+    /// no arguments.
+    pub arguments: Vec<Expression>,
+}
+
+impl Attribute {
+    /// Gets the kind of our attribute. If we don’t know of an attribute with our name then we
+    /// return nothing.
+    pub fn kind(&self) -> Option<AttributeKind> {
+        AttributeKind::from_identifier(&self.name.identifier)
+    }
+}
+
+/// Was a list of attributes written with an attribute of this kind?
+fn has_attribute(attributes: &[Attribute], kind: AttributeKind) -> bool {
+    attributes
+        .iter()
+        .any(|attribute| attribute.kind() == Some(kind))
+}
+
+/// The attributes we know of.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AttributeKind {
+    /// `@inline` inlines a function everywhere it is called. If used on a class then we inline the
+    /// class’s constructor everywhere it is constructed.
+    Inline,
+    /// `@outline` moves the implementation of a function into a new code chunk which is
+    /// fetched asynchronously.
+    Outline,
+    /// `@deprecated` marks a declaration which should no longer be used. Optionally with a
+    /// message explaining what to use instead.
+    Deprecated,
+    /// `@test` marks a function as a test.
+    Test,
+}
+
+impl AttributeKind {
+    /// Gets the kind of attribute with this name.
+    pub fn from_identifier(identifier: &Identifier) -> Option<Self> {
+        match identifier.as_str() {
+            "inline" => Some(AttributeKind::Inline),
+            "outline" => Some(AttributeKind::Outline),
+            "deprecated" => Some(AttributeKind::Deprecated),
+            "test" => Some(AttributeKind::Test),
+            _ => None,
+        }
+    }
+
+    /// May we write an attribute of this kind before the provided kind of declaration or
+    /// class member?
+    pub fn allows(self, target: AttributeTarget) -> bool {
+        match (self, target) {
+            (AttributeKind::Inline, AttributeTarget::Function)
+            | (AttributeKind::Inline, AttributeTarget::Class) => true,
+            (AttributeKind::Inline, _) => false,
+            (AttributeKind::Outline, AttributeTarget::Function) => true,
+            (AttributeKind::Outline, _) => false,
+            (AttributeKind::Deprecated, _) => true,
+            (AttributeKind::Test, AttributeTarget::Function) => true,
+            (AttributeKind::Test, _) => false,
+        }
+    }
+
+    /// The most arguments we may write an attribute of this kind with. Only `@deprecated` accepts
+    /// an argument, its message.
+    pub fn max_arguments(self) -> usize {
+        match self {
+            AttributeKind::Inline | AttributeKind::Outline | AttributeKind::Test => 0,
+            AttributeKind::Deprecated => 1,
+        }
+    }
+}
+
+/// The kinds of declarations and class members we may write attributes before.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AttributeTarget {
+    Function,
+    Class,
+    TypeAlias,
+    Field,
+    Method,
+    BaseMethod,
 }
 
 /// A block contains a list of statements which are executed sequentially.
@@ -170,13 +415,30 @@ pub enum StatementKind {
     Expression(Expression),
     /// Binds a value to some names in the current scope.
     Binding(BindingStatement),
+    /// Sets a new value for a mutable variable.
+    Assignment(AssignmentStatement),
     /// Returns a value from a block early.
     Return(Option<Expression>),
+    /// Exits a loop, possibly with a value.
+    Break(BreakStatement),
+    /// Skips to the next iteration of a loop.
+    Continue(ContinueStatement),
+    /// A statement we could not parse because of a syntax error. We skip all the tokens up to the
+    /// next statement so that one syntax error does not hide the rest of the block.
+    Error(DiagnosticRef),
 }
 
 /// Binds a value to some names in the current scope.
+///
+/// ```ite
+/// let x = 42
+/// let mut y = 0
+/// ```
 #[derive(Debug)]
 pub struct BindingStatement {
+    /// Whether or not the names bound by our pattern may be set to a new value with an
+    /// assignment statement.
+    pub mutable: bool,
     /// Binds the value to this pattern in the current scope.
     pub pattern: Pattern,
     /// An optional type annotation. If a type annotation is not added then the type is inferred.
@@ -185,15 +447,110 @@ pub struct BindingStatement {
     pub value: Expression,
 }
 
+/// Sets a new value for a variable declared with `let mut`. A compound assignment first applies
+/// its operator to the variable’s current value and the new value.
+///
+/// ```ite
+/// x = 42
+/// x += 1
+/// ```
+#[derive(Debug)]
+pub struct AssignmentStatement {
+    /// The variable we are assigning to. Only a reference to a mutable variable may be assigned to,
+    /// but we leave that for the checker to decide.
+    pub target: Expression,
+    /// The operator of a compound assignment like `+=`. If there is no operator then we have a
+    /// plain assignment with `=`.
+    pub operator: Option<AssignmentOperator>,
+    /// The value being assigned.
+    pub value: Expression,
+}
+
+/// The operator of a compound `AssignmentStatement`.
+#[derive(Clone, Debug)]
+pub enum AssignmentOperator {
+    /// `+=`
+    Add,
+    /// `-=`
+    Subtract,
+    /// `*=`
+    Multiply,
+    /// `/=`
+    Divide,
+    /// `%=`
+    Remainder,
+}
+
+impl AssignmentOperator {
+    /// Gets the source string of this operator.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AssignmentOperator::Add => "+=",
+            AssignmentOperator::Subtract => "-=",
+            AssignmentOperator::Multiply => "*=",
+            AssignmentOperator::Divide => "/=",
+            AssignmentOperator::Remainder => "%=",
+        }
+    }
+
+    /// Gets the infix operator this operator applies. So `+` for `+=`.
+    pub fn infix_operator(&self) -> InfixOperator {
+        match self {
+            AssignmentOperator::Add => InfixOperator::Add,
+            AssignmentOperator::Subtract => InfixOperator::Subtract,
+            AssignmentOperator::Multiply => InfixOperator::Multiply,
+            AssignmentOperator::Divide => InfixOperator::Divide,
+            AssignmentOperator::Remainder => InfixOperator::Remainder,
+        }
+    }
+}
+
+/// Exits a loop. Without a label we exit the innermost loop. A `loop` expression may be exited with
+/// a value which becomes the value of the `loop`.
+///
+/// ```ite
+/// break
+/// break 42
+/// break outer
+/// break outer 42
+/// ```
+///
+/// An identifier after `break` is a label if it names a loop we are inside of. Otherwise it is the
+/// value we break with.
+#[derive(Debug)]
+pub struct BreakStatement {
+    /// The label of the loop we exit.
+    pub label: Option<Name>,
+    /// The value we exit a `loop` expression with.
+    pub argument: Option<Expression>,
+}
+
+/// Skips the rest of a loop’s body and starts the next iteration. Without a label we continue the
+/// innermost loop.
+///
+/// ```ite
+/// continue
+/// continue outer
+/// ```
+#[derive(Debug)]
+pub struct ContinueStatement {
+    /// The label of the loop we continue.
+    pub label: Option<Name>,
+}
+
 /// A constant value in the programmer’s code.
 #[derive(Clone, Debug)]
 pub enum Constant {
     /// Either `true` or `false`.
     Boolean(bool),
+    /// The absence of a value. Only an optional type may be `null`.
+    Null,
     /// An integer of arbitrary precision.
     Integer(IntegerBase, BigInt),
     /// A 64-bit floating point number.
     Float(f64),
+    /// A string of text.
+    String(String),
 }
 
 /// The Brite supported integer bases.
@@ -226,25 +583,47 @@ pub enum ExpressionKind {
     /// A special reference to the current class instance.
     This,
     /// A higher-order function.
-    Function(Function),
+    Function(Box<Function>),
     /// Calls a function with some arguments.
     Call(CallExpression),
     /// Constructs a class instance with some fields.
     Construct(ConstructExpression),
     /// Accesses a member of a class instance.
     Member(Box<MemberExpression>),
+    /// A list of values.
+    List(ListExpression),
+    /// Gets the value at some index of a list.
+    Index(Box<IndexExpression>),
     /// An operation using prefix syntax.
     Prefix(Box<PrefixExpression>),
+    /// Waits for a promise to resolve inside of an `async` function.
+    Await(Box<AwaitExpression>),
     /// An operation using infix syntax.
     Infix(Box<InfixExpression>),
+    /// A chain of relational comparisons like `a < b < c`.
+    Comparison(Box<ComparisonExpression>),
     /// A logical operation using infix syntax.
     Logical(Box<LogicalExpression>),
+    /// Calls a function with a value using pipe syntax.
+    Pipe(Box<PipeExpression>),
     /// A conditional expression chooses a branch to take based on a test expression.
     Conditional(Box<ConditionalExpressionIf>),
+    /// A case expression chooses a branch to take based on the first pattern which matches a
+    /// test expression.
+    Case(Box<CaseExpression>),
+    /// Executes a block for as long as a test expression is true.
+    While(Box<WhileExpression>),
+    /// Executes a block forever, or until we break out of it.
+    Loop(Box<LoopExpression>),
     /// Embeds a block into an expression.
     Block(Block),
     /// Wraps an expression in parentheses with an optional type annotation.
     Wrapped(Box<WrappedExpression>),
+    /// A string with expressions interpolated into it.
+    Template(TemplateExpression),
+    /// We wanted an expression but found some other syntax instead. Carries the syntax
+    /// error diagnostic.
+    Error(DiagnosticRef),
 }
 
 /// Calls a function with some arguments.
@@ -252,8 +631,30 @@ pub enum ExpressionKind {
 pub struct CallExpression {
     /// The function we want to call.
     pub callee: Box<Expression>,
+    /// The types we want to use for the type parameters of our function. If there are no type
+    /// arguments then we will infer them from our arguments.
+    pub type_arguments: Vec<Type>,
     /// The arguments we want to call the function with.
     pub arguments: Vec<Expression>,
+    /// The arguments we want to call the function with by parameter name. Named arguments always
+    /// come after positional arguments.
+    pub named_arguments: Vec<NamedArgument>,
+}
+
+/// An argument in a [`CallExpression`] which is passed by parameter name. For example, the
+/// `port: 1` in `connect(host: "x", port: 1)`.
+#[derive(Debug)]
+pub struct NamedArgument {
+    /// The name of the parameter we are passing our argument to.
+    pub name: Name,
+    /// The value of our argument.
+    pub value: Expression,
+}
+
+impl NamedArgument {
+    pub fn new(name: Name, value: Expression) -> Self {
+        NamedArgument { name, value }
+    }
 }
 
 /// Constructs a class instance with some fields.
@@ -261,6 +662,8 @@ pub struct CallExpression {
 pub struct ConstructExpression {
     /// The class to be constructed.
     pub constructor: Name,
+    /// The type arguments for the class’s type parameters, if the programmer wrote them.
+    pub type_arguments: Vec<Type>,
     /// The fields we construct the class with.
     pub fields: Vec<ConstructExpressionField>,
 }
@@ -283,6 +686,30 @@ pub struct MemberExpression {
     pub property: Name,
 }
 
+/// A list of values.
+///
+/// ```ite
+/// [1, 2, 3]
+/// ```
+#[derive(Debug)]
+pub struct ListExpression {
+    /// The values in our list.
+    pub elements: Vec<Expression>,
+}
+
+/// Gets the value at some index of a list.
+///
+/// ```ite
+/// xs[i]
+/// ```
+#[derive(Debug)]
+pub struct IndexExpression {
+    /// The list we are getting a value from.
+    pub object: Expression,
+    /// The index of the value we are getting.
+    pub index: Expression,
+}
+
 /// An operation using prefix syntax.
 #[derive(Debug)]
 pub struct PrefixExpression {
@@ -301,6 +728,15 @@ pub enum PrefixOperator {
     Negative,
     /// `+`
     Positive,
+    /// `~`
+    BitwiseNot,
+}
+
+/// Waits for a promise to resolve. We may only await a promise inside of an `async` function.
+#[derive(Debug)]
+pub struct AwaitExpression {
+    /// The promise we are waiting for.
+    pub operand: Expression,
 }
 
 /// An operation using infix syntax.
@@ -327,8 +763,20 @@ pub enum InfixOperator {
     Divide,
     /// `%`
     Remainder,
-    /// `^`
+    /// `**`
     Exponent,
+    /// `&`
+    BitwiseAnd,
+    /// `|`
+    BitwiseOr,
+    /// `^`
+    BitwiseXor,
+    /// `<<`
+    LeftShift,
+    /// `>>`
+    RightShift,
+    /// `>>>`
+    UnsignedRightShift,
     /// `==`
     Equals,
     /// `!=`
@@ -343,6 +791,103 @@ pub enum InfixOperator {
     GreaterThanOrEqual,
 }
 
+impl InfixOperator {
+    /// Gets the source string of this operator.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InfixOperator::Add => "+",
+            InfixOperator::Subtract => "-",
+            InfixOperator::Multiply => "*",
+            InfixOperator::Divide => "/",
+            InfixOperator::Remainder => "%",
+            InfixOperator::Exponent => "**",
+            InfixOperator::BitwiseAnd => "&",
+            InfixOperator::BitwiseOr => "|",
+            InfixOperator::BitwiseXor => "^",
+            InfixOperator::LeftShift => "<<",
+            InfixOperator::RightShift => ">>",
+            InfixOperator::UnsignedRightShift => ">>>",
+            InfixOperator::Equals => "==",
+            InfixOperator::NotEquals => "!=",
+            InfixOperator::LessThan => "<",
+            InfixOperator::LessThanOrEqual => "<=",
+            InfixOperator::GreaterThan => ">",
+            InfixOperator::GreaterThanOrEqual => ">=",
+        }
+    }
+
+    /// Is this operator `<` or `<=`? Every comparison in a comparison chain must either use only
+    /// these operators or only `>` and `>=`.
+    pub fn is_less_than(&self) -> bool {
+        match self {
+            InfixOperator::LessThan | InfixOperator::LessThanOrEqual => true,
+            InfixOperator::GreaterThan | InfixOperator::GreaterThanOrEqual => false,
+            InfixOperator::Add
+            | InfixOperator::Subtract
+            | InfixOperator::Multiply
+            | InfixOperator::Divide
+            | InfixOperator::Remainder
+            | InfixOperator::Exponent
+            | InfixOperator::BitwiseAnd
+            | InfixOperator::BitwiseOr
+            | InfixOperator::BitwiseXor
+            | InfixOperator::LeftShift
+            | InfixOperator::RightShift
+            | InfixOperator::UnsignedRightShift
+            | InfixOperator::Equals
+            | InfixOperator::NotEquals => false,
+        }
+    }
+
+    /// Gets the name we print for this operator in our lisp debugging format.
+    fn lisp_name(&self) -> &'static str {
+        match self {
+            InfixOperator::Add => "add",
+            InfixOperator::Subtract => "sub",
+            InfixOperator::Multiply => "mul",
+            InfixOperator::Divide => "div",
+            InfixOperator::Remainder => "rem",
+            InfixOperator::Exponent => "exp",
+            InfixOperator::BitwiseAnd => "bit_and",
+            InfixOperator::BitwiseOr => "bit_or",
+            InfixOperator::BitwiseXor => "bit_xor",
+            InfixOperator::LeftShift => "shl",
+            InfixOperator::RightShift => "shr",
+            InfixOperator::UnsignedRightShift => "ushr",
+            InfixOperator::Equals => "eq",
+            InfixOperator::NotEquals => "neq",
+            InfixOperator::LessThan => "lt",
+            InfixOperator::LessThanOrEqual => "lte",
+            InfixOperator::GreaterThan => "gt",
+            InfixOperator::GreaterThanOrEqual => "gte",
+        }
+    }
+}
+
+/// A chain of relational comparisons like `a < b < c`. A chain means the same thing as comparing
+/// every pair of neighboring operands and combining the results with `&&`. So `a < b < c` means
+/// `a < b && b < c` except that `b` is only evaluated once.
+///
+/// We only build a chain for two or more comparisons. A single comparison is an
+/// `InfixExpression`.
+#[derive(Debug)]
+pub struct ComparisonExpression {
+    /// The first operand in our chain.
+    pub left: Expression,
+    /// Every comparison in our chain. Each comparison compares its operand to the operand
+    /// before it.
+    pub comparisons: Vec<Comparison>,
+}
+
+/// A single comparison in a `ComparisonExpression`.
+#[derive(Debug)]
+pub struct Comparison {
+    /// The relational operator for this comparison.
+    pub operator: InfixOperator,
+    /// The right-hand-side operand.
+    pub right: Expression,
+}
+
 /// A logical operation using infix syntax.
 ///
 /// These operators are separate from `InfixExpression` because logical operators may only
@@ -367,6 +912,20 @@ pub enum LogicalOperator {
     Or,
 }
 
+/// Calls a function with a value using pipe syntax. The value is passed as the first argument to
+/// the function so `x |> f(y)` means `f(x, y)`. If the right-hand-side is not a call then we call
+/// it with only the value so `x |> f` means `f(x)`.
+///
+/// Pipes let the programmer write a series of data transformations in the order they happen
+/// instead of nesting calls inside out.
+#[derive(Debug)]
+pub struct PipeExpression {
+    /// The value we pass as the first argument.
+    pub left: Expression,
+    /// The call we pass our value into.
+    pub right: Expression,
+}
+
 /// A conditional expression chooses a branch to take based on a test expression.
 #[derive(Debug)]
 pub struct ConditionalExpressionIf {
@@ -407,6 +966,65 @@ impl ConditionalExpressionIf {
     }
 }
 
+/// A case expression chooses a branch to take based on the first pattern which matches a
+/// test expression.
+///
+/// ```ite
+/// case shape {
+///   Circle { radius } -> radius,
+///   Square { side } -> side,
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CaseExpression {
+    /// The test expression.
+    pub test: Expression,
+    /// The arms we test in order. We execute the body of the first arm whose pattern matches.
+    pub arms: Vec<CaseExpressionArm>,
+}
+
+/// A single arm in a [`CaseExpression`].
+#[derive(Debug)]
+pub struct CaseExpressionArm {
+    /// The pattern our test expression must match for us to execute this arm.
+    pub pattern: Pattern,
+    /// Executes if the pattern matches. May use the names bound by our pattern.
+    pub body: Expression,
+}
+
+/// Executes a block for as long as a test expression is true. Always returns void.
+///
+/// ```ite
+/// while running() {
+///   tick();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct WhileExpression {
+    /// A label which `break` and `continue` statements may use to reference this loop.
+    pub label: Option<Name>,
+    /// We test this expression before every iteration and stop when it is false.
+    pub test: Expression,
+    /// The block we execute on every iteration.
+    pub body: Block,
+}
+
+/// Executes a block forever, or until we break out of it. The value of a loop expression is the
+/// value it was exited with.
+///
+/// ```ite
+/// outer: loop {
+///   break outer 42
+/// }
+/// ```
+#[derive(Debug)]
+pub struct LoopExpression {
+    /// A label which `break` and `continue` statements may use to reference this loop.
+    pub label: Option<Name>,
+    /// The block we execute on every iteration.
+    pub body: Block,
+}
+
 /// Wraps an expression in parentheses with an optional type annotation.
 #[derive(Debug)]
 pub struct WrappedExpression {
@@ -416,6 +1034,25 @@ pub struct WrappedExpression {
     pub annotation: Option<Type>,
 }
 
+/// A string with expressions interpolated into it. For example, `"Hello, ${name}!"`. The
+/// interpolated expressions are converted to strings when the template is evaluated.
+#[derive(Debug)]
+pub struct TemplateExpression {
+    /// The text before the first interpolated expression.
+    pub head: String,
+    /// Every interpolated expression along with the text which comes after it.
+    pub spans: Vec<TemplateSpan>,
+}
+
+/// An interpolated expression in a [`TemplateExpression`] along with the text which comes after it.
+#[derive(Debug)]
+pub struct TemplateSpan {
+    /// The interpolated expression.
+    pub expression: Expression,
+    /// The text after our interpolated expression and before the next one.
+    pub text: String,
+}
+
 impl Expression {
     fn new(range: Range, kind: ExpressionKind) -> Self {
         Expression { range, kind }
@@ -454,6 +1091,22 @@ impl Expression {
             })),
         )
     }
+
+    /// Create a pipe expression.
+    pub fn pipe(range: Range, left: Expression, right: Expression) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Pipe(Box::new(PipeExpression { left, right })),
+        )
+    }
+
+    /// Create a comparison expression.
+    pub fn comparison(range: Range, left: Expression, comparisons: Vec<Comparison>) -> Self {
+        Self::new(
+            range,
+            ExpressionKind::Comparison(Box::new(ComparisonExpression { left, comparisons })),
+        )
+    }
 }
 
 /// A pattern is used for binding a value to some names in the current block scope.
@@ -474,6 +1127,36 @@ pub enum PatternKind {
     Hole,
     /// Binds a class instance. May only be used as the first parameter in a method class member.
     This,
+    /// Matches an instance of a class and binds some of its fields.
+    Class(ClassPattern),
+    /// We wanted a pattern but found some other syntax instead. Carries the syntax
+    /// error diagnostic.
+    Error(DiagnosticRef),
+}
+
+/// Matches an instance of a class, or an instance of a class which extends it, and binds some of
+/// its fields.
+///
+/// ```ite
+/// Circle { radius }
+/// Line { start: Point { x, y }, end: _ }
+/// ```
+#[derive(Debug)]
+pub struct ClassPattern {
+    /// The class our value must be an instance of.
+    pub class: Name,
+    /// The fields we match against.
+    pub fields: Vec<ClassPatternField>,
+}
+
+/// A field in a [`ClassPattern`].
+#[derive(Debug)]
+pub struct ClassPatternField {
+    /// The name of the class field.
+    pub name: Name,
+    /// The pattern we match the field’s value against. If there is no pattern then we bind the
+    /// field’s value to a variable with the same name as the field.
+    pub value: Option<Pattern>,
 }
 
 /// Describes the values which may be assigned to a certain location.
@@ -530,13 +1213,16 @@ pub enum ScalarTypeKind {
 pub enum CompositeType {
     /// The type of a function. Functions may be passed around just like any other value.
     Function(FunctionType),
+    /// A type which may also be `null`. Written as `T?`.
+    Optional(OptionalType),
 }
 
 impl Type {
-    pub fn reference(range: Range, identifier: Identifier) -> Self {
+    pub fn reference(range: Range, identifier: Identifier, arguments: Vec<Type>) -> Self {
         Type::Reference(ReferenceType {
             range,
             identifier,
+            arguments,
             resolved: RefCell::new(None),
             _private: (),
         })
@@ -568,6 +1254,16 @@ impl Type {
         ))))
     }
 
+    pub fn optional(range: Range, type_: Type) -> Self {
+        Type::Resolved(ResolvedType::Composite(Rc::new(CompositeType::Optional(
+            OptionalType {
+                range,
+                type_,
+                _private: (),
+            },
+        ))))
+    }
+
     pub fn error(diagnostic: DiagnosticRef) -> Self {
         Type::Resolved(ResolvedType::Error(diagnostic))
     }
@@ -581,6 +1277,7 @@ impl Type {
             Type::Resolved(ResolvedType::Error(error)) => error.range,
             Type::Resolved(ResolvedType::Composite(composite)) => match &**composite {
                 CompositeType::Function(function) => function.range,
+                CompositeType::Optional(optional) => optional.range,
             },
         }
     }
@@ -599,6 +1296,7 @@ impl CompositeType {
                 }
                 visit(&function.return_);
             }
+            CompositeType::Optional(optional) => visit(&optional.type_),
         }
     }
 }
@@ -622,6 +1320,8 @@ pub struct ReferenceType {
     pub range: Range,
     /// The identifier the programmer wrote to reference their type.
     pub identifier: Identifier,
+    /// The types the programmer wrote for the type parameters of the type we are referencing.
+    pub arguments: Vec<Type>,
     /// The type we are referencing. We will not know the actual type until after we check the
     /// program. Until then our reference will be `None`.
     ///
@@ -633,6 +1333,12 @@ pub struct ReferenceType {
 }
 
 impl ReferenceType {
+    /// Gets the type our reference resolves to. If our reference has not been resolved yet then we
+    /// return `None`.
+    pub fn resolved(&self) -> Option<ResolvedType> {
+        self.resolved.borrow().clone()
+    }
+
     /// Resolves a reference type to a particular resolved type. You should not call this function
     /// if the reference type is already resolved!
     ///
@@ -670,12 +1376,24 @@ pub struct FunctionType {
     _private: (),
 }
 
+/// A type which may also be `null`. Written as `T?`.
+#[derive(Debug)]
+pub struct OptionalType {
+    /// The range of this optional type.
+    pub range: Range,
+    /// The type of our value when it is not `null`.
+    pub type_: Type,
+    /// The struct constructor should be private.
+    _private: (),
+}
+
 impl Constant {
     /// Prints an AST constant to a string.
     ///
     /// - Hexadecimal numbers are always printed in uppercase so that all glyphs have a
     ///   consistent height.
     /// - Floats greater than 1e10 are printed in scientific notation.
+    /// - Strings are printed with quotes and escape sequences.
     pub fn print(&self) -> String {
         match self {
             Constant::Boolean(value) => {
@@ -685,6 +1403,7 @@ impl Constant {
                     "false".to_string()
                 }
             }
+            Constant::Null => "null".to_string(),
             Constant::Integer(IntegerBase::Decimal, value) => value.to_str_radix(10),
             Constant::Integer(IntegerBase::Binary, value) => format!("0b{}", value.to_str_radix(2)),
             Constant::Integer(IntegerBase::Hexadecimal, value) => {
@@ -697,6 +1416,7 @@ impl Constant {
                     format!("{}", value)
                 }
             }
+            Constant::String(value) => StringLiteral::print(value),
         }
     }
 }
@@ -711,7 +1431,19 @@ impl Statement {
             StatementKind::Binding(binding) => {
                 StatementSnippet::Binding(binding.pattern.snippet(), binding.value.snippet())
             }
-            StatementKind::Return(_) => unimplemented!(),
+            StatementKind::Assignment(assignment) => StatementSnippet::Assignment(
+                assignment.target.snippet(),
+                assignment.operator.clone(),
+                assignment.value.snippet(),
+            ),
+            StatementKind::Return(argument) => {
+                StatementSnippet::Return(argument.as_ref().map(Expression::snippet))
+            }
+            StatementKind::Break(break_) => {
+                StatementSnippet::Break(break_.argument.as_ref().map(Expression::snippet))
+            }
+            StatementKind::Continue(_) => StatementSnippet::Continue,
+            StatementKind::Error(_) => StatementSnippet::Expression(ExpressionSnippet::Error),
         }
     }
 }
@@ -724,7 +1456,7 @@ impl Expression {
             ExpressionKind::Reference(identifier) => {
                 ExpressionSnippet::Reference(identifier.clone())
             }
-            ExpressionKind::This => unimplemented!(),
+            ExpressionKind::This => ExpressionSnippet::This,
             ExpressionKind::Function(function) => {
                 ExpressionSnippet::Function(VecSnippet::from_iter(
                     function
@@ -734,21 +1466,59 @@ impl Expression {
                 ))
             }
             ExpressionKind::Call(call) => ExpressionSnippet::Call(Box::new(call.callee.snippet())),
-            ExpressionKind::Construct(_) => unimplemented!(),
-            ExpressionKind::Member(_) => unimplemented!(),
+            ExpressionKind::Construct(construct) => {
+                ExpressionSnippet::Construct(construct.constructor.identifier.clone())
+            }
+            ExpressionKind::Member(member) => ExpressionSnippet::Member(
+                Box::new(member.object.snippet()),
+                member.property.identifier.clone(),
+            ),
+            ExpressionKind::List(_) => ExpressionSnippet::List,
+            ExpressionKind::Index(index) => ExpressionSnippet::Index(
+                Box::new(index.object.snippet()),
+                Box::new(index.index.snippet()),
+            ),
             ExpressionKind::Prefix(prefix) => ExpressionSnippet::Prefix(
                 prefix.operator.clone(),
                 Box::new(prefix.operand.snippet()),
             ),
-            ExpressionKind::Infix(_) => unimplemented!(),
+            ExpressionKind::Await(await_) => {
+                ExpressionSnippet::Await(Box::new(await_.operand.snippet()))
+            }
+            ExpressionKind::Infix(infix) => ExpressionSnippet::Infix(
+                Box::new(infix.left.snippet()),
+                infix.operator.clone(),
+                Box::new(infix.right.snippet()),
+            ),
+            ExpressionKind::Comparison(comparison) => ExpressionSnippet::Comparison(
+                Box::new(comparison.left.snippet()),
+                comparison
+                    .comparisons
+                    .iter()
+                    .map(|comparison| (comparison.operator.clone(), comparison.right.snippet()))
+                    .collect(),
+            ),
+            ExpressionKind::Pipe(pipe) => ExpressionSnippet::Pipe(
+                Box::new(pipe.left.snippet()),
+                Box::new(pipe.right.snippet()),
+            ),
             ExpressionKind::Logical(logical) => ExpressionSnippet::Logical(
                 Box::new(logical.left.snippet()),
                 logical.operator.clone(),
                 Box::new(logical.right.snippet()),
             ),
-            ExpressionKind::Conditional(_) => unimplemented!(),
+            ExpressionKind::Conditional(conditional) => {
+                ExpressionSnippet::Conditional(Box::new(conditional.test.snippet()))
+            }
+            ExpressionKind::Case(case) => ExpressionSnippet::Case(Box::new(case.test.snippet())),
+            ExpressionKind::While(while_) => {
+                ExpressionSnippet::While(Box::new(while_.test.snippet()))
+            }
+            ExpressionKind::Loop(_) => ExpressionSnippet::Loop,
             ExpressionKind::Block(_) => ExpressionSnippet::Block,
             ExpressionKind::Wrapped(wrapped) => wrapped.expression.snippet(),
+            ExpressionKind::Template(_) => ExpressionSnippet::Template,
+            ExpressionKind::Error(_) => ExpressionSnippet::Error,
         }
     }
 }
@@ -758,8 +1528,10 @@ impl Pattern {
     pub fn snippet(&self) -> PatternSnippet {
         match &self.kind {
             PatternKind::Binding(identifier) => PatternSnippet::Binding(identifier.clone()),
-            PatternKind::Hole => unimplemented!(),
+            PatternKind::Hole => PatternSnippet::Hole,
             PatternKind::This => unimplemented!(),
+            PatternKind::Class(class) => PatternSnippet::Class(class.class.identifier.clone()),
+            PatternKind::Error(_) => PatternSnippet::Error,
         }
     }
 }
@@ -781,8 +1553,49 @@ impl Declaration {
     /// Converts a declaration into an S-expression for debugging.
     fn lisp(&self, doc: &Document) -> Lisp {
         match self {
-            Declaration::Function(function) => function.function.lisp(doc, function.name.lisp(doc)),
-            Declaration::Class(class) => class.lisp(doc),
+            Declaration::Function(function) => {
+                let lisp = function.function.lisp(doc, function.name.lisp(doc));
+                let lisp = if function.export {
+                    lisp!("export", lisp)
+                } else {
+                    lisp
+                };
+                Attribute::lisp_all(&function.attributes, doc, lisp)
+            }
+            Declaration::Class(class) => {
+                let lisp = if class.export {
+                    lisp!("export", class.lisp(doc))
+                } else {
+                    class.lisp(doc)
+                };
+                Attribute::lisp_all(&class.attributes, doc, lisp)
+            }
+            Declaration::TypeAlias(type_alias) => {
+                let lisp = lisp!(
+                    "type",
+                    type_alias.name.lisp(doc),
+                    type_alias.value.lisp(doc)
+                );
+                let lisp = if type_alias.export {
+                    lisp!("export", lisp)
+                } else {
+                    lisp
+                };
+                Attribute::lisp_all(&type_alias.attributes, doc, lisp)
+            }
+            Declaration::Import(import) => {
+                let path = lisp!(
+                    "str",
+                    import.path_range.display(doc),
+                    StringLiteral::print(&import.path)
+                );
+                let mut expressions = Vec2::new("import".into(), path);
+                for name in &import.names {
+                    expressions.push(name.lisp(doc));
+                }
+                Lisp::List(expressions)
+            }
+            Declaration::Error(error) => lisp!("error", error.range.display(doc)),
         }
     }
 }
@@ -792,6 +1605,9 @@ impl ClassDeclaration {
     fn lisp(&self, doc: &Document) -> Lisp {
         let kind = if self.base { "base class" } else { "class" };
         let mut expressions = Vec2::new(kind.into(), self.name.lisp(doc));
+        for type_parameter in &self.type_parameters {
+            expressions.push(lisp!("tparam", type_parameter.lisp(doc)));
+        }
         if let Some(extends) = &self.extends {
             expressions.push(lisp!("extends", extends.lisp(doc)));
         }
@@ -802,9 +1618,30 @@ impl ClassDeclaration {
     }
 }
 
+impl Attribute {
+    /// Wraps the S-expression of a declaration or class member with the S-expressions of the
+    /// attributes written before it for debugging.
+    fn lisp_all(attributes: &[Attribute], doc: &Document, lisp: Lisp) -> Lisp {
+        attributes.iter().rev().fold(lisp, |lisp, attribute| {
+            let mut expressions = Vec2::new("attr".into(), attribute.range.display(doc).into());
+            expressions.push((&attribute.name.identifier).into());
+            for argument in &attribute.arguments {
+                expressions.push(argument.lisp(doc));
+            }
+            expressions.push(lisp);
+            Lisp::List(expressions)
+        })
+    }
+}
+
 impl ClassMember {
     /// Converts a class member into an S-expression for debugging.
     fn lisp(&self, doc: &Document) -> Lisp {
+        Attribute::lisp_all(self.attributes(), doc, self.lisp_without_attributes(doc))
+    }
+
+    /// Converts a class member into an S-expression for debugging without its attributes.
+    fn lisp_without_attributes(&self, doc: &Document) -> Lisp {
         match self {
             ClassMember::Field(field) => {
                 lisp!("field", field.name.lisp(doc), field.value.lisp(doc))
@@ -812,16 +1649,11 @@ impl ClassMember {
             ClassMember::Method(method) => method.function.lisp(doc, method.name.lisp(doc)),
             ClassMember::BaseMethod(method) => {
                 let mut expressions = Vec2::new("base fun".into(), method.name.lisp(doc));
+                for type_parameter in &method.type_parameters {
+                    expressions.push(lisp!("tparam", type_parameter.lisp(doc)));
+                }
                 for parameter in &method.parameters {
-                    if let Some(annotation) = &parameter.annotation {
-                        expressions.push(lisp!(
-                            "param",
-                            parameter.pattern.lisp(doc),
-                            lisp!("type", annotation.lisp(doc))
-                        ));
-                    } else {
-                        expressions.push(lisp!("param", parameter.pattern.lisp(doc)));
-                    }
+                    expressions.push(parameter.lisp(doc));
                 }
                 expressions.push(lisp!("type", method.return_type.lisp(doc)));
                 Lisp::List(expressions)
@@ -834,17 +1666,13 @@ impl Function {
     /// Converts a function to a symbolic expression. Accepts a name S-expression parameter for
     /// debugging some name for the function.
     fn lisp(&self, doc: &Document, name: Lisp) -> Lisp {
-        let mut expressions = Vec2::new("fun".into(), name);
+        let keyword = if self.async_ { "async fun" } else { "fun" };
+        let mut expressions = Vec2::new(keyword.into(), name);
+        for type_parameter in &self.type_parameters {
+            expressions.push(lisp!("tparam", type_parameter.lisp(doc)));
+        }
         for parameter in &self.parameters {
-            if let Some(annotation) = &parameter.annotation {
-                expressions.push(lisp!(
-                    "param",
-                    parameter.pattern.lisp(doc),
-                    lisp!("type", annotation.lisp(doc))
-                ));
-            } else {
-                expressions.push(lisp!("param", parameter.pattern.lisp(doc)));
-            }
+            expressions.push(parameter.lisp(doc));
         }
         if let Some(return_type) = &self.return_type {
             expressions.push(lisp!("type", return_type.lisp(doc)));
@@ -854,6 +1682,20 @@ impl Function {
     }
 }
 
+impl FunctionParameter {
+    /// Converts a function parameter to a symbolic expression.
+    fn lisp(&self, doc: &Document) -> Lisp {
+        let mut expressions = Vec2::new("param".into(), self.pattern.lisp(doc));
+        if let Some(annotation) = &self.annotation {
+            expressions.push(lisp!("type", annotation.lisp(doc)));
+        }
+        if let Some(default) = &self.default {
+            expressions.push(lisp!("default", default.lisp(doc)));
+        }
+        Lisp::List(expressions)
+    }
+}
+
 impl Block {
     /// Converts a block to a symbolic expression.
     fn lisp(&self, doc: &Document) -> Lisp {
@@ -877,22 +1719,34 @@ impl Statement {
         match &self.kind {
             StatementKind::Expression(expression) => expression.lisp(doc),
             StatementKind::Binding(binding) => {
+                let mut expressions = Vec2::new("let".into(), range);
+                if binding.mutable {
+                    expressions.push("mut".into());
+                }
+                expressions.push(binding.pattern.lisp(doc));
                 if let Some(annotation) = &binding.annotation {
-                    lisp!(
-                        "let",
-                        range,
-                        binding.pattern.lisp(doc),
-                        lisp!("type", annotation.lisp(doc)),
-                        binding.value.lisp(doc)
-                    )
-                } else {
-                    lisp!(
-                        "let",
-                        range,
-                        binding.pattern.lisp(doc),
-                        binding.value.lisp(doc)
-                    )
+                    expressions.push(lisp!("type", annotation.lisp(doc)));
                 }
+                expressions.push(binding.value.lisp(doc));
+                Lisp::List(expressions)
+            }
+            StatementKind::Assignment(assignment) => {
+                let mut expressions = Vec2::new("set".into(), range);
+                if let Some(operator) = &assignment.operator {
+                    expressions.push(
+                        match operator {
+                            AssignmentOperator::Add => "add",
+                            AssignmentOperator::Subtract => "sub",
+                            AssignmentOperator::Multiply => "mul",
+                            AssignmentOperator::Divide => "div",
+                            AssignmentOperator::Remainder => "rem",
+                        }
+                        .into(),
+                    );
+                }
+                expressions.push(assignment.target.lisp(doc));
+                expressions.push(assignment.value.lisp(doc));
+                Lisp::List(expressions)
             }
             StatementKind::Return(argument) => {
                 if let Some(argument) = argument {
@@ -901,6 +1755,24 @@ impl Statement {
                     lisp!("return", range)
                 }
             }
+            StatementKind::Break(break_) => {
+                let mut expressions = Vec2::new("break".into(), range);
+                if let Some(label) = &break_.label {
+                    expressions.push(label.lisp(doc));
+                }
+                if let Some(argument) = &break_.argument {
+                    expressions.push(argument.lisp(doc));
+                }
+                Lisp::List(expressions)
+            }
+            StatementKind::Continue(continue_) => {
+                if let Some(label) = &continue_.label {
+                    lisp!("continue", range, label.lisp(doc))
+                } else {
+                    lisp!("continue", range)
+                }
+            }
+            StatementKind::Error(_) => lisp!("error", range),
         }
     }
 }
@@ -911,10 +1783,12 @@ impl Constant {
     fn lisp(&self, range: Lisp) -> Lisp {
         let kind = match self {
             Constant::Boolean(_) => "bool",
+            Constant::Null => return lisp!("null", range),
             Constant::Integer(IntegerBase::Decimal, _) => "int",
             Constant::Integer(IntegerBase::Binary, _) => "bin",
             Constant::Integer(IntegerBase::Hexadecimal, _) => "hex",
             Constant::Float(_) => "float",
+            Constant::String(_) => "str",
         };
         lisp!(kind, range, self.print())
     }
@@ -932,14 +1806,27 @@ impl Expression {
             ExpressionKind::Call(call) => {
                 let mut expressions = Vec2::new("call".into(), range);
                 expressions.push(call.callee.lisp(doc));
+                for type_argument in &call.type_arguments {
+                    expressions.push(lisp!("type", type_argument.lisp(doc)));
+                }
                 for argument in &call.arguments {
                     expressions.push(argument.lisp(doc));
                 }
+                for argument in &call.named_arguments {
+                    expressions.push(lisp!(
+                        "named",
+                        argument.name.lisp(doc),
+                        argument.value.lisp(doc)
+                    ));
+                }
                 Lisp::List(expressions)
             }
             ExpressionKind::Construct(construct) => {
                 let mut expressions = Vec2::new("new".into(), range);
                 expressions.push(construct.constructor.lisp(doc));
+                for type_argument in &construct.type_arguments {
+                    expressions.push(lisp!("type", type_argument.lisp(doc)));
+                }
                 for field in &construct.fields {
                     expressions.push(lisp!(field.name.lisp(doc), field.value.lisp(doc)));
                 }
@@ -951,34 +1838,54 @@ impl Expression {
                 assert_eq!(member.object.range.union(member.property.range), self.range);
                 lisp!("prop", member.object.lisp(doc), member.property.lisp(doc))
             }
+            ExpressionKind::List(list) => {
+                let mut expressions = Vec2::new("list".into(), range);
+                for element in &list.elements {
+                    expressions.push(element.lisp(doc));
+                }
+                Lisp::List(expressions)
+            }
+            ExpressionKind::Index(index) => {
+                lisp!(
+                    "index",
+                    range,
+                    index.object.lisp(doc),
+                    index.index.lisp(doc)
+                )
+            }
             ExpressionKind::Prefix(prefix) => {
                 let operator = match &prefix.operator {
                     PrefixOperator::Not => "not",
                     PrefixOperator::Negative => "neg",
                     PrefixOperator::Positive => "pos",
+                    PrefixOperator::BitwiseNot => "bit_not",
                 };
                 lisp!(operator, range, prefix.operand.lisp(doc))
             }
+            ExpressionKind::Await(await_) => lisp!("await", range, await_.operand.lisp(doc)),
             ExpressionKind::Infix(infix) => {
-                let operator = match &infix.operator {
-                    InfixOperator::Add => "add",
-                    InfixOperator::Subtract => "sub",
-                    InfixOperator::Multiply => "mul",
-                    InfixOperator::Divide => "div",
-                    InfixOperator::Remainder => "rem",
-                    InfixOperator::Exponent => "exp",
-                    InfixOperator::Equals => "eq",
-                    InfixOperator::NotEquals => "neq",
-                    InfixOperator::LessThan => "lt",
-                    InfixOperator::LessThanOrEqual => "lte",
-                    InfixOperator::GreaterThan => "gt",
-                    InfixOperator::GreaterThanOrEqual => "gte",
-                };
+                let operator = infix.operator.lisp_name();
                 // We don’t print the range of an infix expression since it should be obvious. We
                 // will assert that the range is as we expect instead.
                 assert_eq!(infix.left.range.union(infix.right.range), self.range);
                 lisp!(operator, infix.left.lisp(doc), infix.right.lisp(doc))
             }
+            ExpressionKind::Comparison(comparison) => {
+                let mut expressions = Vec2::new("compare".into(), comparison.left.lisp(doc));
+                for Comparison { operator, right } in &comparison.comparisons {
+                    expressions.push(operator.lisp_name().into());
+                    expressions.push(right.lisp(doc));
+                }
+                // Like an infix expression we don’t print the range of a comparison expression.
+                assert_eq!(
+                    comparison
+                        .left
+                        .range
+                        .union(comparison.comparisons.last().unwrap().right.range),
+                    self.range
+                );
+                Lisp::List(expressions)
+            }
             ExpressionKind::Logical(logical) => {
                 let operator = match &logical.operator {
                     LogicalOperator::And => "and",
@@ -989,7 +1896,37 @@ impl Expression {
                 assert_eq!(logical.left.range.union(logical.right.range), self.range);
                 lisp!(operator, logical.left.lisp(doc), logical.right.lisp(doc))
             }
+            ExpressionKind::Pipe(pipe) => {
+                // Like an infix expression we don’t print the range of a pipe expression.
+                assert_eq!(pipe.left.range.union(pipe.right.range), self.range);
+                lisp!("pipe", pipe.left.lisp(doc), pipe.right.lisp(doc))
+            }
             ExpressionKind::Conditional(conditional) => conditional.lisp(doc),
+            ExpressionKind::Case(case) => {
+                let mut expressions = Vec2::new("case".into(), range);
+                expressions.push(case.test.lisp(doc));
+                for arm in &case.arms {
+                    expressions.push(lisp!("arm", arm.pattern.lisp(doc), arm.body.lisp(doc)));
+                }
+                Lisp::List(expressions)
+            }
+            ExpressionKind::While(while_) => {
+                let mut expressions = Vec2::new("while".into(), range);
+                if let Some(label) = &while_.label {
+                    expressions.push(label.lisp(doc));
+                }
+                expressions.push(while_.test.lisp(doc));
+                expressions.push(while_.body.lisp(doc));
+                Lisp::List(expressions)
+            }
+            ExpressionKind::Loop(loop_) => {
+                let mut expressions = Vec2::new("loop".into(), range);
+                if let Some(label) = &loop_.label {
+                    expressions.push(label.lisp(doc));
+                }
+                expressions.push(loop_.body.lisp(doc));
+                Lisp::List(expressions)
+            }
             ExpressionKind::Block(block) => block.lisp(doc),
             ExpressionKind::Wrapped(wrapped) => {
                 if let Some(annotation) = &wrapped.annotation {
@@ -1003,6 +1940,16 @@ impl Expression {
                     lisp!("wrap", range, wrapped.expression.lisp(doc))
                 }
             }
+            ExpressionKind::Template(template) => {
+                let mut expressions = Vec2::new("template".into(), range);
+                expressions.push(StringLiteral::print(&template.head).into());
+                for span in &template.spans {
+                    expressions.push(span.expression.lisp(doc));
+                    expressions.push(StringLiteral::print(&span.text).into());
+                }
+                Lisp::List(expressions)
+            }
+            ExpressionKind::Error(_) => lisp!("error", range),
         }
     }
 }
@@ -1036,6 +1983,18 @@ impl Pattern {
             PatternKind::Binding(identifier) => lisp!("var", range, identifier),
             PatternKind::Hole => lisp!("hole", range),
             PatternKind::This => lisp!("this", range),
+            PatternKind::Class(class) => {
+                let mut expressions = Vec2::new("class".into(), range);
+                expressions.push(class.class.lisp(doc));
+                for field in &class.fields {
+                    expressions.push(match &field.value {
+                        None => field.name.lisp(doc),
+                        Some(value) => lisp!(field.name.lisp(doc), value.lisp(doc)),
+                    });
+                }
+                Lisp::List(expressions)
+            }
+            PatternKind::Error(_) => lisp!("error", range),
         }
     }
 }
@@ -1045,7 +2004,14 @@ impl Type {
     fn lisp(&self, doc: &Document) -> Lisp {
         let range: Lisp = self.range().display(doc).into();
         match self {
-            Type::Reference(reference) => lisp!("var", range, &reference.identifier),
+            Type::Reference(reference) => {
+                let mut expressions = Vec2::new("var".into(), range);
+                expressions.push((&reference.identifier).into());
+                for argument in &reference.arguments {
+                    expressions.push(argument.lisp(doc));
+                }
+                Lisp::List(expressions)
+            }
             Type::This(_) => lisp!("this", range),
             Type::Resolved(ResolvedType::Scalar(scalar)) => match &scalar.kind {
                 ScalarTypeKind::Void => lisp!("void", range),
@@ -1060,6 +2026,9 @@ impl Type {
                     expressions.push(function.return_.lisp(doc));
                     Lisp::List(Vec2::from_vec(expressions))
                 }
+                CompositeType::Optional(optional) => {
+                    lisp!("optional", range, optional.type_.lisp(doc))
+                }
             },
             Type::Resolved(ResolvedType::Error(_)) => lisp!("error", range),
         }
@@ -203,7 +203,7 @@ enum DocumentCharsLookahead {
 /// We need to keep this small as an AST will contain a _lot_ of positions. Currently 32 bits.
 ///
 /// [1]: https://microsoft.github.io/language-server-protocol/specification
-#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Position(
     /// The UTF-8 index in our source document for this position.
     u32,
@@ -281,7 +281,7 @@ impl Position {
 /// selection in an editor. Therefore the end position is exclusive.
 ///
 /// We need to keep this small as an AST will contain a _lot_ of ranges. Currently 64 bits.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Range {
     /// The range’s start position.
     start: Position,
@@ -2,6 +2,7 @@ use super::document::{Document, DocumentChars, Position, Range};
 use crate::diagnostics::*;
 use num::BigInt;
 use std::cmp;
+use std::collections::VecDeque;
 use std::f64;
 use std::fmt;
 use std::hash::{Hash, Hasher};
@@ -38,6 +39,8 @@ pub enum TokenKind {
     Identifier(Identifier),
     /// Some number written in the program.
     Number(Number),
+    /// Some string of text written in the program.
+    String(StringLiteral),
     /// An unexpected character in the program.
     UnexpectedChar(char),
 }
@@ -61,6 +64,12 @@ impl<'src> Token<'src> {
             TokenKind::Glyph(glyph) => UnexpectedSyntax::Glyph(*glyph),
             TokenKind::Identifier(_) => UnexpectedSyntax::Identifier,
             TokenKind::Number(_) => UnexpectedSyntax::Number,
+            // The programmer sees a string which continues after an interpolated expression as
+            // a `}`.
+            TokenKind::String(string) => match string.part {
+                StringPart::Complete | StringPart::Head => UnexpectedSyntax::String,
+                StringPart::Middle | StringPart::Tail => UnexpectedSyntax::Glyph(Glyph::BraceRight),
+            },
             TokenKind::UnexpectedChar(c) => UnexpectedSyntax::Char(*c),
         }
     }
@@ -84,6 +93,7 @@ impl<'src> Token<'src> {
             TokenKind::Glyph(glyph) => source.push_str(glyph.as_str()),
             TokenKind::Identifier(identifier) => source.push_str(&identifier.as_str()),
             TokenKind::Number(number) => source.push_str(&number.raw),
+            TokenKind::String(string) => source.push_str(&string.raw),
             TokenKind::UnexpectedChar(c) => source.push(*c),
         }
         for trivia in &self.trailing_trivia {
@@ -128,6 +138,12 @@ pub enum Glyph {
     Arrow,
     /// `*`
     Asterisk,
+    /// `**`
+    AsteriskDouble,
+    /// `*=`
+    AsteriskEquals,
+    /// `@`
+    At,
     /// `!`
     Bang,
     /// `|`
@@ -162,22 +178,38 @@ pub enum Glyph {
     GreaterThanOrEqual,
     /// `<`
     LessThan,
+    /// `<<`
+    LessThanDouble,
     /// `<=`
     LessThanOrEqual,
     /// `-`
     Minus,
+    /// `-=`
+    MinusEquals,
     /// `(`
     ParenLeft,
     /// `)`
     ParenRight,
     /// `%`
     Percent,
+    /// `%=`
+    PercentEquals,
+    /// `|>`
+    Pipe,
     /// `+`
     Plus,
+    /// `+=`
+    PlusEquals,
+    /// `?`
+    Question,
     /// `;`
     Semicolon,
     /// `/`
     Slash,
+    /// `/=`
+    SlashEquals,
+    /// `~`
+    Tilde,
 }
 
 impl Glyph {
@@ -190,6 +222,9 @@ impl Glyph {
             AmpersandDouble => "&&",
             Arrow => "->",
             Asterisk => "*",
+            AsteriskDouble => "**",
+            AsteriskEquals => "*=",
+            At => "@",
             Bang => "!",
             Bar => "|",
             BarDouble => "||",
@@ -207,14 +242,22 @@ impl Glyph {
             GreaterThan => ">",
             GreaterThanOrEqual => ">=",
             LessThan => "<",
+            LessThanDouble => "<<",
             LessThanOrEqual => "<=",
             Minus => "-",
+            MinusEquals => "-=",
             ParenLeft => "(",
             ParenRight => ")",
             Percent => "%",
+            PercentEquals => "%=",
+            Pipe => "|>",
             Plus => "+",
+            PlusEquals => "+=",
+            Question => "?",
             Semicolon => ";",
             Slash => "/",
+            SlashEquals => "/=",
+            Tilde => "~",
         }
     }
 }
@@ -230,12 +273,20 @@ pub enum Keyword {
     True,
     /// `false`
     False,
+    /// `null`
+    Null,
     /// `void`
     Void,
     /// `fun`
     Fun,
+    /// `async`
+    Async,
+    /// `await`
+    Await,
     /// `let`
     Let,
+    /// `mut`
+    Mut,
     /// `if`
     If,
     /// `else`
@@ -244,6 +295,16 @@ pub enum Keyword {
     Return,
     /// `do`
     Do,
+    /// `case`
+    Case,
+    /// `loop`
+    Loop,
+    /// `while`
+    While,
+    /// `break`
+    Break,
+    /// `continue`
+    Continue,
     /// `this`
     This,
 }
@@ -256,13 +317,22 @@ impl Keyword {
             "_" => Some(Hole),
             "true" => Some(True),
             "false" => Some(False),
+            "null" => Some(Null),
             "void" => Some(Void),
             "fun" => Some(Fun),
+            "async" => Some(Async),
+            "await" => Some(Await),
             "let" => Some(Let),
+            "mut" => Some(Mut),
             "if" => Some(If),
             "else" => Some(Else),
             "return" => Some(Return),
             "do" => Some(Do),
+            "case" => Some(Case),
+            "loop" => Some(Loop),
+            "while" => Some(While),
+            "break" => Some(Break),
+            "continue" => Some(Continue),
             "this" => Some(This),
             _ => None,
         }
@@ -275,13 +345,22 @@ impl Keyword {
             Hole => "_",
             True => "true",
             False => "false",
+            Null => "null",
             Void => "void",
             Fun => "fun",
+            Async => "async",
+            Await => "await",
             Let => "let",
+            Mut => "mut",
             If => "if",
             Else => "else",
             Return => "return",
             Do => "do",
+            Case => "case",
+            Loop => "loop",
+            While => "while",
+            Break => "break",
+            Continue => "continue",
             This => "this",
         }
     }
@@ -301,6 +380,14 @@ pub enum IdentifierKeyword {
     Base,
     /// `extends`
     Extends,
+    /// `import`
+    Import,
+    /// `export`
+    Export,
+    /// `from`
+    From,
+    /// `type`
+    Type,
 }
 
 impl IdentifierKeyword {
@@ -311,6 +398,10 @@ impl IdentifierKeyword {
             Class => "class",
             Base => "base",
             Extends => "extends",
+            Import => "import",
+            Export => "export",
+            From => "from",
+            Type => "type",
         }
     }
 
@@ -497,6 +588,72 @@ pub enum NumberKind {
     Invalid(DiagnosticRef),
 }
 
+/// A string of text in Brite source code is written between double quotes (`"`). For example,
+/// `"Hello, world!"`. Strings may not span multiple lines.
+///
+/// A backslash (`\`) inside of a string starts an escape sequence. We support the escape
+/// sequences:
+///
+/// - `\"`: A double quote.
+/// - `\\`: A backslash.
+/// - `\n`: A line feed.
+/// - `\r`: A carriage return.
+/// - `\t`: A tab.
+/// - `\u{1F600}`: Any Unicode character written as one to six hexadecimal digits.
+/// - `\$`: A dollar sign. Useful for writing `${` without starting an interpolation.
+///
+/// Strings may interpolate expressions between `${` and `}`. For example, `"Hello, ${name}!"`. We
+/// split such a string into many tokens. The text of the string is divided into [`StringPart`]s
+/// and the interpolated expressions are tokenized as normal code in between.
+pub struct StringLiteral {
+    /// The raw string this string literal was parsed from. Includes the quotes and
+    /// interpolation delimiters.
+    raw: String,
+    /// The value of our string with all escape sequences resolved. If we reported a diagnostic
+    /// while parsing our string then we have the first diagnostic instead.
+    pub value: Result<String, DiagnosticRef>,
+    /// Which part of a string with interpolations this token is.
+    pub part: StringPart,
+}
+
+/// Which part of a string with interpolations a [`StringLiteral`] token is. We borrow the names
+/// from JavaScript template literal tokens.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StringPart {
+    /// `"..."`: A string with no interpolations.
+    Complete,
+    /// `"...${`: The start of a string before its first interpolation.
+    Head,
+    /// `}...${`: The text of a string in between two interpolations.
+    Middle,
+    /// `}..."`: The end of a string after its last interpolation.
+    Tail,
+}
+
+impl StringLiteral {
+    /// Prints a string value as Brite source code. The opposite of parsing a string literal. We
+    /// escape any character which may not be written directly in a string literal.
+    pub fn print(value: &str) -> String {
+        let mut source = String::with_capacity(value.len() + 2);
+        source.push('"');
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => source.push_str("\\\""),
+                '\\' => source.push_str("\\\\"),
+                '$' if chars.peek() == Some(&'{') => source.push_str("\\$"),
+                '\n' => source.push_str("\\n"),
+                '\r' => source.push_str("\\r"),
+                '\t' => source.push_str("\\t"),
+                c if c.is_control() => source.push_str(&format!("\\u{{{:X}}}", c as u32)),
+                c => source.push(c),
+            }
+        }
+        source.push('"');
+        source
+    }
+}
+
 /// Pieces of Brite syntax which (usually) don’t affect program behavior. Like comments or spaces.
 pub enum Trivia<'src> {
     /// Contiguous space characters (` `).
@@ -574,9 +731,18 @@ pub struct Lexer<'errs, 'src> {
     chars: DocumentChars<'src>,
     /// The last token in a document. If we have an end token then the lexer is done iterating.
     end: Option<EndToken<'src>>,
-    /// The programmer may lookahead at the next token, if they have done so we’ll have a
-    /// token here.
-    lookahead: Option<Option<Token<'src>>>,
+    /// The programmer may lookahead at upcoming tokens, if they have done so we’ll have those
+    /// tokens here in the order they appear in source code. `None` means we’ve reached the end.
+    lookahead: VecDeque<Option<Token<'src>>>,
+    /// For every string interpolation we are currently inside of, the range of the `${` which
+    /// opened it and the number of braces we’ve opened in the interpolated expression but have not
+    /// yet closed. When we see a `}` and there are no unclosed braces then we continue lexing
+    /// the string.
+    interpolations: Vec<(Range, usize)>,
+    /// The interpolations we were inside of when we saw a newline and the position at the end of
+    /// their line. We end each one with an empty string tail token at the end of its line before
+    /// lexing the next token on the new line.
+    unclosed_interpolations: Vec<(Range, Position)>,
 }
 
 impl<'errs, 'src> Lexer<'errs, 'src> {
@@ -590,7 +756,9 @@ impl<'errs, 'src> Lexer<'errs, 'src> {
             document,
             chars: document.chars(),
             end: None,
-            lookahead: None,
+            lookahead: VecDeque::new(),
+            interpolations: Vec::new(),
+            unclosed_interpolations: Vec::new(),
         }
     }
 
@@ -602,13 +770,20 @@ impl<'errs, 'src> Lexer<'errs, 'src> {
     /// returns `None`, then [`Lexer::lookahead_end`] will return an [`EndToken`] even though
     /// [`Lexer::advance`] has not advanced the lexer to the end.
     pub fn lookahead(&mut self) -> Option<&Token<'src>> {
-        if self.lookahead.is_none() {
-            self.lookahead = Some(self.advance());
-        }
-        match &self.lookahead {
-            Some(next) => next.as_ref(),
-            None => unreachable!(),
+        self.lookahead_nth(0)
+    }
+
+    /// Look at the token `n` tokens after the next token without advancing the iterator. So
+    /// `lookahead_nth(0)` is the same as [`Lexer::lookahead`]. Our grammar almost always only needs
+    /// one token of lookahead. Use this sparingly for the few places where it does not.
+    ///
+    /// Just like [`Lexer::lookahead`], peeking will advance the lexer’s end token state.
+    pub fn lookahead_nth(&mut self, n: usize) -> Option<&Token<'src>> {
+        while self.lookahead.len() <= n {
+            let next = self.next();
+            self.lookahead.push_back(next);
         }
+        self.lookahead[n].as_ref()
     }
 
     /// When one is done with their lexer they may call this function to get the final [`EndToken`].
@@ -646,34 +821,73 @@ impl<'errs, 'src> Lexer<'errs, 'src> {
     /// be hidden or broken by iterator composition.
     pub fn advance(&mut self) -> Option<Token<'src>> {
         // If we’ve peeked, then let’s return the peeked iterator step.
-        if let Some(next) = self.lookahead.take() {
+        if let Some(next) = self.lookahead.pop_front() {
             return next;
         }
+        self.next()
+    }
 
+    /// Lexes the next token in our document ignoring any tokens we’ve peeked at.
+    fn next(&mut self) -> Option<Token<'src>> {
         // If we have ended then keep returning `None`.
         if self.end.is_some() {
             return None;
         }
 
+        let trivia_start = self.chars.position();
         let leading_trivia = self.next_trivia(true);
+
+        // Strings may not span multiple lines so neither may the expressions interpolated into
+        // them. If we see a newline then we end all of our interpolations. This keeps an unclosed
+        // interpolation from turning the rest of our document into a string. The innermost
+        // interpolation is ended first.
+        if !self.interpolations.is_empty() {
+            for trivia in &leading_trivia {
+                if let Trivia::Newlines(_, _) = trivia {
+                    let interpolations = self.interpolations.drain(..);
+                    self.unclosed_interpolations
+                        .extend(interpolations.map(|(range, _)| (range, trivia_start)));
+                    break;
+                }
+            }
+        }
+
+        // End an unclosed interpolation with an empty string tail at the end of its line. The
+        // string has an error so that the parser won’t report some other confusing error.
+        if let Some((range, end)) = self.unclosed_interpolations.pop() {
+            let diagnostic = self.report_diagnostic(Diagnostic::unclosed_interpolation(range));
+            return Some(Token {
+                range: Range::position(end),
+                leading_trivia,
+                trailing_trivia: Vec::new(),
+                kind: TokenKind::String(StringLiteral {
+                    raw: String::new(),
+                    value: Err(diagnostic),
+                    part: StringPart::Tail,
+                }),
+            });
+        }
         let start = self.chars.position();
 
         let kind = match self.chars.advance() {
             // Single character glyphs
-            Some('*') => TokenKind::Glyph(Glyph::Asterisk),
-            Some('{') => TokenKind::Glyph(Glyph::BraceLeft),
-            Some('}') => TokenKind::Glyph(Glyph::BraceRight),
+            Some('{') => {
+                if let Some((_, depth)) = self.interpolations.last_mut() {
+                    *depth += 1;
+                }
+                TokenKind::Glyph(Glyph::BraceLeft)
+            }
             Some('[') => TokenKind::Glyph(Glyph::BracketLeft),
             Some(']') => TokenKind::Glyph(Glyph::BracketRight),
             Some('^') => TokenKind::Glyph(Glyph::Caret),
             Some(':') => TokenKind::Glyph(Glyph::Colon),
             Some(',') => TokenKind::Glyph(Glyph::Comma),
+            Some('@') => TokenKind::Glyph(Glyph::At),
             Some('(') => TokenKind::Glyph(Glyph::ParenLeft),
             Some(')') => TokenKind::Glyph(Glyph::ParenRight),
-            Some('%') => TokenKind::Glyph(Glyph::Percent),
-            Some('+') => TokenKind::Glyph(Glyph::Plus),
+            Some('?') => TokenKind::Glyph(Glyph::Question),
             Some(';') => TokenKind::Glyph(Glyph::Semicolon),
-            Some('/') => TokenKind::Glyph(Glyph::Slash),
+            Some('~') => TokenKind::Glyph(Glyph::Tilde),
 
             // Multiple character glyphs
             Some('&') => match self.chars.lookahead() {
@@ -688,6 +902,10 @@ impl<'errs, 'src> Lexer<'errs, 'src> {
                     self.chars.advance();
                     TokenKind::Glyph(Glyph::BarDouble)
                 }
+                Some('>') => {
+                    self.chars.advance();
+                    TokenKind::Glyph(Glyph::Pipe)
+                }
                 _ => TokenKind::Glyph(Glyph::Bar),
             },
             Some('=') => match self.chars.lookahead() {
@@ -704,6 +922,9 @@ impl<'errs, 'src> Lexer<'errs, 'src> {
                 }
                 _ => TokenKind::Glyph(Glyph::Bang),
             },
+            // We never lex `>>` as a single glyph since it closes two type argument lists in
+            // `List<List<Int>>`. Our parser combines neighboring `>` glyphs into the shift
+            // operators instead.
             Some('>') => match self.chars.lookahead() {
                 Some('=') => {
                     self.chars.advance();
@@ -712,6 +933,10 @@ impl<'errs, 'src> Lexer<'errs, 'src> {
                 _ => TokenKind::Glyph(Glyph::GreaterThan),
             },
             Some('<') => match self.chars.lookahead() {
+                Some('<') => {
+                    self.chars.advance();
+                    TokenKind::Glyph(Glyph::LessThanDouble)
+                }
                 Some('=') => {
                     self.chars.advance();
                     TokenKind::Glyph(Glyph::LessThanOrEqual)
@@ -723,8 +948,44 @@ impl<'errs, 'src> Lexer<'errs, 'src> {
                     self.chars.advance();
                     TokenKind::Glyph(Glyph::Arrow)
                 }
+                Some('=') => {
+                    self.chars.advance();
+                    TokenKind::Glyph(Glyph::MinusEquals)
+                }
                 _ => TokenKind::Glyph(Glyph::Minus),
             },
+            Some('*') => match self.chars.lookahead() {
+                Some('*') => {
+                    self.chars.advance();
+                    TokenKind::Glyph(Glyph::AsteriskDouble)
+                }
+                Some('=') => {
+                    self.chars.advance();
+                    TokenKind::Glyph(Glyph::AsteriskEquals)
+                }
+                _ => TokenKind::Glyph(Glyph::Asterisk),
+            },
+            Some('%') => match self.chars.lookahead() {
+                Some('=') => {
+                    self.chars.advance();
+                    TokenKind::Glyph(Glyph::PercentEquals)
+                }
+                _ => TokenKind::Glyph(Glyph::Percent),
+            },
+            Some('+') => match self.chars.lookahead() {
+                Some('=') => {
+                    self.chars.advance();
+                    TokenKind::Glyph(Glyph::PlusEquals)
+                }
+                _ => TokenKind::Glyph(Glyph::Plus),
+            },
+            Some('/') => match self.chars.lookahead() {
+                Some('=') => {
+                    self.chars.advance();
+                    TokenKind::Glyph(Glyph::SlashEquals)
+                }
+                _ => TokenKind::Glyph(Glyph::Slash),
+            },
 
             // Identifier
             Some(c) if Identifier::is_start(c) => {
@@ -929,6 +1190,23 @@ impl<'errs, 'src> Lexer<'errs, 'src> {
                 }
             }
 
+            // If we are inside of a string interpolation and there are no unclosed braces then this
+            // brace closes our interpolation and we continue lexing the string.
+            Some('}') => match self.interpolations.last_mut() {
+                Some((_, 0)) => {
+                    self.interpolations.pop();
+                    TokenKind::String(self.next_string('}'))
+                }
+                Some((_, depth)) => {
+                    *depth -= 1;
+                    TokenKind::Glyph(Glyph::BraceRight)
+                }
+                None => TokenKind::Glyph(Glyph::BraceRight),
+            },
+
+            // String
+            Some('"') => TokenKind::String(self.next_string('"')),
+
             // If we encountered an unexpected character then add an unexpected character token.
             Some(c) => TokenKind::UnexpectedChar(c),
 
@@ -956,6 +1234,150 @@ impl<'errs, 'src> Lexer<'errs, 'src> {
         })
     }
 
+    /// Parses the rest of a string literal after we’ve advanced past its opening character. Either
+    /// a quote (`"`) which starts a string or a brace (`}`) which ends an interpolation.
+    ///
+    /// If we see an invalid escape sequence then we report a diagnostic but keep going until the
+    /// string ends so that the rest of the string doesn’t get parsed as code. A string ends at its
+    /// closing quote, at the start of an interpolation, at the end of the line, or at the end of
+    /// the file.
+    fn next_string(&mut self, open: char) -> StringLiteral {
+        let mut raw = String::new();
+        raw.push(open);
+        let mut value = String::new();
+        let mut error = None;
+        let mut interpolation = false;
+
+        loop {
+            match self.chars.lookahead() {
+                // If we see a closing quote then our string is done.
+                Some('"') => {
+                    raw.push(self.chars.advance().unwrap());
+                    break;
+                }
+
+                // If we see `${` then we start an interpolation. The lexer will tokenize code until
+                // the interpolation is closed.
+                Some('$') if self.chars.lookahead2() == Some('{') => {
+                    let start = self.chars.position();
+                    raw.push(self.chars.advance().unwrap());
+                    raw.push(self.chars.advance().unwrap());
+                    self.interpolations.push((Range::new(start, 2), 0));
+                    interpolation = true;
+                    break;
+                }
+
+                // Strings may not span multiple lines. If we see the end of a line or the end of
+                // our file before we see a closing quote then report an error.
+                Some('\n') | Some('\r') | None => {
+                    let diagnostic = self.unexpected_peek(ExpectedSyntax::StringEnd);
+                    error.get_or_insert(diagnostic);
+                    break;
+                }
+
+                // Escape sequences.
+                Some('\\') => {
+                    let start = self.chars.position();
+                    raw.push(self.chars.advance().unwrap());
+                    let c = match self.chars.lookahead() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('$') => '$',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            raw.push(self.chars.advance().unwrap());
+                            match self.next_unicode_escape(start, &mut raw) {
+                                Ok(c) => value.push(c),
+                                Err(diagnostic) => {
+                                    error.get_or_insert(diagnostic);
+                                }
+                            }
+                            continue;
+                        }
+
+                        // If we don’t know about this escape sequence then report an error. We
+                        // don’t advance past the unexpected character. We let the next iteration of
+                        // our loop handle it.
+                        _ => {
+                            let diagnostic = self.unexpected_peek(ExpectedSyntax::EscapeSequence);
+                            error.get_or_insert(diagnostic);
+                            continue;
+                        }
+                    };
+                    raw.push(self.chars.advance().unwrap());
+                    value.push(c);
+                }
+
+                // All other characters are added to our string as-is.
+                Some(c) => {
+                    self.chars.advance();
+                    raw.push(c);
+                    value.push(c);
+                }
+            }
+        }
+
+        raw.shrink_to_fit();
+        value.shrink_to_fit();
+        StringLiteral {
+            raw,
+            value: match error {
+                None => Ok(value),
+                Some(error) => Err(error),
+            },
+            part: match (open, interpolation) {
+                ('"', false) => StringPart::Complete,
+                ('"', true) => StringPart::Head,
+                (_, true) => StringPart::Middle,
+                (_, false) => StringPart::Tail,
+            },
+        }
+    }
+
+    /// Parses a Unicode escape sequence like `\u{1F600}` after we’ve advanced past the `\u`. The
+    /// escape sequence must contain between one and six hexadecimal digits.
+    fn next_unicode_escape(
+        &mut self,
+        start: Position,
+        raw: &mut String,
+    ) -> Result<char, DiagnosticRef> {
+        if self.chars.lookahead() != Some('{') {
+            return Err(self.unexpected_peek(ExpectedSyntax::Glyph(Glyph::BraceLeft)));
+        }
+        raw.push(self.chars.advance().unwrap());
+
+        let mut code = 0;
+        let mut digits = 0;
+        while digits < 6 {
+            match self.chars.lookahead().and_then(|c| c.to_digit(16)) {
+                Some(digit) => code = code * 16 + digit,
+                None => break,
+            }
+            raw.push(self.chars.advance().unwrap());
+            digits += 1;
+        }
+        if digits == 0 {
+            return Err(self.unexpected_peek(ExpectedSyntax::HexadecimalDigit));
+        }
+
+        if self.chars.lookahead() != Some('}') {
+            return Err(self.unexpected_peek(ExpectedSyntax::Glyph(Glyph::BraceRight)));
+        }
+        raw.push(self.chars.advance().unwrap());
+
+        // Surrogate code points and code points larger than `10FFFF` are not Unicode characters.
+        match char::from_u32(code) {
+            Some(c) => Ok(c),
+            None => {
+                let end = self.chars.position();
+                let range = Range::new(start, end.index_utf8() - start.index_utf8());
+                Err(self.report_diagnostic(Diagnostic::invalid_unicode_escape(range, code)))
+            }
+        }
+    }
+
     /// Parses some token trivia. If `leading` is true then we are parsing leading trivia. Otherwise
     /// we are parsing trailing trivia.
     fn next_trivia(&mut self, leading: bool) -> Vec<Trivia<'src>> {
@@ -1252,6 +1674,13 @@ impl<'src> Token<'src> {
                 ),
                 NumberKind::Invalid(_) => ("Number::Invalid", number.raw.clone()),
             },
+            TokenKind::String(string) => match (&string.value, string.part) {
+                (Err(_), _) => ("String::Invalid", string.raw.clone()),
+                (Ok(value), StringPart::Complete) => ("String", StringLiteral::print(value)),
+                (Ok(_), StringPart::Head) => ("String::Head", string.raw.clone()),
+                (Ok(_), StringPart::Middle) => ("String::Middle", string.raw.clone()),
+                (Ok(_), StringPart::Tail) => ("String::Tail", string.raw.clone()),
+            },
             TokenKind::UnexpectedChar(c) => ("UnexpectedChar", format!("`{}`", c)),
         };
 
@@ -1,14 +1,12 @@
-// TODO: Error recovery? Incremental parsing? These can be solved at the same time. We can recover
-// from errors by parsing at a different increment. Having syntax with clear semicolons and/
-// declarations makes it really easy for us to determine good, incremental, ranges.
-
-// TODO: Special handling for `a < b < c`?
-// TODO: Disallow `a < b > (c)` since we’ll use that for function call syntax.
+// TODO: Incremental parsing? We already recover from errors at declaration, class member, and
+// statement boundaries. Those same boundaries should make it really easy for us to determine good,
+// incremental, ranges.
 
 use super::document::Range;
 use super::lexer::*;
 use crate::diagnostics::{Diagnostic, DiagnosticRef, ExpectedSyntax};
 use crate::language::*;
+use std::mem;
 
 /// Manages the parsing of Brite syntactical elements from source code. The `Parser` struct is
 /// more like a parsing “context”. It does not hold much state itself. Most of the parsing state is
@@ -16,31 +14,84 @@ use crate::language::*;
 pub struct Parser<'errs, 'src> {
     /// The lexer our parser uses.
     lexer: Lexer<'errs, 'src>,
+    /// The range of the last token we advanced past. Used to measure the code we skip over when
+    /// recovering from a syntax error.
+    last_range: Range,
+    /// The labels of the loops we are currently parsing the body of. We need these to tell apart
+    /// the label and the value in `break x`.
+    labels: Vec<Identifier>,
+    /// The last unexpected syntax error we reported along with the value of `last_range` when we
+    /// reported it. If we have not advanced since then any other syntax error is at the same token
+    /// so we don’t report it again.
+    last_unexpected: Option<(Range, DiagnosticRef)>,
 }
 
 impl<'errs, 'src> Parser<'errs, 'src> {
     /// Creates a new parser.
     pub fn new(lexer: Lexer<'errs, 'src>) -> Self {
-        Parser { lexer }
+        Parser {
+            lexer,
+            last_range: Range::initial(),
+            labels: Vec::new(),
+            last_unexpected: None,
+        }
     }
 
     /// Parses a Brite module to the end of the document being parsed. Consumes the parser as we
-    /// consume the provided lexer. Either succeeds and returns a module or fails and returns the
-    /// error diagnostic.
-    pub fn parse_module(mut self) -> Result<Module, DiagnosticRef> {
+    /// consume the provided lexer.
+    ///
+    /// We always return a module. When we run into a syntax error we report a diagnostic, skip to
+    /// the next place where we can start parsing again, and leave an error node in the module. That
+    /// way one syntax error won’t hide the rest of the programmer’s code.
+    pub fn parse_module(mut self) -> Module {
         let mut declarations = Vec::new();
-        while self.lexer.lookahead().is_some() {
-            declarations.push(self.parse_declaration()?);
+        while let Some(token) = self.lexer.lookahead() {
+            let start = token.range;
+            let declaration = match self.parse_declaration() {
+                Ok(declaration) => declaration,
+                Err(error) => {
+                    let range = self.recover(start, Boundary::Declaration);
+                    Declaration::Error(ErrorDeclaration { range, error })
+                }
+            };
+            declarations.push(declaration);
         }
-        Ok(Module { declarations })
+        Module { declarations }
     }
 
     fn parse_declaration(&mut self) -> Result<Declaration, DiagnosticRef> {
+        // Import Declaration
+        if self
+            .try_parse_identifier_keyword(IdentifierKeyword::Import)
+            .is_some()
+        {
+            self.parse_glyph(Glyph::BraceLeft)?;
+            let (names, _) = self.parse_comma_list(Glyph::BraceRight, Self::parse_name)?;
+            self.parse_identifier_keyword(IdentifierKeyword::From)?;
+            let (path_range, path) = match self.try_parse_string(StringPart::Complete) {
+                Some((range, string)) => (range, string.value?),
+                None => return self.unexpected(ExpectedSyntax::String),
+            };
+            return Ok(Declaration::Import(ImportDeclaration::new(
+                names, path_range, path,
+            )));
+        }
+
+        // Attributes
+        let attributes = self.parse_attributes()?;
+
+        // Exported Declaration
+        let export = self
+            .try_parse_identifier_keyword(IdentifierKeyword::Export)
+            .is_some();
+
         // Function Declaration
-        if self.try_parse_keyword(Keyword::Fun).is_some() {
+        if let Some((_, async_)) = self.try_parse_fun_keyword()? {
             let name = self.parse_name()?;
-            let function = self.parse_function()?;
+            let function = self.parse_function(async_)?;
             return Ok(Declaration::Function(FunctionDeclaration {
+                attributes,
+                export,
                 name,
                 function,
             }));
@@ -48,21 +99,47 @@ impl<'errs, 'src> Parser<'errs, 'src> {
 
         // Class Declaration
         if let Some(_) = self.try_parse_identifier_keyword(IdentifierKeyword::Class) {
-            return Ok(Declaration::Class(self.parse_class_declaration(false)?));
+            return Ok(Declaration::Class(
+                self.parse_class_declaration(attributes, export, false)?,
+            ));
         }
 
         // Base Class Declaration
         if let Some(_) = self.try_parse_identifier_keyword(IdentifierKeyword::Base) {
             self.parse_identifier_keyword(IdentifierKeyword::Class)?;
-            return Ok(Declaration::Class(self.parse_class_declaration(true)?));
+            return Ok(Declaration::Class(
+                self.parse_class_declaration(attributes, export, true)?,
+            ));
+        }
+
+        // Type Alias Declaration
+        if self
+            .try_parse_identifier_keyword(IdentifierKeyword::Type)
+            .is_some()
+        {
+            let name = self.parse_name()?;
+            self.parse_glyph(Glyph::Equals)?;
+            let value = self.parse_type()?;
+            return Ok(Declaration::TypeAlias(TypeAliasDeclaration {
+                attributes,
+                export,
+                name,
+                value,
+            }));
         }
 
         self.unexpected(ExpectedSyntax::Declaration)
     }
 
     /// Parses a class declaration.
-    fn parse_class_declaration(&mut self, base: bool) -> Result<ClassDeclaration, DiagnosticRef> {
+    fn parse_class_declaration(
+        &mut self,
+        attributes: Vec<Attribute>,
+        export: bool,
+        base: bool,
+    ) -> Result<ClassDeclaration, DiagnosticRef> {
         let name = self.parse_name()?;
+        let type_parameters = self.parse_type_parameters()?;
         let extends = if self
             .try_parse_identifier_keyword(IdentifierKeyword::Extends)
             .is_some()
@@ -74,13 +151,30 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         let mut members = Vec::new();
         if self.try_parse_glyph(Glyph::BraceLeft).is_some() {
             while self.try_parse_glyph(Glyph::BraceRight).is_none() {
-                let member = self.parse_class_member()?;
-                members.push(member);
+                // If our document ends before the class does then keep the members we have parsed.
+                let start = match self.lexer.lookahead() {
+                    Some(token) => token.range,
+                    None => {
+                        self.report_unexpected(ExpectedSyntax::Glyph(Glyph::BraceRight));
+                        break;
+                    }
+                };
+                // If we fail to parse a class member then skip to the next class member. We don’t
+                // have error class members so the syntax error diagnostic is all that remains.
+                match self.parse_class_member() {
+                    Ok(member) => members.push(member),
+                    Err(_) => {
+                        self.recover(start, Boundary::ClassMember);
+                    }
+                }
             }
         }
         Ok(ClassDeclaration {
+            attributes,
+            export,
             base,
             name,
+            type_parameters,
             extends,
             members,
         })
@@ -88,6 +182,9 @@ impl<'errs, 'src> Parser<'errs, 'src> {
 
     /// Parses a class member.
     fn parse_class_member(&mut self) -> Result<ClassMember, DiagnosticRef> {
+        // Attributes
+        let attributes = self.parse_attributes()?;
+
         // Class Field Member
         if let Some(name) = self.try_parse_name() {
             // Class Base Method Member
@@ -95,13 +192,16 @@ impl<'errs, 'src> Parser<'errs, 'src> {
                 if self.try_parse_glyph(Glyph::Colon).is_none() {
                     self.parse_keyword(Keyword::Fun)?;
                     let name = self.parse_name()?;
+                    let type_parameters = self.parse_type_parameters()?;
                     self.parse_glyph(Glyph::ParenLeft)?;
                     let (parameters, _) =
                         self.parse_comma_list(Glyph::ParenRight, Self::parse_function_parameter)?;
                     self.parse_glyph(Glyph::Colon)?;
                     let return_type = self.parse_type()?;
                     return Ok(ClassMember::BaseMethod(BaseMethodClassMember {
+                        attributes,
                         name,
+                        type_parameters,
                         parameters,
                         return_type,
                     }));
@@ -111,21 +211,64 @@ impl<'errs, 'src> Parser<'errs, 'src> {
             }
             let value = self.parse_type()?;
             self.try_parse_glyph(Glyph::Semicolon);
-            return Ok(ClassMember::Field(FieldClassMember { name, value }));
+            return Ok(ClassMember::Field(FieldClassMember {
+                attributes,
+                name,
+                value,
+            }));
         }
 
         // Class Method Member
-        if self.try_parse_keyword(Keyword::Fun).is_some() {
+        if let Some((_, async_)) = self.try_parse_fun_keyword()? {
             let name = self.parse_name()?;
-            let function = self.parse_function()?;
-            return Ok(ClassMember::Method(MethodClassMember { name, function }));
+            let function = self.parse_function(async_)?;
+            return Ok(ClassMember::Method(MethodClassMember {
+                attributes,
+                name,
+                function,
+            }));
         }
 
         self.unexpected(ExpectedSyntax::ClassMember)
     }
 
-    /// Parses the common parts of every function. Starting at the parameters.
-    fn parse_function(&mut self) -> Result<Function, DiagnosticRef> {
+    /// Parses the attributes written before a declaration or class member, like `@inline` or
+    /// `@deprecated("Use `connect` instead.")`. If there are no attributes then we return an
+    /// empty list.
+    fn parse_attributes(&mut self) -> Result<Vec<Attribute>, DiagnosticRef> {
+        let mut attributes = Vec::new();
+        while let Some(start) = self.try_parse_glyph(Glyph::At) {
+            let name = self.parse_name()?;
+            let (arguments, end) = if self.try_parse_glyph(Glyph::ParenLeft).is_some() {
+                self.parse_comma_list(Glyph::ParenRight, Self::parse_expression)?
+            } else {
+                (Vec::new(), name.range)
+            };
+            attributes.push(Attribute {
+                range: start.union(end),
+                name,
+                arguments,
+            });
+        }
+        Ok(attributes)
+    }
+
+    /// Parses the keywords which start every function, `fun` or `async fun`. Returns the range of
+    /// the first keyword and whether our function is async.
+    fn try_parse_fun_keyword(&mut self) -> Result<Option<(Range, bool)>, DiagnosticRef> {
+        if let Some(start) = self.try_parse_keyword(Keyword::Async) {
+            self.parse_keyword(Keyword::Fun)?;
+            Ok(Some((start, true)))
+        } else {
+            Ok(self
+                .try_parse_keyword(Keyword::Fun)
+                .map(|start| (start, false)))
+        }
+    }
+
+    /// Parses the common parts of every function. Starting at the type parameters.
+    fn parse_function(&mut self, async_: bool) -> Result<Function, DiagnosticRef> {
+        let type_parameters = self.parse_type_parameters()?;
         self.parse_glyph(Glyph::ParenLeft)?;
         let (parameters, _) =
             self.parse_comma_list(Glyph::ParenRight, Self::parse_function_parameter)?;
@@ -134,20 +277,45 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         } else {
             None
         };
-        let body = self.parse_block()?;
+        // We may not break out of a function so a function’s body can’t see the labels of the loops
+        // around it.
+        let labels = mem::take(&mut self.labels);
+        let body = self.parse_block();
+        self.labels = labels;
+        let body = body?;
         Ok(Function {
+            async_,
+            type_parameters,
             parameters,
             return_type,
             body,
         })
     }
 
+    /// Parses the type parameters of a generic function or class. If there are no type parameters
+    /// then we return an empty list.
+    fn parse_type_parameters(&mut self) -> Result<Vec<Name>, DiagnosticRef> {
+        if self.try_parse_glyph(Glyph::LessThan).is_some() {
+            let (type_parameters, _) =
+                self.parse_comma_list(Glyph::GreaterThan, Self::parse_name)?;
+            Ok(type_parameters)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
     fn parse_function_parameter(&mut self) -> Result<FunctionParameter, DiagnosticRef> {
         let pattern = self.parse_pattern()?;
         let annotation = self.try_parse_type_annotation()?;
+        let default = if self.try_parse_glyph(Glyph::Equals).is_some() {
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
         Ok(FunctionParameter {
             pattern,
             annotation,
+            default,
         })
     }
 
@@ -160,10 +328,31 @@ impl<'errs, 'src> Parser<'errs, 'src> {
 
             if let Some(end) = self.try_parse_glyph(Glyph::BraceRight) {
                 break end;
-            } else {
-                let statement = self.parse_statement()?;
-                statements.push(statement);
             }
+            // If our document ends before the block does then keep the statements we have parsed
+            // and end our block with an error statement.
+            let start = match self.lexer.lookahead() {
+                Some(token) => token.range,
+                None => {
+                    let error = self.report_unexpected(ExpectedSyntax::Glyph(Glyph::BraceRight));
+                    let end = error.range;
+                    statements.push(Statement {
+                        range: end,
+                        kind: StatementKind::Error(error),
+                    });
+                    break end;
+                }
+            };
+            // If we fail to parse a statement then skip to the next statement and leave an error
+            // statement in its place.
+            let statement = match self.parse_statement() {
+                Ok(statement) => statement,
+                Err(error) => Statement {
+                    range: self.recover(start, Boundary::Statement),
+                    kind: StatementKind::Error(error),
+                },
+            };
+            statements.push(statement);
         };
         let range = start.union(end);
         Ok(Block { range, statements })
@@ -172,6 +361,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
     fn parse_statement(&mut self) -> Result<Statement, DiagnosticRef> {
         // Binding Statement
         if let Some(start) = self.try_parse_keyword(Keyword::Let) {
+            let mutable = self.try_parse_keyword(Keyword::Mut).is_some();
             let pattern = self.parse_pattern()?;
             let annotation = self.try_parse_type_annotation()?;
             self.parse_glyph(Glyph::Equals)?;
@@ -180,6 +370,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
             return Ok(Statement {
                 range: start.union(maybe_end.unwrap_or(value.range)),
                 kind: StatementKind::Binding(BindingStatement {
+                    mutable,
                     pattern,
                     annotation,
                     value,
@@ -205,8 +396,73 @@ impl<'errs, 'src> Parser<'errs, 'src> {
             });
         }
 
+        // Break Statement
+        //
+        // NOTE: Just like return statements, the label and argument of a break statement must be
+        // on the same line as the break keyword.
+        if let Some(start) = self.try_parse_keyword(Keyword::Break) {
+            let is_label = match self.lexer.lookahead() {
+                Some(token) if !token.first_on_newline() => match &token.kind {
+                    TokenKind::Identifier(identifier) => self.labels.contains(identifier),
+                    _ => false,
+                },
+                _ => false,
+            };
+            let label = if is_label {
+                self.try_parse_name()
+            } else {
+                None
+            };
+            let argument = match self.lexer.lookahead() {
+                Some(token) if !token.first_on_newline() => self.try_parse_expression()?,
+                _ => None,
+            };
+            let maybe_end = self.try_parse_glyph(Glyph::Semicolon);
+            let end = maybe_end.unwrap_or(match (&argument, &label) {
+                (Some(argument), _) => argument.range,
+                (None, Some(label)) => label.range,
+                (None, None) => start,
+            });
+            return Ok(Statement {
+                range: start.union(end),
+                kind: StatementKind::Break(BreakStatement { label, argument }),
+            });
+        }
+
+        // Continue Statement
+        if let Some(start) = self.try_parse_keyword(Keyword::Continue) {
+            let label = match self.lexer.lookahead() {
+                Some(token) if !token.first_on_newline() => self.try_parse_name(),
+                _ => None,
+            };
+            let maybe_end = self.try_parse_glyph(Glyph::Semicolon);
+            let end = maybe_end.unwrap_or_else(|| label.as_ref().map(|x| x.range).unwrap_or(start));
+            return Ok(Statement {
+                range: start.union(end),
+                kind: StatementKind::Continue(ContinueStatement { label }),
+            });
+        }
+
         // Expression Statement
         if let Some(expression) = self.try_parse_expression()? {
+            // Assignment Statement
+            //
+            // An assignment starts with an expression, so we only know we have one when we see an
+            // assignment operator after the expression. The checker decides whether or not the
+            // expression may be assigned to.
+            if let Some(operator) = self.try_parse_assignment_operator() {
+                let value = self.parse_expression()?;
+                let maybe_end = self.try_parse_glyph(Glyph::Semicolon);
+                return Ok(Statement {
+                    range: expression.range.union(maybe_end.unwrap_or(value.range)),
+                    kind: StatementKind::Assignment(AssignmentStatement {
+                        target: expression,
+                        operator,
+                        value,
+                    }),
+                });
+            }
+
             let maybe_end = self.try_parse_glyph(Glyph::Semicolon);
             return Ok(Statement {
                 range: if let Some(end) = maybe_end {
@@ -221,15 +477,46 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         self.unexpected(ExpectedSyntax::Statement)
     }
 
-    fn try_parse_constant(&mut self) -> Result<Option<(Range, Constant)>, DiagnosticRef> {
+    /// Tries to parse the operator of an assignment statement. Returns `Some(None)` for a plain
+    /// assignment with `=` and `Some(Some(operator))` for a compound assignment like `+=`.
+    fn try_parse_assignment_operator(&mut self) -> Option<Option<AssignmentOperator>> {
+        if self.try_parse_glyph(Glyph::Equals).is_some() {
+            return Some(None);
+        }
+        if self.try_parse_glyph(Glyph::PlusEquals).is_some() {
+            return Some(Some(AssignmentOperator::Add));
+        }
+        if self.try_parse_glyph(Glyph::MinusEquals).is_some() {
+            return Some(Some(AssignmentOperator::Subtract));
+        }
+        if self.try_parse_glyph(Glyph::AsteriskEquals).is_some() {
+            return Some(Some(AssignmentOperator::Multiply));
+        }
+        if self.try_parse_glyph(Glyph::SlashEquals).is_some() {
+            return Some(Some(AssignmentOperator::Divide));
+        }
+        if self.try_parse_glyph(Glyph::PercentEquals).is_some() {
+            return Some(Some(AssignmentOperator::Remainder));
+        }
+        None
+    }
+
+    /// Tries to parse a constant. If the constant is an invalid number then we return the error
+    /// diagnostic the lexer reported for that number.
+    fn try_parse_constant(&mut self) -> Option<(Range, Result<Constant, DiagnosticRef>)> {
         // True Boolean Constant
         if let Some(range) = self.try_parse_keyword(Keyword::True) {
-            return Ok(Some((range, Constant::Boolean(true))));
+            return Some((range, Ok(Constant::Boolean(true))));
         }
 
         // False Boolean Constant
         if let Some(range) = self.try_parse_keyword(Keyword::False) {
-            return Ok(Some((range, Constant::Boolean(false))));
+            return Some((range, Ok(Constant::Boolean(false))));
+        }
+
+        // Null Constant
+        if let Some(range) = self.try_parse_keyword(Keyword::Null) {
+            return Some((range, Ok(Constant::Null)));
         }
 
         // Number Constant
@@ -241,12 +528,17 @@ impl<'errs, 'src> Parser<'errs, 'src> {
                 }
                 NumberKind::BinaryInteger(value) => Constant::Integer(IntegerBase::Binary, value),
                 NumberKind::Float(value) => Constant::Float(value),
-                NumberKind::Invalid(error) => return Err(error),
+                NumberKind::Invalid(error) => return Some((range, Err(error))),
             };
-            return Ok(Some((range, constant)));
+            return Some((range, Ok(constant)));
         }
 
-        Ok(None)
+        // String Constant
+        if let Some((range, string)) = self.try_parse_string(StringPart::Complete) {
+            return Some((range, string.value.map(Constant::String)));
+        }
+
+        None
     }
 
     #[inline]
@@ -256,7 +548,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
 
     #[inline]
     fn try_parse_expression(&mut self) -> Result<Option<Expression>, DiagnosticRef> {
-        self.try_parse_infix_expression(&ParseExpressionConfig::default(), Precedence::LogicalOr)
+        self.try_parse_infix_expression(&ParseExpressionConfig::default(), Precedence::Pipe)
     }
 
     /// Parses an expression with some custom configuration.
@@ -265,10 +557,10 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         &mut self,
         config: &ParseExpressionConfig,
     ) -> Result<Expression, DiagnosticRef> {
-        if let Some(expression) = self.try_parse_infix_expression(config, Precedence::LogicalOr)? {
+        if let Some(expression) = self.try_parse_infix_expression(config, Precedence::Pipe)? {
             Ok(expression)
         } else {
-            self.unexpected(ExpectedSyntax::Expression)
+            Ok(self.unexpected_expression())
         }
     }
 
@@ -298,7 +590,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         if let Some(expression) = self.try_parse_infix_expression(config, precedence)? {
             Ok(expression)
         } else {
-            self.unexpected(ExpectedSyntax::Expression)
+            Ok(self.unexpected_expression())
         }
     }
 
@@ -308,6 +600,19 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         precedence: Precedence,
         left: Expression,
     ) -> Result<Expression, DiagnosticRef> {
+        // Pipe Expression
+        //
+        // Pipes have the lowest precedence so that `a + b |> f()` pipes `a + b` into `f`.
+        if precedence >= Precedence::Pipe {
+            let next_precedence = Precedence::LogicalOr;
+            if self.try_parse_glyph(Glyph::Pipe).is_some() {
+                let right = self.parse_infix_expression(config, next_precedence)?;
+                let range = left.range.union(right.range);
+                let node = Expression::pipe(range, left, right);
+                return self.try_parse_infix_operator(config, precedence, node);
+            }
+        }
+
         // Or Logical Expression
         if precedence >= Precedence::LogicalOr {
             let next_precedence = Precedence::LogicalAnd;
@@ -352,31 +657,86 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         }
 
         // Relational Infix Expression
+        //
+        // If we find more than one relational operator in a row then we build a comparison chain
+        // instead of nesting infix expressions. So `a < b < c` means `a < b && b < c` and not
+        // `(a < b) < c`.
         if precedence >= Precedence::Relational {
-            let next_precedence = Precedence::Additive;
-            if self.try_parse_glyph(Glyph::LessThan).is_some() {
-                let op = InfixOperator::LessThan;
+            let next_precedence = Precedence::BitwiseOr;
+            if let Some(op) = self.try_parse_relational_operator() {
+                let right = self.parse_infix_expression(config, next_precedence)?;
+                let mut comparisons = vec![Comparison {
+                    operator: op,
+                    right,
+                }];
+                while let Some(op) = self.try_parse_relational_operator() {
+                    let right = self.parse_infix_expression(config, next_precedence)?;
+                    comparisons.push(Comparison {
+                        operator: op,
+                        right,
+                    });
+                }
+                let range = left.range.union(comparisons.last().unwrap().right.range);
+                let node = if comparisons.len() == 1 {
+                    let Comparison { operator, right } = comparisons.pop().unwrap();
+                    Expression::infix(range, operator, left, right)
+                } else {
+                    Expression::comparison(range, left, comparisons)
+                };
+                return self.try_parse_infix_operator(config, precedence, node);
+            }
+        }
+
+        // Bitwise Or Infix Expression
+        //
+        // Unlike JavaScript our bitwise operators bind tighter than our comparison operators so
+        // that `x & y == 0` means `(x & y) == 0`.
+        if precedence >= Precedence::BitwiseOr {
+            let next_precedence = Precedence::BitwiseXor;
+            if self.try_parse_glyph(Glyph::Bar).is_some() {
+                let op = InfixOperator::BitwiseOr;
                 let right = self.parse_infix_expression(config, next_precedence)?;
                 let range = left.range.union(right.range);
                 let node = Expression::infix(range, op, left, right);
                 return self.try_parse_infix_operator(config, precedence, node);
             }
-            if self.try_parse_glyph(Glyph::LessThanOrEqual).is_some() {
-                let op = InfixOperator::LessThanOrEqual;
+        }
+
+        // Bitwise Exclusive Or Infix Expression
+        if precedence >= Precedence::BitwiseXor {
+            let next_precedence = Precedence::BitwiseAnd;
+            if self.try_parse_glyph(Glyph::Caret).is_some() {
+                let op = InfixOperator::BitwiseXor;
                 let right = self.parse_infix_expression(config, next_precedence)?;
                 let range = left.range.union(right.range);
                 let node = Expression::infix(range, op, left, right);
                 return self.try_parse_infix_operator(config, precedence, node);
             }
-            if self.try_parse_glyph(Glyph::GreaterThan).is_some() {
-                let op = InfixOperator::GreaterThan;
+        }
+
+        // Bitwise And Infix Expression
+        if precedence >= Precedence::BitwiseAnd {
+            let next_precedence = Precedence::Shift;
+            if self.try_parse_glyph(Glyph::Ampersand).is_some() {
+                let op = InfixOperator::BitwiseAnd;
                 let right = self.parse_infix_expression(config, next_precedence)?;
                 let range = left.range.union(right.range);
                 let node = Expression::infix(range, op, left, right);
                 return self.try_parse_infix_operator(config, precedence, node);
             }
-            if self.try_parse_glyph(Glyph::GreaterThanOrEqual).is_some() {
-                let op = InfixOperator::GreaterThanOrEqual;
+        }
+
+        // Shift Infix Expression
+        if precedence >= Precedence::Shift {
+            let next_precedence = Precedence::Additive;
+            if self.try_parse_glyph(Glyph::LessThanDouble).is_some() {
+                let op = InfixOperator::LeftShift;
+                let right = self.parse_infix_expression(config, next_precedence)?;
+                let range = left.range.union(right.range);
+                let node = Expression::infix(range, op, left, right);
+                return self.try_parse_infix_operator(config, precedence, node);
+            }
+            if let Some(op) = self.try_parse_right_shift_operator() {
                 let right = self.parse_infix_expression(config, next_precedence)?;
                 let range = left.range.union(right.range);
                 let node = Expression::infix(range, op, left, right);
@@ -432,7 +792,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         // Exponentiation Infix Expression
         if precedence >= Precedence::Exponentiation {
             let next_precedence = Precedence::Bottom;
-            if self.try_parse_glyph(Glyph::Caret).is_some() {
+            if self.try_parse_glyph(Glyph::AsteriskDouble).is_some() {
                 let op = InfixOperator::Exponent;
                 let right = self.parse_infix_expression(config, next_precedence)?;
                 let range = left.range.union(right.range);
@@ -444,6 +804,57 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         Ok(left)
     }
 
+    /// Tries to parse a relational operator like `<` or `>=`.
+    fn try_parse_relational_operator(&mut self) -> Option<InfixOperator> {
+        if self.try_parse_glyph(Glyph::LessThan).is_some() {
+            Some(InfixOperator::LessThan)
+        } else if self.try_parse_glyph(Glyph::LessThanOrEqual).is_some() {
+            Some(InfixOperator::LessThanOrEqual)
+        } else if self.lookahead_greater_than_count() == 1 {
+            self.advance();
+            Some(InfixOperator::GreaterThan)
+        } else if self.try_parse_glyph(Glyph::GreaterThanOrEqual).is_some() {
+            Some(InfixOperator::GreaterThanOrEqual)
+        } else {
+            None
+        }
+    }
+
+    /// Tries to parse a right shift operator, `>>` or `>>>`. Our lexer never combines `>` glyphs
+    /// so a right shift operator is two or three `>` glyphs with nothing in between them.
+    fn try_parse_right_shift_operator(&mut self) -> Option<InfixOperator> {
+        let operator = match self.lookahead_greater_than_count() {
+            2 => InfixOperator::RightShift,
+            3 => InfixOperator::UnsignedRightShift,
+            _ => return None,
+        };
+        for _ in 0..operator.as_str().len() {
+            self.advance();
+        }
+        Some(operator)
+    }
+
+    /// Counts the `>` glyphs at the start of our lookahead with nothing in between them, up to
+    /// three. So `>>` counts as two glyphs but `> >` counts as one.
+    fn lookahead_greater_than_count(&mut self) -> usize {
+        let mut n = 0;
+        let mut end = None;
+        while n < 3 {
+            match self.lexer.lookahead_nth(n) {
+                Some(Token {
+                    kind: TokenKind::Glyph(Glyph::GreaterThan),
+                    range,
+                    ..
+                }) if end.map_or(true, |end| end == range.start()) => {
+                    end = Some(range.end());
+                    n += 1;
+                }
+                _ => break,
+            }
+        }
+        n
+    }
+
     /// Parses a prefix expression. A prefix expression is a postfix expression extended with some
     /// operations before the expression. Like the boolean “not” operator or the number
     /// “negative” operator.
@@ -451,6 +862,20 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         &mut self,
         config: &ParseExpressionConfig,
     ) -> Result<Option<Expression>, DiagnosticRef> {
+        // Await Expression
+        if let Some(start) = self.try_parse_keyword(Keyword::Await) {
+            let operand = if let Some(expression) = self.try_parse_prefix_expression(config)? {
+                expression
+            } else {
+                self.unexpected_expression()
+            };
+            let range = start.union(operand.range);
+            return Ok(Some(Expression {
+                range,
+                kind: ExpressionKind::Await(Box::new(AwaitExpression { operand })),
+            }));
+        }
+
         // Try to parse a prefix expression operator. If no such operator exists then try to parse a
         // postfix expression.
         let (start, operator) = if let Some(range) = self.try_parse_glyph(Glyph::Bang) {
@@ -459,6 +884,8 @@ impl<'errs, 'src> Parser<'errs, 'src> {
             (range, PrefixOperator::Negative)
         } else if let Some(range) = self.try_parse_glyph(Glyph::Plus) {
             (range, PrefixOperator::Positive)
+        } else if let Some(range) = self.try_parse_glyph(Glyph::Tilde) {
+            (range, PrefixOperator::BitwiseNot)
         } else {
             return self.try_parse_postfix_expression(config);
         };
@@ -468,7 +895,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         let operand = if let Some(expression) = self.try_parse_prefix_expression(config)? {
             expression
         } else {
-            self.unexpected(ExpectedSyntax::Expression)?
+            self.unexpected_expression()
         };
 
         let range = start.union(operand.range);
@@ -500,59 +927,90 @@ impl<'errs, 'src> Parser<'errs, 'src> {
                     continue;
                 }
 
-                // Call Expression
+                // Index Expression
+                //
+                // NOTE: The index must be on the same line as the list! Otherwise we would parse a
+                // list expression on the next line as an index. This makes programming without
+                // semicolons in Brite easier.
                 if let Some(token) = self.lexer.lookahead() {
-                    if let TokenKind::Glyph(Glyph::ParenLeft) = &token.kind {
-                        // NOTE: Call arguments must be on the same line as the callee! This makes
-                        // programming without semicolons in Brite easier.
+                    if let TokenKind::Glyph(Glyph::BracketLeft) = &token.kind {
                         if !token.first_on_newline() {
-                            self.lexer.advance();
-                            let (arguments, end) =
-                                self.parse_comma_list(Glyph::ParenRight, Self::parse_expression)?;
+                            self.advance();
+                            let index = self.parse_expression()?;
+                            let end = self.parse_glyph(Glyph::BracketRight)?;
                             let range = expression.range.union(end);
                             expression = Expression {
                                 range,
-                                kind: ExpressionKind::Call(CallExpression {
-                                    callee: Box::new(expression),
-                                    arguments,
-                                }),
+                                kind: ExpressionKind::Index(Box::new(IndexExpression {
+                                    object: expression,
+                                    index,
+                                })),
                             };
                             continue;
                         }
                     }
                 }
 
+                // Call Expression
+                if self.lookahead_type_arguments_before(Glyph::ParenLeft) {
+                    let type_arguments = if self.try_parse_glyph(Glyph::LessThan).is_some() {
+                        let (type_arguments, _) =
+                            self.parse_comma_list(Glyph::GreaterThan, Self::parse_type)?;
+                        type_arguments
+                    } else {
+                        Vec::new()
+                    };
+                    self.parse_glyph(Glyph::ParenLeft)?;
+                    let (arguments, named_arguments, end) = self.parse_call_arguments()?;
+                    let range = expression.range.union(end);
+                    expression = Expression {
+                        range,
+                        kind: ExpressionKind::Call(CallExpression {
+                            callee: Box::new(expression),
+                            type_arguments,
+                            arguments,
+                            named_arguments,
+                        }),
+                    };
+                    continue;
+                }
+
                 // Construct Expression
-                if !config.before_block {
-                    if let Some(token) = self.lexer.lookahead() {
-                        if let TokenKind::Glyph(Glyph::BraceLeft) = &token.kind {
-                            // NOTE: Constructor fields must be on the same line as the constructor!
-                            // This makes programming without semicolons in Brite easier.
-                            if !token.first_on_newline() {
-                                match into_constructor(expression) {
-                                    Err(x) => expression = x,
-
-                                    // If the next token is a left brace (`{`) which is on the same
-                                    // line as our expression and that expression is convertible
-                                    // into a constructor then we have a `ConstructExpression`!
-                                    Ok(constructor) => {
-                                        self.lexer.advance();
-                                        let (fields, end) = self.parse_comma_list(
-                                            Glyph::BraceRight,
-                                            Self::parse_construct_expression_field,
-                                        )?;
-                                        let range = constructor.range.union(end);
-                                        expression = Expression {
-                                            range,
-                                            kind: ExpressionKind::Construct(ConstructExpression {
-                                                constructor,
-                                                fields,
-                                            }),
-                                        };
-                                        continue;
-                                    }
-                                }
-                            }
+                //
+                // NOTE: Constructor fields must be on the same line as the constructor! This makes
+                // programming without semicolons in Brite easier.
+                if !config.before_block && self.lookahead_type_arguments_before(Glyph::BraceLeft) {
+                    match into_constructor(expression) {
+                        Err(x) => expression = x,
+
+                        // If the next token is a left brace (`{`), maybe after some type
+                        // arguments, which is on the same line as our expression and that
+                        // expression is convertible into a constructor then we have
+                        // a `ConstructExpression`!
+                        Ok(constructor) => {
+                            let type_arguments = if self.try_parse_glyph(Glyph::LessThan).is_some()
+                            {
+                                let (type_arguments, _) =
+                                    self.parse_comma_list(Glyph::GreaterThan, Self::parse_type)?;
+                                type_arguments
+                            } else {
+                                Vec::new()
+                            };
+                            self.parse_glyph(Glyph::BraceLeft)?;
+                            let (fields, end) = self.parse_comma_list(
+                                Glyph::BraceRight,
+                                Self::parse_construct_expression_field,
+                            )?;
+                            let range = constructor.range.union(end);
+                            expression = Expression {
+                                range,
+                                kind: ExpressionKind::Construct(ConstructExpression {
+                                    constructor,
+                                    type_arguments,
+                                    fields,
+                                }),
+                            };
+                            continue;
                         }
                     }
                 }
@@ -565,11 +1023,61 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         }
     }
 
+    /// Looks ahead to see if the next tokens start the arguments of a call expression, when our
+    /// glyph is `(`, or the fields of a construct expression, when our glyph is `{`.
+    ///
+    /// NOTE: The arguments must be on the same line as the callee or constructor! This makes
+    /// programming without semicolons in Brite easier.
+    ///
+    /// Calls and constructs may have type arguments, like `f<T>(x)`, which look exactly like the
+    /// comparisons `(f < T) > (x)`. We always choose the call. When we see a `<` we scan ahead
+    /// through tokens which may appear in a type until we find the matching `>`. If that `>` is
+    /// followed by our glyph then we have type arguments. Programmers who really want the
+    /// comparisons may wrap one of them in parentheses.
+    fn lookahead_type_arguments_before(&mut self, glyph: Glyph) -> bool {
+        // The number of type argument lists and the number of parentheses we are inside of.
+        let mut depth = 0;
+        let mut parens = 0;
+        let mut n = 0;
+        while let Some(token) = self.lexer.lookahead_nth(n) {
+            match &token.kind {
+                TokenKind::Glyph(open) if *open == glyph && depth == 0 => {
+                    return !token.first_on_newline();
+                }
+                TokenKind::Glyph(Glyph::LessThan) if n == 0 && !token.first_on_newline() => {
+                    depth += 1;
+                }
+                TokenKind::Glyph(Glyph::LessThan) if depth > 0 => depth += 1,
+                TokenKind::Glyph(Glyph::GreaterThan) if depth > 0 => depth -= 1,
+                TokenKind::Glyph(Glyph::ParenLeft) if depth > 0 => parens += 1,
+                TokenKind::Glyph(Glyph::ParenRight) if parens > 0 => parens -= 1,
+                TokenKind::Identifier(_)
+                | TokenKind::Glyph(Glyph::Comma)
+                | TokenKind::Glyph(Glyph::Colon)
+                | TokenKind::Glyph(Glyph::Keyword(Keyword::Fun))
+                | TokenKind::Glyph(Glyph::Keyword(Keyword::This))
+                | TokenKind::Glyph(Glyph::Keyword(Keyword::Void))
+                    if depth > 0 => {}
+                _ => return false,
+            }
+            n += 1;
+        }
+        false
+    }
+
     /// Parses a primary expression. A primary expression is balanced. It may stand on its own. It
     /// has a start and end which are not expressions themselves. It has the most basic precedence
     /// because of this. Other expressions which do depend on precedence build themselves out of
     /// primary expressions.
     fn try_parse_primary_expression(&mut self) -> Result<Option<Expression>, DiagnosticRef> {
+        // Labeled Loop Expression
+        if self.lookahead_label() {
+            let label = self.parse_name()?;
+            self.parse_glyph(Glyph::Colon)?;
+            let start = label.range;
+            return self.try_parse_loop_expression(start, Some(label));
+        }
+
         // Reference Expression
         if let Some((range, identifier)) = self.try_parse_identifier() {
             return Ok(Some(Expression {
@@ -587,20 +1095,32 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         }
 
         // Constant Expression
-        if let Some((range, constant)) = self.try_parse_constant()? {
-            return Ok(Some(Expression {
-                range,
-                kind: ExpressionKind::Constant(constant),
-            }));
+        if let Some((range, constant)) = self.try_parse_constant() {
+            let kind = match constant {
+                Ok(constant) => ExpressionKind::Constant(constant),
+                Err(error) => ExpressionKind::Error(error),
+            };
+            return Ok(Some(Expression { range, kind }));
+        }
+
+        // Template Expression
+        if let Some((start, head)) = self.try_parse_string(StringPart::Head) {
+            let (end, template) = self.parse_template(head)?;
+            let range = start.union(end);
+            let kind = match template {
+                Ok(template) => ExpressionKind::Template(template),
+                Err(error) => ExpressionKind::Error(error),
+            };
+            return Ok(Some(Expression { range, kind }));
         }
 
         // Function Expression
-        if let Some(start) = self.try_parse_keyword(Keyword::Fun) {
-            let function = self.parse_function()?;
+        if let Some((start, async_)) = self.try_parse_fun_keyword()? {
+            let function = self.parse_function(async_)?;
             let range = start.union(function.body.range);
             return Ok(Some(Expression {
                 range,
-                kind: ExpressionKind::Function(function),
+                kind: ExpressionKind::Function(Box::new(function)),
             }));
         }
 
@@ -614,6 +1134,27 @@ impl<'errs, 'src> Parser<'errs, 'src> {
             }));
         }
 
+        // Loop Expression
+        if let Some(token) = self.lexer.lookahead() {
+            let start = token.range;
+            if let Some(expression) = self.try_parse_loop_expression(start, None)? {
+                return Ok(Some(expression));
+            }
+        }
+
+        // Case Expression
+        if let Some(start) = self.try_parse_keyword(Keyword::Case) {
+            let test_config = ParseExpressionConfig { before_block: true };
+            let test = self.parse_expression_with_config(&test_config)?;
+            self.parse_glyph(Glyph::BraceLeft)?;
+            let (arms, end) = self.parse_comma_list(Glyph::BraceRight, Self::parse_case_arm)?;
+            let range = start.union(end);
+            return Ok(Some(Expression {
+                range,
+                kind: ExpressionKind::Case(Box::new(CaseExpression { test, arms })),
+            }));
+        }
+
         // Wrapped Expression
         if let Some(start) = self.try_parse_glyph(Glyph::ParenLeft) {
             let expression = self.parse_expression()?;
@@ -629,6 +1170,17 @@ impl<'errs, 'src> Parser<'errs, 'src> {
             }));
         }
 
+        // List Expression
+        if let Some(start) = self.try_parse_glyph(Glyph::BracketLeft) {
+            let (elements, end) =
+                self.parse_comma_list(Glyph::BracketRight, Self::parse_expression)?;
+            let range = start.union(end);
+            return Ok(Some(Expression {
+                range,
+                kind: ExpressionKind::List(ListExpression { elements }),
+            }));
+        }
+
         // Block Expression
         if let Some(start) = self.try_parse_keyword(Keyword::Do) {
             let block = self.parse_block()?;
@@ -651,6 +1203,44 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         Ok(ConstructExpressionField { name, value })
     }
 
+    /// Parses the interpolated expressions and text of a template after its head. Returns the
+    /// range of the last string part. If any string part of our template is invalid then we
+    /// return the first error instead of a template.
+    fn parse_template(
+        &mut self,
+        head: StringLiteral,
+    ) -> Result<(Range, Result<TemplateExpression, DiagnosticRef>), DiagnosticRef> {
+        let (head, mut error) = match head.value {
+            Ok(head) => (head, None),
+            Err(error) => (String::new(), Some(error)),
+        };
+        let mut spans = Vec::new();
+        loop {
+            let expression = self.parse_expression()?;
+            let (range, string) = if let Some(middle) = self.try_parse_string(StringPart::Middle) {
+                middle
+            } else if let Some(tail) = self.try_parse_string(StringPart::Tail) {
+                tail
+            } else {
+                return self.unexpected(ExpectedSyntax::Glyph(Glyph::BraceRight));
+            };
+            let part = string.part;
+            match string.value {
+                Ok(text) => spans.push(TemplateSpan { expression, text }),
+                Err(diagnostic) => {
+                    error.get_or_insert(diagnostic);
+                }
+            }
+            if part == StringPart::Tail {
+                let template = match error {
+                    None => Ok(TemplateExpression { head, spans }),
+                    Some(error) => Err(error),
+                };
+                return Ok((range, template));
+            }
+        }
+    }
+
     fn parse_conditional_expression_if(
         &mut self,
     ) -> Result<ConditionalExpressionIf, DiagnosticRef> {
@@ -674,9 +1264,138 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         })
     }
 
+    /// Parses the arguments of a call expression after the opening parenthesis. Positional
+    /// arguments come first. Once we parse a named argument, like `port: 1`, every argument after
+    /// it must also be named. Returns the range of the closing parenthesis.
+    fn parse_call_arguments(
+        &mut self,
+    ) -> Result<(Vec<Expression>, Vec<NamedArgument>, Range), DiagnosticRef> {
+        let mut arguments = Vec::new();
+        let mut named_arguments = Vec::new();
+        let end = loop {
+            if let Some(range) = self.try_parse_glyph(Glyph::ParenRight) {
+                break range;
+            }
+            if named_arguments.is_empty() && !self.lookahead_named_argument() {
+                arguments.push(self.parse_expression()?);
+            } else {
+                let name = self.parse_name()?;
+                self.parse_glyph(Glyph::Colon)?;
+                let value = self.parse_expression()?;
+                named_arguments.push(NamedArgument::new(name, value));
+            }
+            if self.try_parse_glyph(Glyph::Comma).is_none() {
+                break self.parse_glyph(Glyph::ParenRight)?;
+            }
+        };
+        Ok((arguments, named_arguments, end))
+    }
+
+    /// Looks ahead to see if the next tokens are the name of a named argument, like `port:`.
+    fn lookahead_named_argument(&mut self) -> bool {
+        match self.lexer.lookahead_nth(0).map(|token| &token.kind) {
+            Some(TokenKind::Identifier(_)) => {}
+            _ => return false,
+        }
+        match self.lexer.lookahead_nth(1).map(|token| &token.kind) {
+            Some(TokenKind::Glyph(Glyph::Colon)) => {}
+            _ => return false,
+        }
+        true
+    }
+
+    /// Looks ahead to see if the next tokens are the label of a loop, like `outer: loop`.
+    fn lookahead_label(&mut self) -> bool {
+        match self.lexer.lookahead_nth(0).map(|token| &token.kind) {
+            Some(TokenKind::Identifier(_)) => {}
+            _ => return false,
+        }
+        match self.lexer.lookahead_nth(1).map(|token| &token.kind) {
+            Some(TokenKind::Glyph(Glyph::Colon)) => {}
+            _ => return false,
+        }
+        match self.lexer.lookahead_nth(2).map(|token| &token.kind) {
+            Some(TokenKind::Glyph(Glyph::Keyword(Keyword::While)))
+            | Some(TokenKind::Glyph(Glyph::Keyword(Keyword::Loop))) => {}
+            _ => return false,
+        }
+        true
+    }
+
+    /// Tries to parse a while or loop expression with an optional label. The label, if there is
+    /// one, has already been parsed. The range of our label, or of our keyword if there is no
+    /// label, is provided as the start of our expression.
+    fn try_parse_loop_expression(
+        &mut self,
+        start: Range,
+        label: Option<Name>,
+    ) -> Result<Option<Expression>, DiagnosticRef> {
+        // While Expression
+        if self.try_parse_keyword(Keyword::While).is_some() {
+            let test_config = ParseExpressionConfig { before_block: true };
+            let test = self.parse_expression_with_config(&test_config)?;
+            let body = self.parse_loop_body(&label)?;
+            return Ok(Some(Expression {
+                range: start.union(body.range),
+                kind: ExpressionKind::While(Box::new(WhileExpression { label, test, body })),
+            }));
+        }
+
+        // Loop Expression
+        if self.try_parse_keyword(Keyword::Loop).is_some() {
+            let body = self.parse_loop_body(&label)?;
+            return Ok(Some(Expression {
+                range: start.union(body.range),
+                kind: ExpressionKind::Loop(Box::new(LoopExpression { label, body })),
+            }));
+        }
+
+        Ok(None)
+    }
+
+    /// Parses the body of a loop while remembering our loop’s label.
+    fn parse_loop_body(&mut self, label: &Option<Name>) -> Result<Block, DiagnosticRef> {
+        if let Some(label) = label {
+            self.labels.push(label.identifier.clone());
+        }
+        let body = self.parse_block();
+        if label.is_some() {
+            self.labels.pop();
+        }
+        body
+    }
+
+    fn parse_case_arm(&mut self) -> Result<CaseExpressionArm, DiagnosticRef> {
+        let pattern = self.parse_pattern()?;
+        self.parse_glyph(Glyph::Arrow)?;
+        let body = self.parse_expression()?;
+        Ok(CaseExpressionArm { pattern, body })
+    }
+
+    /// Parses a pattern. If we don’t find a pattern then we report a diagnostic and return an
+    /// error pattern.
     fn parse_pattern(&mut self) -> Result<Pattern, DiagnosticRef> {
-        // Binding Pattern
         if let Some((range, identifier)) = self.try_parse_identifier() {
+            // Class Pattern
+            //
+            // NOTE: Just like constructor fields, the fields of a class pattern must be on the
+            // same line as the class name!
+            if let Some(token) = self.lexer.lookahead() {
+                if let TokenKind::Glyph(Glyph::BraceLeft) = &token.kind {
+                    if !token.first_on_newline() {
+                        self.advance();
+                        let (fields, end) = self
+                            .parse_comma_list(Glyph::BraceRight, Self::parse_class_pattern_field)?;
+                        let class = Name { range, identifier };
+                        return Ok(Pattern {
+                            range: range.union(end),
+                            kind: PatternKind::Class(ClassPattern { class, fields }),
+                        });
+                    }
+                }
+            }
+
+            // Binding Pattern
             return Ok(Pattern {
                 range,
                 kind: PatternKind::Binding(identifier),
@@ -699,13 +1418,46 @@ impl<'errs, 'src> Parser<'errs, 'src> {
             });
         }
 
-        self.unexpected(ExpectedSyntax::Pattern)
+        let error = self.unexpected_node(ExpectedSyntax::Pattern);
+        Ok(Pattern {
+            range: error.range,
+            kind: PatternKind::Error(error),
+        })
+    }
+
+    fn parse_class_pattern_field(&mut self) -> Result<ClassPatternField, DiagnosticRef> {
+        let name = self.parse_name()?;
+        let value = if self.try_parse_glyph(Glyph::Colon).is_some() {
+            Some(self.parse_pattern()?)
+        } else {
+            None
+        };
+        Ok(ClassPatternField { name, value })
     }
 
+    /// Parses a type. If we don’t find a type then we report a diagnostic and return an
+    /// error type.
     fn parse_type(&mut self) -> Result<Type, DiagnosticRef> {
+        let mut type_ = self.parse_primary_type()?;
+
+        // Optional type
+        while let Some(end) = self.try_parse_glyph(Glyph::Question) {
+            let range = type_.range().union(end);
+            type_ = Type::optional(range, type_);
+        }
+
+        Ok(type_)
+    }
+
+    fn parse_primary_type(&mut self) -> Result<Type, DiagnosticRef> {
         // Reference type
-        if let Some((range, identifier)) = self.try_parse_identifier() {
-            return Ok(Type::reference(range, identifier));
+        if let Some((start, identifier)) = self.try_parse_identifier() {
+            if self.try_parse_glyph(Glyph::LessThan).is_some() {
+                let (arguments, end) =
+                    self.parse_comma_list(Glyph::GreaterThan, Self::parse_type)?;
+                return Ok(Type::reference(start.union(end), identifier, arguments));
+            }
+            return Ok(Type::reference(start, identifier, Vec::new()));
         }
 
         // This type
@@ -728,7 +1480,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
             return Ok(Type::function(range, parameters, return_));
         }
 
-        self.unexpected(ExpectedSyntax::Type)
+        Ok(Type::error(self.unexpected_node(ExpectedSyntax::Type)))
     }
 
     /// If there is a colon then we parse a type annotation. Otherwise parse nothing.
@@ -768,7 +1520,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
             if let TokenKind::Glyph(actual) = &token.kind {
                 if expected == *actual {
                     let range = token.range;
-                    self.lexer.advance();
+                    self.advance();
                     return Ok(range);
                 }
             }
@@ -783,7 +1535,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
             if let TokenKind::Glyph(actual) = &token.kind {
                 if expected == *actual {
                     let range = token.range;
-                    self.lexer.advance();
+                    self.advance();
                     return Some(range);
                 }
             }
@@ -807,7 +1559,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
     fn try_parse_identifier(&mut self) -> Option<(Range, Identifier)> {
         if let Some(token) = self.lexer.lookahead() {
             if let TokenKind::Identifier(_) = &token.kind {
-                let token = self.lexer.advance().unwrap();
+                let token = self.advance().unwrap();
                 let range = token.range;
                 return match token.kind {
                     TokenKind::Identifier(identifier) => Some((range, identifier)),
@@ -826,7 +1578,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
             if let TokenKind::Identifier(identifier) = &token.kind {
                 if keyword.test(identifier) {
                     let range = token.range;
-                    self.lexer.advance();
+                    self.advance();
                     return Some(range);
                 }
             }
@@ -844,7 +1596,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
             if let TokenKind::Identifier(identifier) = &token.kind {
                 if keyword.test(identifier) {
                     let range = token.range;
-                    self.lexer.advance();
+                    self.advance();
                     return Ok(range);
                 }
             }
@@ -856,7 +1608,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
     fn try_parse_name(&mut self) -> Option<Name> {
         if let Some(token) = self.lexer.lookahead() {
             if let TokenKind::Identifier(_) = &token.kind {
-                let token = self.lexer.advance().unwrap();
+                let token = self.advance().unwrap();
                 let range = token.range;
                 return match token.kind {
                     TokenKind::Identifier(identifier) => Some(Name { range, identifier }),
@@ -871,7 +1623,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
     fn parse_name(&mut self) -> Result<Name, DiagnosticRef> {
         if let Some(token) = self.lexer.lookahead() {
             if let TokenKind::Identifier(_) = &token.kind {
-                let token = self.lexer.advance().unwrap();
+                let token = self.advance().unwrap();
                 let range = token.range;
                 return match token.kind {
                     TokenKind::Identifier(identifier) => Ok(Name { range, identifier }),
@@ -887,7 +1639,7 @@ impl<'errs, 'src> Parser<'errs, 'src> {
     fn try_parse_number(&mut self) -> Option<(Range, Number)> {
         if let Some(token) = self.lexer.lookahead() {
             if let TokenKind::Number(_) = &token.kind {
-                let token = self.lexer.advance().unwrap();
+                let token = self.advance().unwrap();
                 let range = token.range;
                 return match token.kind {
                     TokenKind::Number(number) => Some((range, number)),
@@ -898,20 +1650,127 @@ impl<'errs, 'src> Parser<'errs, 'src> {
         None
     }
 
+    /// Tries to parse a string part. If the next token is a string of the provided part then we
+    /// advance the lexer and return that string. Otherwise we don’t advance the lexer and
+    /// return nothing.
+    fn try_parse_string(&mut self, part: StringPart) -> Option<(Range, StringLiteral)> {
+        if let Some(token) = self.lexer.lookahead() {
+            if let TokenKind::String(string) = &token.kind {
+                if string.part == part {
+                    let token = self.advance().unwrap();
+                    let range = token.range;
+                    return match token.kind {
+                        TokenKind::String(string) => Some((range, string)),
+                        _ => unreachable!(),
+                    };
+                }
+            }
+        }
+        None
+    }
+
     /// If the next token is unexpected then call this function and say what we did expect. This
     /// function will throw an unexpected syntax error.
     fn unexpected<T>(&mut self, expected: ExpectedSyntax) -> Result<T, DiagnosticRef> {
-        match self.lexer.lookahead() {
-            Some(token) => {
-                let diagnostic = Diagnostic::unexpected_token(token, expected);
-                Err(self.report_diagnostic(diagnostic))
+        Err(self.report_unexpected(expected))
+    }
+
+    /// If the next token is unexpected then call this function and say what we did expect. Instead
+    /// of throwing an error like [`Parser::unexpected`] we return the error diagnostic so that the
+    /// caller may put an error node in place of the syntax it expected.
+    ///
+    /// We skip the unexpected token unless some other syntax, like the next statement or a closing
+    /// parenthesis, might want it.
+    fn unexpected_node(&mut self, expected: ExpectedSyntax) -> DiagnosticRef {
+        let error = self.report_unexpected(expected);
+        if let Some(token) = self.lexer.lookahead() {
+            if !token.first_on_newline() && !is_recovery_token(token) {
+                self.advance();
+            }
+        }
+        error
+    }
+
+    /// Reports an unexpected syntax error and returns an error expression in place of the
+    /// expression we expected.
+    fn unexpected_expression(&mut self) -> Expression {
+        let error = self.unexpected_node(ExpectedSyntax::Expression);
+        Expression {
+            range: error.range,
+            kind: ExpressionKind::Error(error),
+        }
+    }
+
+    /// Reports a diagnostic for the next token. Say what we did expect.
+    ///
+    /// If we already reported an unexpected syntax error for the next token then we return that
+    /// diagnostic instead of reporting another. After one syntax error the code which catches it
+    /// often expects something else at the very same token, like the `{` of a block after a
+    /// missing condition, and those follow-on errors are only noise.
+    fn report_unexpected(&mut self, expected: ExpectedSyntax) -> DiagnosticRef {
+        if let Some((range, error)) = &self.last_unexpected {
+            if *range == self.last_range {
+                return error.clone();
             }
+        }
+        let diagnostic = match self.lexer.lookahead() {
+            Some(token) => Diagnostic::unexpected_token(token, expected),
             None => {
                 let end_position = self.lexer.lookahead_end().unwrap().position();
-                let diagnostic = Diagnostic::unexpected_ending(end_position, expected);
-                Err(self.report_diagnostic(diagnostic))
+                Diagnostic::unexpected_ending(end_position, expected)
+            }
+        };
+        let error = self.report_diagnostic(diagnostic);
+        self.last_unexpected = Some((self.last_range, error.clone()));
+        error
+    }
+
+    /// Recovers from a syntax error by skipping tokens until we find one where `boundary` says we
+    /// may start parsing again. We always skip at least one token so that we never get stuck. We
+    /// keep track of brace, parenthesis, and bracket nesting so that we don’t resume parsing in the
+    /// middle of some nested code.
+    ///
+    /// Returns the range of all the code from `start` to the last token we skipped.
+    fn recover(&mut self, start: Range, boundary: Boundary) -> Range {
+        let mut depth: usize = 0;
+        while let Some(token) = self.lexer.lookahead() {
+            // If we have not skipped any tokens since `start` then we must skip this one!
+            if depth == 0 && token.range != start {
+                match boundary.test(token) {
+                    Recovery::Skip => {}
+                    Recovery::Before => break,
+                    Recovery::After => {
+                        self.advance();
+                        break;
+                    }
+                }
+            }
+            match &token.kind {
+                TokenKind::Glyph(Glyph::BraceLeft)
+                | TokenKind::Glyph(Glyph::BracketLeft)
+                | TokenKind::Glyph(Glyph::ParenLeft) => depth += 1,
+                TokenKind::Glyph(Glyph::BraceRight)
+                | TokenKind::Glyph(Glyph::BracketRight)
+                | TokenKind::Glyph(Glyph::ParenRight) => depth = depth.saturating_sub(1),
+                TokenKind::String(string) => match string.part {
+                    StringPart::Head => depth += 1,
+                    StringPart::Tail => depth = depth.saturating_sub(1),
+                    StringPart::Complete | StringPart::Middle => {}
+                },
+                _ => {}
             }
+            self.advance();
         }
+        start.union(self.last_range)
+    }
+
+    /// Advances our lexer and remembers the range of the token we advanced past.
+    fn advance(&mut self) -> Option<Token<'src>> {
+        let token = self.lexer.advance();
+        if let Some(token) = &token {
+            self.last_range = token.range;
+        }
+        token
     }
 
     /// Report a diagnostic.
@@ -935,6 +1794,106 @@ impl Default for ParseExpressionConfig {
     }
 }
 
+/// A place in our source code where we may start parsing again after a syntax error.
+#[derive(Copy, Clone)]
+enum Boundary {
+    /// We may start parsing again at the next declaration.
+    Declaration,
+    /// We may start parsing again at the next class member.
+    ClassMember,
+    /// We may start parsing again at the next statement.
+    Statement,
+}
+
+/// What to do with a token while we are recovering from a syntax error.
+enum Recovery {
+    /// Skip the token and keep looking for a boundary.
+    Skip,
+    /// Stop recovering. We will parse the token as the start of our boundary.
+    Before,
+    /// Skip the token and then stop recovering. The token ends the syntax which had an error.
+    After,
+}
+
+impl Boundary {
+    /// Tests if we’ve reached our boundary at this token. Only called for tokens which are not
+    /// nested inside the braces, brackets, or parentheses that we skip over.
+    fn test(self, token: &Token) -> Recovery {
+        match (self, &token.kind) {
+            // Every declaration starts with `fun`, `async`, `class`, `base`, `type`, `import`,
+            // `export`, or the `@` of an attribute.
+            (Boundary::Declaration, TokenKind::Glyph(Glyph::Keyword(Keyword::Fun)))
+            | (Boundary::Declaration, TokenKind::Glyph(Glyph::Keyword(Keyword::Async)))
+            | (Boundary::Declaration, TokenKind::Glyph(Glyph::At)) => Recovery::Before,
+            (Boundary::Declaration, TokenKind::Identifier(identifier))
+                if IdentifierKeyword::Class.test(identifier)
+                    || IdentifierKeyword::Base.test(identifier)
+                    || IdentifierKeyword::Type.test(identifier)
+                    || IdentifierKeyword::Import.test(identifier)
+                    || IdentifierKeyword::Export.test(identifier) =>
+            {
+                Recovery::Before
+            }
+            (Boundary::Declaration, _) => Recovery::Skip,
+
+            // A closing brace ends the class or block we were parsing.
+            (Boundary::ClassMember, TokenKind::Glyph(Glyph::BraceRight))
+            | (Boundary::Statement, TokenKind::Glyph(Glyph::BraceRight)) => Recovery::Before,
+
+            // A semicolon ends the class member or statement which had an error.
+            (Boundary::ClassMember, TokenKind::Glyph(Glyph::Semicolon))
+            | (Boundary::Statement, TokenKind::Glyph(Glyph::Semicolon)) => Recovery::After,
+
+            // Some keywords and glyphs may only ever start a class member or a statement.
+            (Boundary::ClassMember, TokenKind::Glyph(Glyph::Keyword(Keyword::Fun)))
+            | (Boundary::ClassMember, TokenKind::Glyph(Glyph::Keyword(Keyword::Async)))
+            | (Boundary::ClassMember, TokenKind::Glyph(Glyph::At))
+            | (Boundary::Statement, TokenKind::Glyph(Glyph::Keyword(Keyword::Let)))
+            | (Boundary::Statement, TokenKind::Glyph(Glyph::Keyword(Keyword::Return)))
+            | (Boundary::Statement, TokenKind::Glyph(Glyph::Keyword(Keyword::Break)))
+            | (Boundary::Statement, TokenKind::Glyph(Glyph::Keyword(Keyword::Continue))) => {
+                Recovery::Before
+            }
+
+            // Class members and statements may be separated by newlines instead of semicolons so
+            // a token on a new line is a good guess for where the next one starts.
+            (Boundary::ClassMember, TokenKind::Identifier(_)) | (Boundary::Statement, _)
+                if token.first_on_newline() =>
+            {
+                Recovery::Before
+            }
+
+            (Boundary::ClassMember, _) | (Boundary::Statement, _) => Recovery::Skip,
+        }
+    }
+}
+
+/// Some tokens are better left alone when we find them in place of a pattern, expression, or type.
+/// Other syntax, like a closing parenthesis or the next statement, probably wants them.
+fn is_recovery_token(token: &Token) -> bool {
+    match &token.kind {
+        TokenKind::Glyph(Glyph::Arrow)
+        | TokenKind::Glyph(Glyph::BraceLeft)
+        | TokenKind::Glyph(Glyph::BraceRight)
+        | TokenKind::Glyph(Glyph::BracketRight)
+        | TokenKind::Glyph(Glyph::ParenRight)
+        | TokenKind::Glyph(Glyph::Colon)
+        | TokenKind::Glyph(Glyph::Comma)
+        | TokenKind::Glyph(Glyph::Equals)
+        | TokenKind::Glyph(Glyph::Semicolon)
+        | TokenKind::Glyph(Glyph::Keyword(Keyword::Else))
+        | TokenKind::Glyph(Glyph::Keyword(Keyword::Let))
+        | TokenKind::Glyph(Glyph::Keyword(Keyword::Return))
+        | TokenKind::Glyph(Glyph::Keyword(Keyword::Break))
+        | TokenKind::Glyph(Glyph::Keyword(Keyword::Continue)) => true,
+        TokenKind::String(string) => match string.part {
+            StringPart::Middle | StringPart::Tail => true,
+            StringPart::Complete | StringPart::Head => false,
+        },
+        _ => false,
+    }
+}
+
 /// The precedence level at which we parse an infix expression.
 #[derive(Copy, Clone, Eq, Ord, PartialEq, PartialOrd)]
 enum Precedence {
@@ -942,10 +1901,15 @@ enum Precedence {
     Exponentiation,
     Multiplicative,
     Additive,
+    Shift,
+    BitwiseAnd,
+    BitwiseXor,
+    BitwiseOr,
     Relational,
     Equality,
     LogicalAnd,
     LogicalOr,
+    Pipe,
 }
 
 /// Converts an expression into a class constructor. If the expression cannot be converted into a
@@ -10,6 +10,7 @@ use std::hash::Hash;
 /// the deeper `x` binding goes out of scope.
 ///
 /// This data structure provides that service.
+#[derive(Clone)]
 pub struct BindingMap<Key: Hash + Eq, Value> {
     stack: Vec1<HashMap<Key, Value>>,
 }
@@ -56,6 +57,26 @@ impl<Key: Hash + Eq, Value> BindingMap<Key, Value> {
         self.stack.pop();
     }
 
+    /// Manually removes every level of nesting from the binding map so that only the root bindings
+    /// are visible. Returns the levels we removed. Must call [`BindingMap::manual_restore`] with
+    /// those levels afterwards.
+    pub fn manual_take_nested(&mut self) -> Vec<HashMap<Key, Value>> {
+        let mut nested = Vec::new();
+        while let Some(map) = self.stack.pop() {
+            nested.push(map);
+        }
+        nested.reverse();
+        nested
+    }
+
+    /// Manually adds back the levels of nesting removed by [`BindingMap::manual_take_nested`]. Any
+    /// levels of nesting added since must already be removed.
+    pub fn manual_restore(&mut self, nested: Vec<HashMap<Key, Value>>) {
+        for map in nested {
+            self.stack.push(map);
+        }
+    }
+
     /// Insert a new binding into the map. If we are inside of a [`BindingMap::nest`] then when we
     /// leave that scope the binding will be removed.
     pub fn insert(&mut self, key: Key, value: Value) {
@@ -92,6 +113,11 @@ impl<Key: Hash + Eq, Value> BindingMap<Key, Value> {
         false
     }
 
+    /// Checks if any nesting level, but not the root, contains a binding for the provided key.
+    pub fn contains_nested(&self, key: &Key) -> bool {
+        self.stack.iter().skip(1).any(|map| map.contains_key(key))
+    }
+
     /// Checks if the current nesting level contains a binding for the provided key.
     ///
     /// If a binding exists in the map and then we call [`BindingMap::nest`], then this function
@@ -22,6 +22,7 @@ macro_rules! vec2 {
 }
 
 /// A vector with at least one element.
+#[derive(Clone)]
 pub struct Vec1<T> {
     /// We put all our items in a vec. So our types don’t hold the contract of our utility only the
     /// external interface.
@@ -1,5 +1,10 @@
+test!(attribute);
 test!(class_extends);
+test!(class_extends_cycle);
 test!(declaration_already_exists);
 test!(declaration_prelude_shadow);
 test!(function_parameter);
+test!(function_reference);
 test!(function_return);
+test!(import_module_not_found);
+test!(outline);
@@ -1,6 +1,25 @@
+test!(arithmetic);
+test!(await_);
+test!(bitwise);
 test!(block);
 test!(call);
+test!(call_generic);
+test!(call_named);
+test!(case);
+test!(case_nested);
+test!(comparison);
+test!(conditional);
 test!(constants);
+test!(construct);
 test!(function);
+test!(list);
 test!(logical);
+test!(loop_);
+test!(member);
+test!(method);
+test!(method_static);
+test!(pipe);
+test!(prefix);
+test!(template);
+test!(while_);
 test!(wrapped);
@@ -1 +1,5 @@
+test!(assignment);
 test!(binding);
+test!(binding_pattern);
+test!(break_);
+test!(return_);
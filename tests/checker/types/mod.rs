@@ -1,2 +1,7 @@
+test!(alias);
+test!(alias_cycle);
+test!(class);
 test!(error);
 test!(function);
+test!(generic);
+test!(optional);
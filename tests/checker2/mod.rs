@@ -1 +1,2 @@
 test!(declaration_already_exists);
+test!(type_alias);
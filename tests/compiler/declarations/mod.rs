@@ -1,2 +1,10 @@
+test!(class);
+test!(class_order);
 test!(declaration_already_exists);
+test!(error);
+test!(function_export);
 test!(function_return);
+test!(inline);
+test!(inline_class);
+test!(outline);
+test!(type_alias);
@@ -1,3 +1,22 @@
+test!(arithmetic);
+test!(await_);
+test!(bitwise);
 test!(block);
+test!(call_error);
+test!(call_named);
+test!(case);
+test!(comparison);
+test!(conditional);
+test!(error);
 test!(function);
+test!(integer);
+test!(list);
 test!(logical);
+test!(loop_);
+test!(method_static);
+test!(null);
+test!(pipe);
+test!(prefix);
+test!(string);
+test!(template);
+test!(while_);
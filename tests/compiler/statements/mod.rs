@@ -1 +1,5 @@
+test!(assignment);
 test!(binding);
+test!(binding_hole);
+test!(error);
+test!(return_);
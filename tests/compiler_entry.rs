@@ -1,47 +1,53 @@
-// extern crate brite;
+extern crate brite;
 
-// macro_rules! test {
-//     ($name:ident) => {
-//         #[test]
-//         fn $name() {
-//             use brite::checker::Checker;
-//             use brite::compiler::js::Compiler;
-//             use brite::diagnostics::DiagnosticsCollection;
-//             use brite::parser::{Document, Lexer, Parser};
-//             use std::fs;
-//             use std::io::prelude::*;
-//             use std::path::PathBuf;
+macro_rules! test {
+    ($name:ident) => {
+        #[test]
+        fn $name() {
+            use brite::checker::Checker;
+            use brite::compiler::js::Compiler;
+            use brite::diagnostics::DiagnosticsCollection;
+            use brite::parser::{Document, Lexer, Parser};
+            use std::fs;
+            use std::io::prelude::*;
+            use std::path::PathBuf;
 
-//             let mut path = PathBuf::from(file!());
-//             path.set_file_name(stringify!($name));
-//             path.set_extension("ite");
+            let mut path = PathBuf::from(file!());
+            path.set_file_name(stringify!($name));
+            path.set_extension("ite");
 
-//             let source = fs::read_to_string(&path).unwrap();
+            let source = fs::read_to_string(&path).unwrap();
 
-//             let mut diagnostics = DiagnosticsCollection::new();
-//             let document = Document::new(source);
-//             let lexer = Lexer::new(&mut diagnostics, &document);
-//             let module = Parser::new(lexer).parse_module().unwrap();
-//             Checker::new(&mut diagnostics).check_module(&module);
-//             let program = Compiler::new().compile_module(&module);
+            let mut diagnostics = DiagnosticsCollection::new();
+            let document = Document::new(source);
+            let lexer = Lexer::new(&mut diagnostics, &document);
+            let module = Parser::new(lexer).parse_module();
+            let checked = Checker::new(&mut diagnostics).check_module(&module);
+            let (program, chunks) =
+                Compiler::new(&document).compile_module(stringify!($name), &checked);
 
-//             path.set_extension("ite.md");
-//             let mut file = fs::File::create(path).unwrap();
-//             write!(&mut file, "# Compiler Test: `{}`\n", stringify!($name)).unwrap();
-//             if !diagnostics.is_empty() {
-//                 write!(
-//                     &mut file,
-//                     "\n## Errors\n{}",
-//                     diagnostics.markdown_list(&document)
-//                 )
-//                 .unwrap();
-//             }
+            path.set_extension("ite.md");
+            let mut file = fs::File::create(path).unwrap();
+            write!(&mut file, "# Compiler Test: `{}`\n", stringify!($name)).unwrap();
+            if !diagnostics.is_empty() {
+                write!(
+                    &mut file,
+                    "\n## Errors\n{}",
+                    diagnostics.markdown_list(&document)
+                )
+                .unwrap();
+            }
 
-//             write!(&mut file, "\n## JS\n```js\n").unwrap();
-//             program.write(&mut file).unwrap();
-//             write!(&mut file, "```\n").unwrap();
-//         }
-//     };
-// }
+            write!(&mut file, "\n## JS\n```js\n").unwrap();
+            program.write(&mut file).unwrap();
+            write!(&mut file, "```\n").unwrap();
+            for chunk in chunks {
+                write!(&mut file, "\n## JS `{}.js`\n```js\n", chunk.name).unwrap();
+                chunk.program.write(&mut file).unwrap();
+                write!(&mut file, "```\n").unwrap();
+            }
+        }
+    };
+}
 
-// mod compiler;
+mod compiler;
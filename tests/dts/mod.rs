@@ -0,0 +1,5 @@
+test!(class);
+test!(empty);
+test!(function);
+test!(private);
+test!(types);
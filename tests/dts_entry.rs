@@ -0,0 +1,51 @@
+extern crate brite;
+
+macro_rules! test {
+    ($name:ident) => {
+        #[test]
+        fn $name() {
+            use brite::checker::Checker;
+            use brite::compiler::js::write_type_declarations;
+            use brite::diagnostics::DiagnosticsCollection;
+            use brite::parser::{Document, Lexer, Parser};
+            use std::fs;
+            use std::io::prelude::*;
+            use std::path::PathBuf;
+
+            let mut path = PathBuf::from(file!());
+            path.set_file_name(stringify!($name));
+            path.set_extension("ite");
+
+            let source = fs::read_to_string(&path).unwrap();
+
+            let mut diagnostics = DiagnosticsCollection::new();
+            let document = Document::new(source);
+            let lexer = Lexer::new(&mut diagnostics, &document);
+            let module = Parser::new(lexer).parse_module();
+            let module = Checker::new(&mut diagnostics).check_module(&module);
+
+            path.set_extension("ite.md");
+            let mut file = fs::File::create(path).unwrap();
+            write!(
+                &mut file,
+                "# Type Declarations Test: `{}`\n",
+                stringify!($name)
+            )
+            .unwrap();
+            if !diagnostics.is_empty() {
+                write!(
+                    &mut file,
+                    "\n## Errors\n{}",
+                    diagnostics.markdown_list(&document)
+                )
+                .unwrap();
+            }
+
+            write!(&mut file, "\n## TypeScript\n```ts\n").unwrap();
+            write_type_declarations(&mut file, &module).unwrap();
+            write!(&mut file, "```\n").unwrap();
+        }
+    };
+}
+
+mod dts;
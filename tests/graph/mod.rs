@@ -0,0 +1,8 @@
+test!(basic);
+test!(cycle);
+test!(cycle_tail);
+test!(export_not_found);
+test!(extends);
+test!(name_clash);
+test!(nested);
+test!(type_alias);
@@ -0,0 +1,92 @@
+extern crate brite;
+
+macro_rules! test {
+    ($name:ident) => {
+        #[test]
+        fn $name() {
+            use brite::checker::ModuleGraph;
+            use brite::compiler::js::{write_type_declarations, Compiler};
+            use brite::diagnostics::DiagnosticsCollection;
+            use brite::parser::{Document, Lexer, Parser};
+            use std::fs;
+            use std::io::prelude::*;
+            use std::path::{Path, PathBuf};
+
+            /// Finds every module in a directory and its subdirectories. Returns the path of each
+            /// module relative to `root` without the `.ite` extension.
+            fn find_modules(root: &Path, directory: &Path, paths: &mut Vec<String>) {
+                for entry in fs::read_dir(directory).unwrap() {
+                    let path = entry.unwrap().path();
+                    if path.is_dir() {
+                        find_modules(root, &path, paths);
+                    } else if path
+                        .extension()
+                        .map_or(false, |extension| extension == "ite")
+                    {
+                        let path = path.strip_prefix(root).unwrap().with_extension("");
+                        let segments: Vec<_> = path.iter().map(|s| s.to_str().unwrap()).collect();
+                        paths.push(segments.join("/"));
+                    }
+                }
+            }
+
+            let mut root = PathBuf::from(file!());
+            root.set_file_name(stringify!($name));
+
+            let mut paths = Vec::new();
+            find_modules(&root, &root, &mut paths);
+            paths.sort();
+
+            let mut documents = Vec::with_capacity(paths.len());
+            let mut diagnostics = Vec::with_capacity(paths.len());
+            let mut modules = Vec::with_capacity(paths.len());
+            for path in &paths {
+                let source = fs::read_to_string(root.join(path).with_extension("ite")).unwrap();
+                let mut module_diagnostics = DiagnosticsCollection::new();
+                let document = Document::new(source);
+                let lexer = Lexer::new(&mut module_diagnostics, &document);
+                modules.push(Parser::new(lexer).parse_module());
+                documents.push(document);
+                diagnostics.push(module_diagnostics);
+            }
+
+            let mut graph = ModuleGraph::new();
+            for ((path, module), module_diagnostics) in
+                paths.iter().zip(&modules).zip(&mut diagnostics)
+            {
+                graph.add(path, module, module_diagnostics);
+            }
+            let checked = graph.check();
+
+            let mut file = fs::File::create(root.with_extension("md")).unwrap();
+            write!(&mut file, "# Graph Test: `{}`\n", stringify!($name)).unwrap();
+            for i in 0..paths.len() {
+                write!(&mut file, "\n## `{}.ite`\n", paths[i]).unwrap();
+                if !diagnostics[i].is_empty() {
+                    write!(
+                        &mut file,
+                        "\n### Errors\n{}",
+                        diagnostics[i].markdown_list(&documents[i])
+                    )
+                    .unwrap();
+                }
+                let name = paths[i].rsplit('/').next().unwrap();
+                let (program, chunks) =
+                    Compiler::new(&documents[i]).compile_module(name, &checked[i]);
+                write!(&mut file, "\n### JS\n```js\n").unwrap();
+                program.write(&mut file).unwrap();
+                write!(&mut file, "```\n").unwrap();
+                for chunk in chunks {
+                    write!(&mut file, "\n### JS `{}.js`\n```js\n", chunk.name).unwrap();
+                    chunk.program.write(&mut file).unwrap();
+                    write!(&mut file, "```\n").unwrap();
+                }
+                write!(&mut file, "\n### TypeScript\n```ts\n").unwrap();
+                write_type_declarations(&mut file, &checked[i]).unwrap();
+                write!(&mut file, "```\n").unwrap();
+            }
+        }
+    };
+}
+
+mod graph;
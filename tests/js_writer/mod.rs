@@ -0,0 +1,3 @@
+test!(lists);
+test!(operators);
+test!(statements);
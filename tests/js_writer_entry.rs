@@ -0,0 +1,80 @@
+extern crate brite;
+
+macro_rules! test {
+    ($name:ident) => {
+        #[test]
+        fn $name() {
+            use brite::checker::Checker;
+            use brite::compiler::js::{Compiler, JsWriterOptions, Semicolons};
+            use brite::diagnostics::DiagnosticsCollection;
+            use brite::parser::{Document, Lexer, Parser};
+            use std::fs;
+            use std::io::prelude::*;
+            use std::path::PathBuf;
+
+            let mut path = PathBuf::from(file!());
+            path.set_file_name(stringify!($name));
+            path.set_extension("ite");
+
+            let source = fs::read_to_string(&path).unwrap();
+
+            let mut diagnostics = DiagnosticsCollection::new();
+            let document = Document::new(source);
+            let lexer = Lexer::new(&mut diagnostics, &document);
+            let module = Parser::new(lexer).parse_module();
+            let checked = Checker::new(&mut diagnostics).check_module(&module);
+            let (program, _) =
+                Compiler::new(&document).compile_module(stringify!($name), &checked);
+
+            path.set_extension("ite.md");
+            let mut file = fs::File::create(path).unwrap();
+            write!(&mut file, "# JS Writer Test: `{}`\n", stringify!($name)).unwrap();
+            if !diagnostics.is_empty() {
+                write!(
+                    &mut file,
+                    "\n## Errors\n{}",
+                    diagnostics.markdown_list(&document)
+                )
+                .unwrap();
+            }
+
+            let tests = vec![
+                ("JS", JsWriterOptions::default()),
+                (
+                    "JS Without Semicolons",
+                    JsWriterOptions {
+                        indentation: "    ".to_string(),
+                        semicolons: Semicolons::AsNeeded,
+                        max_line_width: 40,
+                        ..JsWriterOptions::default()
+                    },
+                ),
+                (
+                    "Minified JS",
+                    JsWriterOptions {
+                        minify: true,
+                        ..JsWriterOptions::default()
+                    },
+                ),
+                (
+                    "Minified JS Without Semicolons",
+                    JsWriterOptions {
+                        minify: true,
+                        semicolons: Semicolons::AsNeeded,
+                        ..JsWriterOptions::default()
+                    },
+                ),
+            ];
+            for (title, options) in tests {
+                write!(&mut file, "\n## {}\n```js\n", title).unwrap();
+                program.write_with_options(&mut file, &options).unwrap();
+                if options.minify {
+                    write!(&mut file, "\n").unwrap();
+                }
+                write!(&mut file, "```\n").unwrap();
+            }
+        }
+    };
+}
+
+mod js_writer;
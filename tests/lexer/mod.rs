@@ -8,4 +8,8 @@ test!(line_comment_end);
 test!(newlines);
 test!(numbers_dot_end);
 test!(numbers);
+test!(strings);
+test!(strings_end);
+test!(strings_invalid);
+test!(strings_template);
 test!(tabs);
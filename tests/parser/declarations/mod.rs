@@ -1,8 +1,20 @@
+test!(attribute);
 test!(class_base_method_only);
 test!(class_base_only);
 test!(class_expected_member);
+test!(class_generic);
 test!(class_head);
 test!(class_member_base_method);
 test!(class_member_field);
 test!(class_member_method);
+test!(class_unclosed);
+test!(export);
 test!(function);
+test!(function_async);
+test!(function_default);
+test!(function_generic);
+test!(function_unclosed);
+test!(import);
+test!(recover);
+test!(recover_same_token);
+test!(type_alias);
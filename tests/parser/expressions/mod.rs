@@ -1,5 +1,12 @@
+test!(await_);
+test!(bitwise);
 test!(block);
 test!(call);
+test!(call_generic);
+test!(call_named);
+test!(case);
+test!(case_invalid);
+test!(comparison);
 test!(conditional);
 test!(conditional_construct_1);
 test!(conditional_construct_2);
@@ -8,9 +15,16 @@ test!(constant);
 test!(construct);
 test!(construct_line);
 test!(function);
+test!(index);
 test!(infix);
+test!(list);
+test!(loop_);
 test!(member);
 test!(number_invalid);
+test!(pipe);
 test!(prefix);
 test!(reference);
+test!(string);
+test!(template);
+test!(while_);
 test!(wrapped);
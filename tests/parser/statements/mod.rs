@@ -1,4 +1,9 @@
+test!(assignment);
 test!(binding);
+test!(binding_pattern);
+test!(break_);
+test!(continue_);
 test!(empty);
 test!(expected_statement);
+test!(recover);
 test!(return_);
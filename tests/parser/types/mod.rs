@@ -1,2 +1,4 @@
 test!(void);
 test!(function);
+test!(generic);
+test!(optional);
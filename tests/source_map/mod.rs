@@ -0,0 +1,3 @@
+test!(function);
+test!(outline);
+test!(unicode);
@@ -0,0 +1,114 @@
+extern crate brite;
+
+macro_rules! test {
+    ($name:ident) => {
+        #[test]
+        fn $name() {
+            use brite::checker::Checker;
+            use brite::compiler::js::{Compiler, JsWriterOptions, SourceMapOptions};
+            use brite::diagnostics::DiagnosticsCollection;
+            use brite::parser::{Document, Lexer, Parser};
+            use std::fs;
+            use std::io::prelude::*;
+            use std::path::PathBuf;
+
+            let mut path = PathBuf::from(file!());
+            path.set_file_name(stringify!($name));
+            path.set_extension("ite");
+
+            let source = fs::read_to_string(&path).unwrap();
+
+            let mut diagnostics = DiagnosticsCollection::new();
+            let document = Document::new(source);
+            let lexer = Lexer::new(&mut diagnostics, &document);
+            let module = Parser::new(lexer).parse_module();
+            let checked = Checker::new(&mut diagnostics).check_module(&module);
+            let (program, chunks) =
+                Compiler::new(&document).compile_module(stringify!($name), &checked);
+
+            path.set_extension("ite.md");
+            let mut file = fs::File::create(path).unwrap();
+            write!(&mut file, "# Source Map Test: `{}`\n", stringify!($name)).unwrap();
+            if !diagnostics.is_empty() {
+                write!(
+                    &mut file,
+                    "\n## Errors\n{}",
+                    diagnostics.markdown_list(&document)
+                )
+                .unwrap();
+            }
+
+            // Embed our source in the source map of our program but not in the source maps of
+            // our chunks so that we test both.
+            let mut js = Vec::new();
+            let source_map = program
+                .write_with_source_map(
+                    &mut js,
+                    &JsWriterOptions::default(),
+                    &document,
+                    SourceMapOptions {
+                        file: format!("{}.js", stringify!($name)),
+                        source: format!("{}.ite", stringify!($name)),
+                        sources_content: true,
+                    },
+                )
+                .unwrap();
+            write!(&mut file, "\n## JS\n```js\n").unwrap();
+            file.write_all(&js).unwrap();
+            write!(&mut file, "```\n\n## Source Map\n```json\n").unwrap();
+            source_map.write(&mut file).unwrap();
+            write!(&mut file, "```\n").unwrap();
+
+            // Minified code puts everything on one line so test that our columns are still right.
+            let mut js = Vec::new();
+            let source_map = program
+                .write_with_source_map(
+                    &mut js,
+                    &JsWriterOptions {
+                        minify: true,
+                        ..JsWriterOptions::default()
+                    },
+                    &document,
+                    SourceMapOptions {
+                        file: format!("{}.min.js", stringify!($name)),
+                        source: format!("{}.ite", stringify!($name)),
+                        sources_content: false,
+                    },
+                )
+                .unwrap();
+            write!(&mut file, "\n## Minified JS\n```js\n").unwrap();
+            file.write_all(&js).unwrap();
+            write!(&mut file, "\n```\n\n## Minified Source Map\n```json\n").unwrap();
+            source_map.write(&mut file).unwrap();
+            write!(&mut file, "```\n").unwrap();
+            for chunk in chunks {
+                let mut js = Vec::new();
+                let source_map = chunk
+                    .program
+                    .write_with_source_map(
+                        &mut js,
+                        &JsWriterOptions::default(),
+                        &document,
+                        SourceMapOptions {
+                            file: format!("{}.js", chunk.name),
+                            source: format!("{}.ite", stringify!($name)),
+                            sources_content: false,
+                        },
+                    )
+                    .unwrap();
+                write!(&mut file, "\n## JS `{}.js`\n```js\n", chunk.name).unwrap();
+                file.write_all(&js).unwrap();
+                write!(
+                    &mut file,
+                    "```\n\n## Source Map `{}.js.map`\n```json\n",
+                    chunk.name
+                )
+                .unwrap();
+                source_map.write(&mut file).unwrap();
+                write!(&mut file, "```\n").unwrap();
+            }
+        }
+    };
+}
+
+mod source_map;